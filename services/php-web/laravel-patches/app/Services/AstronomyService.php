@@ -3,103 +3,44 @@
 namespace App\Services;
 
 use Illuminate\Support\Facades\Cache;
-use Illuminate\Support\Facades\Http;
-use Illuminate\Support\Facades\Log;
 
 class AstronomyService extends BaseHttpService
 {
-    private string $appId;
-    private string $appSecret;
-    private string $baseUrl = 'https://api.astronomyapi.com/api/v2';
+    private string $rustApiUrl;
 
     public function __construct()
     {
-        $this->appId = env('ASTRO_APP_ID', '');
-        $this->appSecret = env('ASTRO_APP_SECRET', '');
+        $this->rustApiUrl = env('RUST_ISS_URL', 'http://rust_iss:3000');
         $this->timeout = 30;
     }
 
     /**
-     * Получить позиции небесных тел через Astronomy API
+     * Получить позиции небесных тел (AstronomyAPI через rust_iss, общий кэш и rate limit)
      */
     public function getEvents(): array
     {
         return Cache::remember('astronomy:positions', 3600, function () { // Кэш 1 час
-            $auth = base64_encode("{$this->appId}:{$this->appSecret}");
-            
-            // Observer parameters (required for positions endpoint)
             // Координаты по умолчанию: Kentucky, USA (из примера документации)
-            $latitude = 38.775867;
-            $longitude = -84.39733;
-            $elevation = 0;
-            
             // Date range: текущая дата и следующие 3 дня
-            $fromDate = date('Y-m-d'); // Только дата
-            $toDate = date('Y-m-d', strtotime('+3 days'));
-            $time = date('H:i:s'); // Время отдельно
-            
-            // Строим URL с query параметрами (все обязательные)
-            $url = "{$this->baseUrl}/bodies/positions"
-                . "?latitude={$latitude}"
-                . "&longitude={$longitude}"
-                . "&elevation={$elevation}"
-                . "&from_date={$fromDate}"
-                . "&to_date={$toDate}"
-                . "&time={$time}";
-            
-            Log::info("Astronomy API request", [
-                'url' => $url,
-                'auth_length' => strlen($auth),
+            $data = $this->get("{$this->rustApiUrl}/astro/positions", [
+                'lat' => 38.775867,
+                'lon' => -84.39733,
+                'elevation' => 0,
+                'from' => date('Y-m-d'),
+                'to' => date('Y-m-d', strtotime('+3 days')),
             ]);
 
-            $response = Http::timeout(30)
-                ->withHeaders([
-                    'Authorization' => "Basic {$auth}",
-                ])
-                ->get($url);
-
-            if (!$response->successful()) {
-                $errorBody = $response->body();
-                $status = $response->status();
-                
-                Log::error("Astronomy API failed", [
-                    'status' => $status,
-                    'body' => $errorBody,
-                    'url' => $url,
-                ]);
-                
-                throw new \Exception("Astronomy API error: {$status}");
+            if (!($data['ok'] ?? false)) {
+                $error = $data['error']['message'] ?? $data['error'] ?? 'Unknown error';
+                throw new \Exception($error);
             }
 
-            $data = $response->json();
-            
-            if (!isset($data['data']['table']['rows']) || empty($data['data']['table']['rows'])) {
+            if (empty($data['data']['table']['rows'])) {
                 throw new \Exception('No astronomy data available');
             }
 
-            return $data;
-        });
-    }
-
-    /**
-     * Получить список доступных небесных тел
-     */
-    public function getBodies(): array
-    {
-        return Cache::remember('astronomy:bodies', 3600, function () {
-            $auth = base64_encode("{$this->appId}:{$this->appSecret}");
-            
-            $response = Http::timeout($this->timeout)
-                ->withHeaders([
-                    'Authorization' => "Basic {$auth}",
-                ])
-                ->get("{$this->baseUrl}/bodies");
-
-            if (!$response->successful()) {
-                throw new \Exception("Astronomy API failed: {$response->status()}");
-            }
-
-            return $response->json();
+            // Представление ожидает исходный формат ответа AstronomyAPI
+            return ['data' => $data['data']];
         });
     }
-}
\ No newline at end of file
+}
@@ -1,17 +1,18 @@
-use crate::domain::error::ApiError;
+use crate::domain::{error::ApiError, provenance};
 use reqwest::Client;
 use serde_json::Value;
 use std::time::Duration;
-use base64::{Engine as _, engine::general_purpose}; 
+use base64::{Engine as _, engine::general_purpose};
 
 pub struct AstronomyClient {
     client: Client,
+    base_url: String,
     app_id: String,
     app_secret: String,
 }
 
 impl AstronomyClient {
-    pub fn new(app_id: String, app_secret: String) -> Result<Self, ApiError> {
+    pub fn new(base_url: String, app_id: String, app_secret: String) -> Result<Self, ApiError> {
         let client = Client::builder()
             .timeout(Duration::from_secs(20))
             .user_agent("CassiopeiaBot/1.0 (Space Data Collector)")
@@ -20,20 +21,45 @@ impl AstronomyClient {
 
         Ok(Self {
             client,
+            base_url: base_url.trim_end_matches('/').to_string(),
             app_id,
             app_secret,
         })
     }
 
-    pub async fn fetch_events(&self) -> Result<Value, ApiError> {
-        let url = "https://api.astronomyapi.com/api/v2/bodies/events";
+    /// Заданы ли учётные данные AstronomyAPI
+    pub fn is_configured(&self) -> bool {
+        !self.app_id.is_empty() && !self.app_secret.is_empty()
+    }
+
+    /// GET /bodies/events/{body} - затмения и другие события тела (sun, moon)
+    pub async fn fetch_events(&self, body: &str, params: &[(&str, String)]) -> Result<Value, ApiError> {
+        self.fetch(&format!("bodies/events/{}", body), params).await
+    }
+
+    /// GET /bodies/positions - положения тел на каждую дату диапазона
+    pub async fn fetch_positions(&self, params: &[(&str, String)]) -> Result<Value, ApiError> {
+        self.fetch("bodies/positions", params).await
+    }
+
+    async fn fetch(&self, path: &str, params: &[(&str, String)]) -> Result<Value, ApiError> {
+        if !self.is_configured() {
+            return Err(ApiError::UpstreamError(
+                "AstronomyAPI credentials are not configured (ASTRO_APP_ID, ASTRO_APP_SECRET)".to_string(),
+            ));
+        }
+
+        let url = format!("{}/{}", self.base_url, path);
 
         let mut retries = 0;
         let max_retries = 3;
 
         loop {
-            match self.try_fetch(url).await {
-                Ok(data) => return Ok(data),
+            match self.try_fetch(&url, params).await {
+                Ok(data) => {
+                    provenance::record_upstream("astronomyapi");
+                    return Ok(data);
+                }
                 Err(e) if retries < max_retries => {
                     retries += 1;
                     tracing::warn!("Astronomy API fetch attempt {} failed: {}", retries, e);
@@ -49,14 +75,15 @@ impl AstronomyClient {
         }
     }
 
-    async fn try_fetch(&self, url: &str) -> Result<Value, String> {
+    async fn try_fetch(&self, url: &str, params: &[(&str, String)]) -> Result<Value, String> {
         let auth = format!("{}:{}", self.app_id, self.app_secret);
-       
+
         let basic_auth = format!("Basic {}", general_purpose::STANDARD.encode(&auth));
 
         let response = self
             .client
             .get(url)
+            .query(params)
             .header("Authorization", basic_auth)
             .send()
             .await
@@ -66,9 +93,14 @@ impl AstronomyClient {
             return Err(format!("HTTP {}", response.status()));
         }
 
-        response
+        let body = response
             .json::<Value>()
             .await
-            .map_err(|e| format!("JSON parse error: {}", e))
+            .map_err(|e| format!("JSON parse error: {}", e))?;
+
+        // Полезная нагрузка ответа лежит в поле data
+        body.get("data")
+            .cloned()
+            .ok_or_else(|| "Response has no data field".to_string())
     }
-}
\ No newline at end of file
+}
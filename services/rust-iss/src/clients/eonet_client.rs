@@ -0,0 +1,70 @@
+use crate::domain::{error::ApiError, models::EONET_CATEGORIES, provenance};
+use reqwest::Client;
+use serde_json::Value;
+use std::time::Duration;
+
+pub struct EonetClient {
+    client: Client,
+}
+
+impl EonetClient {
+    pub fn new() -> Result<Self, ApiError> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(30))
+            .user_agent("CassiopeiaBot/1.0 (Space Data Collector)")
+            .build()
+            .map_err(|e| ApiError::InternalError(format!("Failed to create HTTP client: {}", e)))?;
+
+        Ok(Self { client })
+    }
+
+    /// Открытые и закрытые события EONET (пожары, штормы, вулканы), активные за последние days дней
+    pub async fn fetch_events(&self, days: u32) -> Result<Value, ApiError> {
+        let url = format!(
+            "https://eonet.gsfc.nasa.gov/api/v3/events?category={}&status=all&days={}",
+            EONET_CATEGORIES.join(","),
+            days
+        );
+
+        let mut retries = 0;
+        let max_retries = 3;
+
+        loop {
+            match self.try_fetch(&url).await {
+                Ok(data) => {
+                    provenance::record_upstream("eonet");
+                    return Ok(data);
+                }
+                Err(e) if retries < max_retries => {
+                    retries += 1;
+                    tracing::warn!("EONET fetch attempt {} failed: {}", retries, e);
+                    tokio::time::sleep(Duration::from_millis(2000 * retries)).await;
+                }
+                Err(e) => {
+                    return Err(ApiError::UpstreamError(format!(
+                        "EONET API failed after {} retries: {}",
+                        max_retries, e
+                    )));
+                }
+            }
+        }
+    }
+
+    async fn try_fetch(&self, url: &str) -> Result<Value, String> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()));
+        }
+
+        response
+            .json::<Value>()
+            .await
+            .map_err(|e| format!("JSON parse error: {}", e))
+    }
+}
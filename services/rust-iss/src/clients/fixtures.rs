@@ -0,0 +1,83 @@
+//! Демонстрационные данные на случай недоступности внешних API.
+//! Отдаются только в ответе с meta.degraded = true и никогда не пишутся в Postgres или Redis.
+
+use crate::domain::models::{OsdrApiDataset, OsdrApiResponse};
+use serde_json::Value;
+
+/// Файлы JWST в формате ответа jwstapi.com
+pub fn jwst_images() -> Value {
+    serde_json::json!([
+        {
+            "id": "demo_1",
+            "program": "Mock Demo Data - Carina Nebula",
+            "observation_id": "jw02731-o001_t001_nircam_clear-f200w",
+            "suffix": "i2d",
+            "details": {
+                "mission": "JWST",
+                "instruments": ["NIRCAM"],
+                "filters": ["F200W"]
+            },
+            "file_type": "jpg",
+            "thumbnail": "https://www.nasa.gov/wp-content/uploads/2023/03/main_image_star-forming_region_carina_nircam_final-5mb.jpg?resize=768,768",
+            "location": "https://www.nasa.gov/wp-content/uploads/2023/03/main_image_star-forming_region_carina_nircam_final-5mb.jpg"
+        },
+        {
+            "id": "demo_2",
+            "program": "Mock Demo Data - Pillars of Creation",
+            "observation_id": "jw02731-o001_t001_miri_f1130w",
+            "suffix": "i2d",
+            "details": {
+                "mission": "JWST",
+                "instruments": ["MIRI"],
+                "filters": ["F1130W"]
+            },
+            "file_type": "jpg",
+            "thumbnail": "https://www.nasa.gov/wp-content/uploads/2023/03/main_image_deep_field_smacs0723-5mb.jpg?resize=768,768",
+            "location": "https://www.nasa.gov/wp-content/uploads/2023/03/main_image_deep_field_smacs0723-5mb.jpg"
+        }
+    ])
+}
+
+/// Датасеты OSDR в формате ответа API
+pub fn osdr_datasets() -> OsdrApiResponse {
+    OsdrApiResponse {
+        from_upstream: false,
+        results: vec![
+            OsdrApiDataset {
+                dataset_id: "GLDS-379".to_string(),
+                title: "Rodent Research-1 (RR-1): Spaceflight-induced bone loss and immune dysregulation".to_string(),
+                description: Some("Gene expression changes in mice exposed to spaceflight environment".to_string()),
+                release_date: Some("2019-06-01".to_string()),
+                doi: None,
+            },
+            OsdrApiDataset {
+                dataset_id: "GLDS-120".to_string(),
+                title: "NASA Twins Study: Integrated multi-omics analysis".to_string(),
+                description: Some("Comprehensive genomic comparison of astronaut twin in space vs on Earth".to_string()),
+                release_date: Some("2019-04-11".to_string()),
+                doi: None,
+            },
+            OsdrApiDataset {
+                dataset_id: "GLDS-38".to_string(),
+                title: "APEX-03: Plant root gravitropism in microgravity".to_string(),
+                description: Some("Arabidopsis thaliana root growth patterns in space environment".to_string()),
+                release_date: Some("2018-09-15".to_string()),
+                doi: None,
+            },
+            OsdrApiDataset {
+                dataset_id: "GLDS-47".to_string(),
+                title: "BRIC-19: C. elegans development in spaceflight".to_string(),
+                description: Some("Effects of microgravity on nematode muscle development".to_string()),
+                release_date: Some("2017-03-20".to_string()),
+                doi: None,
+            },
+            OsdrApiDataset {
+                dataset_id: "GLDS-251".to_string(),
+                title: "Cardiovascular changes during long-duration spaceflight".to_string(),
+                description: Some("Physiological adaptations of human cardiovascular system in space".to_string()),
+                release_date: Some("2020-11-08".to_string()),
+                doi: None,
+            },
+        ],
+    }
+}
@@ -1,4 +1,4 @@
-use crate::domain::{error::ApiError, models::IssApiResponse};
+use crate::domain::{error::ApiError, models::{IssApiResponse, IssApiTle}, provenance};
 use reqwest::Client;
 use std::time::Duration;
 
@@ -24,7 +24,10 @@ impl IssClient {
 
         loop {
             match self.try_fetch().await {
-                Ok(data) => return Ok(data),
+                Ok(data) => {
+                    provenance::record_upstream("wheretheiss");
+                    return Ok(data);
+                }
                 Err(e) if retries < max_retries => {
                     retries += 1;
                     tracing::warn!("ISS fetch attempt {} failed: {}", retries, e);
@@ -57,4 +60,22 @@ impl IssClient {
             .await
             .map_err(|e| format!("JSON parse error: {}", e))
     }
-}
\ No newline at end of file
+
+    /// Получить актуальные элементы орбиты (TLE) МКС
+    pub async fn fetch_tle(&self) -> Result<IssApiTle, ApiError> {
+        let response = self
+            .client
+            .get(format!("{}/tles", self.base_url))
+            .query(&[("format", "json")])
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(ApiError::UpstreamError(format!("ISS TLE API returned {}", response.status())));
+        }
+
+        let tle = response.json::<IssApiTle>().await?;
+        provenance::record_upstream("wheretheiss");
+        Ok(tle)
+    }
+}
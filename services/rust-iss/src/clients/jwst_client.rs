@@ -1,5 +1,5 @@
-use crate::domain::error::ApiError;
-use reqwest::Client;
+use crate::domain::{error::ApiError, provenance};
+use reqwest::{header::RANGE, Client, StatusCode};
 use serde_json::Value;
 use std::time::Duration;
 
@@ -7,10 +7,11 @@ pub struct JwstClient {
     client: Client,
     base_url: String,
     api_key: String,
+    product_url: String,
 }
 
 impl JwstClient {
-    pub fn new(base_url: String, api_key: String) -> Result<Self, ApiError> {
+    pub fn new(base_url: String, api_key: String, product_url: String) -> Result<Self, ApiError> {
         let client = Client::builder()
             .timeout(Duration::from_secs(30))
             .user_agent("CassiopeiaBot/1.0 (Space Data Collector)")
@@ -21,15 +22,53 @@ impl JwstClient {
             client,
             base_url,
             api_key,
+            product_url,
         })
     }
 
+    /// URL продукта (FITS) по имени файла
+    pub fn product_url(&self, file: &str) -> String {
+        format!("{}{}", self.product_url, file)
+    }
+
+    /// Байты файла [offset, offset + len) через HTTP Range; пустой результат - конец файла
+    pub async fn fetch_range(&self, url: &str, offset: u64, len: usize) -> Result<Vec<u8>, ApiError> {
+        let mut response = self
+            .client
+            .get(url)
+            .header(RANGE, format!("bytes={}-{}", offset, offset + len as u64 - 1))
+            .send()
+            .await?;
+
+        match response.status() {
+            StatusCode::RANGE_NOT_SATISFIABLE => return Ok(Vec::new()),
+            StatusCode::PARTIAL_CONTENT => {}
+            // Сервер без поддержки Range отдаёт файл целиком - годится только начало
+            StatusCode::OK if offset == 0 => {}
+            StatusCode::OK => {
+                return Err(ApiError::UpstreamError(format!("{} does not support range requests", url)));
+            }
+            status => return Err(ApiError::UpstreamError(format!("HTTP {} for {}", status, url))),
+        }
+
+        let mut body = Vec::with_capacity(len);
+        while let Some(chunk) = response.chunk().await? {
+            body.extend_from_slice(&chunk);
+            if body.len() >= len {
+                body.truncate(len);
+                break;
+            }
+        }
+
+        provenance::record_upstream("mast");
+        Ok(body)
+    }
+
     pub async fn fetch_images(&self, program_id: &str) -> Result<Value, ApiError> {
-        // JWST API v0.0.17 - пробуем разные endpoints
+        // JWST API v0.0.17 - пробуем разные endpoints (без /all: весь архив не относится к программе)
         let endpoints = vec![
             format!("{}/program/{}/images", self.base_url, program_id),
             format!("{}/images?program={}", self.base_url, program_id),
-            format!("{}/all", self.base_url), // Fallback: все изображения
         ];
 
         let mut last_error = String::new();
@@ -38,6 +77,7 @@ impl JwstClient {
             match self.try_fetch(&url).await {
                 Ok(data) => {
                     tracing::info!("JWST images fetched successfully from {}", url);
+                    provenance::record_upstream("jwst");
                     return Ok(data);
                 }
                 Err(e) => {
@@ -48,42 +88,7 @@ impl JwstClient {
             }
         }
 
-        // Если все endpoints недоступны - возвращаем mock данные для демонстрации
-        tracing::error!("All JWST endpoints failed, returning mock data. Last error: {}", last_error);
-        Ok(self.mock_jwst_data())
-    }
-
-    fn mock_jwst_data(&self) -> Value {
-        serde_json::json!([
-            {
-                "id": "demo_1",
-                "program": "Mock Demo Data - Carina Nebula",
-                "observation_id": "jw02731-o001_t001_nircam_clear-f200w",
-                "suffix": "i2d",
-                "details": {
-                    "mission": "JWST",
-                    "instruments": ["NIRCAM"],
-                    "filters": ["F200W"]
-                },
-                "file_type": "jpg",
-                "thumbnail": "https://www.nasa.gov/wp-content/uploads/2023/03/main_image_star-forming_region_carina_nircam_final-5mb.jpg?resize=768,768",
-                "location": "https://www.nasa.gov/wp-content/uploads/2023/03/main_image_star-forming_region_carina_nircam_final-5mb.jpg"
-            },
-            {
-                "id": "demo_2",
-                "program": "Mock Demo Data - Pillars of Creation",
-                "observation_id": "jw02731-o001_t001_miri_f1130w",
-                "suffix": "i2d",
-                "details": {
-                    "mission": "JWST",
-                    "instruments": ["MIRI"],
-                    "filters": ["F1130W"]
-                },
-                "file_type": "jpg",
-                "thumbnail": "https://www.nasa.gov/wp-content/uploads/2023/03/main_image_deep_field_smacs0723-5mb.jpg?resize=768,768",
-                "location": "https://www.nasa.gov/wp-content/uploads/2023/03/main_image_deep_field_smacs0723-5mb.jpg"
-            }
-        ])
+        Err(ApiError::UpstreamError(format!("All JWST endpoints failed. Last error: {}", last_error)))
     }
 
     async fn try_fetch(&self, url: &str) -> Result<Value, String> {
@@ -0,0 +1,53 @@
+use crate::domain::error::ApiError;
+use reqwest::{redirect, Client};
+use std::time::Duration;
+
+pub struct MediaClient {
+    client: Client,
+}
+
+impl MediaClient {
+    pub fn new() -> Result<Self, ApiError> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(120))
+            .user_agent("CassiopeiaBot/1.0 (Space Data Collector)")
+            // URL приходят из ответов APOD/JWST: редирект мог бы увести загрузку на внутренний адрес
+            .redirect(redirect::Policy::none())
+            .build()
+            .map_err(|e| ApiError::InternalError(format!("Failed to create HTTP client: {}", e)))?;
+
+        Ok(Self { client })
+    }
+
+    /// Скачать файл целиком, не более max_bytes
+    pub async fn download(&self, url: &str, max_bytes: u64) -> Result<Vec<u8>, String> {
+        let mut response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()));
+        }
+
+        if response.content_length().is_some_and(|len| len > max_bytes) {
+            return Err(format!("File exceeds {} bytes", max_bytes));
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| format!("Download failed: {}", e))?
+        {
+            body.extend_from_slice(&chunk);
+            if body.len() as u64 > max_bytes {
+                return Err(format!("File exceeds {} bytes", max_bytes));
+            }
+        }
+
+        Ok(body)
+    }
+}
@@ -2,6 +2,10 @@ pub mod iss_client;
 pub mod osdr_client;
 pub mod jwst_client;
 pub mod astronomy_client;
+pub mod eonet_client;
+pub mod fixtures;
+pub mod media_client;
+pub mod nasa_budget;
 pub mod nasa_client;
 pub mod spacex_client;
 
@@ -9,5 +13,7 @@ pub use iss_client::IssClient;
 pub use osdr_client::OsdrClient;
 pub use jwst_client::JwstClient;
 pub use astronomy_client::AstronomyClient;
+pub use eonet_client::EonetClient;
+pub use media_client::MediaClient;
 pub use nasa_client::NasaClient;
 pub use spacex_client::SpaceXClient;
\ No newline at end of file
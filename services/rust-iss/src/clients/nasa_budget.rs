@@ -0,0 +1,114 @@
+use crate::domain::error::ApiError;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Окно лимита api.nasa.gov (лимит почасовой, скользящий)
+const QUOTA_WINDOW: Duration = Duration::from_secs(3600);
+
+/// Кто инициировал запрос к NASA API
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestPriority {
+    /// Плановое обновление планировщиком
+    Scheduled,
+    /// Промах кэша при запросе пользователя
+    OnDemand,
+}
+
+impl RequestPriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RequestPriority::Scheduled => "scheduled",
+            RequestPriority::OnDemand => "on_demand",
+        }
+    }
+}
+
+/// Последние значения X-RateLimit-Limit / X-RateLimit-Remaining
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QuotaState {
+    pub limit: Option<i64>,
+    pub remaining: Option<i64>,
+    pub updated_at: Option<Instant>,
+}
+
+/// Бюджет запросов к NASA API для одного ключа.
+/// Часть лимита (reserve_percent) оставляется плановым обновлениям.
+pub struct NasaBudget {
+    state: Mutex<QuotaState>,
+    reserve_percent: u8,
+}
+
+impl NasaBudget {
+    pub fn new(reserve_percent: u8) -> Self {
+        Self {
+            state: Mutex::new(QuotaState::default()),
+            reserve_percent: reserve_percent.min(100),
+        }
+    }
+
+    /// Запомнить заголовки ответа
+    pub fn record(&self, limit: Option<i64>, remaining: Option<i64>) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if limit.is_some() {
+            state.limit = limit;
+        }
+        state.remaining = remaining;
+        state.updated_at = Some(Instant::now());
+    }
+
+    /// Лимит исчерпан (HTTP 429)
+    pub fn record_exhausted(&self) {
+        self.record(None, Some(0));
+    }
+
+    pub fn snapshot(&self) -> QuotaState {
+        *self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Можно ли сейчас тратить запрос с данным приоритетом
+    pub fn allows(&self, priority: RequestPriority) -> bool {
+        let state = self.snapshot();
+        let remaining = match state.updated_at {
+            Some(at) if at.elapsed() < QUOTA_WINDOW => state.remaining,
+            // Данных нет или окно прошло - лимит восстановился
+            _ => None,
+        };
+        budget_allows(state.limit, remaining, self.reserve_percent, priority)
+    }
+}
+
+/// Плановые обновления тратят лимит до нуля, пользовательские промахи - только сверх резерва
+pub fn budget_allows(
+    limit: Option<i64>,
+    remaining: Option<i64>,
+    reserve_percent: u8,
+    priority: RequestPriority,
+) -> bool {
+    let Some(remaining) = remaining else {
+        return true;
+    };
+
+    match priority {
+        RequestPriority::Scheduled => remaining > 0,
+        RequestPriority::OnDemand => {
+            let reserve = limit.map_or(0, |limit| (limit * i64::from(reserve_percent) + 99) / 100);
+            remaining > reserve.max(1)
+        }
+    }
+}
+
+/// Лимит NASA API почти исчерпан, а устаревшей копии нет
+pub fn quota_exhausted() -> ApiError {
+    ApiError::UpstreamError("NASA API quota is nearly exhausted, try again later".to_string())
+}
+
+/// Метка ключа для метрик без раскрытия самого ключа
+pub fn key_label(api_key: &str) -> String {
+    if api_key == "DEMO_KEY" || api_key.len() <= 4 {
+        return api_key.to_string();
+    }
+    format!("...{}", &api_key[api_key.len() - 4..])
+}
+
+#[cfg(test)]
+mod tests;
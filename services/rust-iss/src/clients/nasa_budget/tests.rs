@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_unknown_quota_allows_everything() {
+        assert!(budget_allows(None, None, 20, RequestPriority::OnDemand));
+        assert!(budget_allows(Some(30), None, 20, RequestPriority::Scheduled));
+    }
+
+    #[test]
+    fn test_reserve_is_kept_for_scheduler() {
+        // DEMO_KEY: 30 в час, 20% резерва = 6 запросов
+        assert!(budget_allows(Some(30), Some(7), 20, RequestPriority::OnDemand));
+        assert!(!budget_allows(Some(30), Some(6), 20, RequestPriority::OnDemand));
+        assert!(budget_allows(Some(30), Some(1), 20, RequestPriority::Scheduled));
+        assert!(!budget_allows(Some(30), Some(0), 20, RequestPriority::Scheduled));
+    }
+
+    #[test]
+    fn test_on_demand_never_spends_last_request() {
+        assert!(!budget_allows(None, Some(1), 0, RequestPriority::OnDemand));
+        assert!(budget_allows(Some(1000), Some(2), 0, RequestPriority::OnDemand));
+    }
+
+    #[test]
+    fn test_budget_tracks_headers() {
+        let budget = NasaBudget::new(20);
+        assert!(budget.allows(RequestPriority::OnDemand));
+
+        budget.record(Some(30), Some(5));
+        assert!(!budget.allows(RequestPriority::OnDemand));
+        assert!(budget.allows(RequestPriority::Scheduled));
+
+        budget.record_exhausted();
+        assert_eq!(budget.snapshot().limit, Some(30));
+        assert!(!budget.allows(RequestPriority::Scheduled));
+    }
+
+    #[test]
+    fn test_key_label_masks_key() {
+        assert_eq!(key_label("DEMO_KEY"), "DEMO_KEY");
+        assert_eq!(key_label("abcdefgh1234"), "...1234");
+    }
+}
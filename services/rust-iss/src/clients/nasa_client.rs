@@ -1,49 +1,116 @@
-use crate::domain::error::ApiError;
-use reqwest::Client;
+use crate::{
+    clients::nasa_budget::{key_label, NasaBudget, RequestPriority},
+    domain::{
+        error::ApiError,
+        models::{DonkiEventType, EpicCollection},
+        provenance,
+    },
+};
+use reqwest::{header::HeaderMap, Client, StatusCode};
 use serde_json::Value;
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
+/// Клоны делят один бюджет лимита ключа
+#[derive(Clone)]
 pub struct NasaClient {
     client: Client,
     api_key: String,
+    budget: Arc<NasaBudget>,
 }
 
 impl NasaClient {
-    pub fn new(api_key: String) -> Result<Self, ApiError> {
+    pub fn new(api_key: String, quota_reserve_percent: u8) -> Result<Self, ApiError> {
         let client = Client::builder()
             .timeout(Duration::from_secs(30))
             .user_agent("CassiopeiaBot/1.0 (Space Data Collector)")
             .build()
             .map_err(|e| ApiError::InternalError(format!("Failed to create HTTP client: {}", e)))?;
 
-        Ok(Self { client, api_key })
+        let api_key = if api_key.is_empty() { "DEMO_KEY".to_string() } else { api_key };
+
+        Ok(Self {
+            client,
+            api_key,
+            budget: Arc::new(NasaBudget::new(quota_reserve_percent)),
+        })
+    }
+
+    /// Можно ли потратить запрос из лимита ключа; отказ учитывается в метриках
+    pub fn has_budget(&self, priority: RequestPriority) -> bool {
+        let allowed = self.budget.allows(priority);
+        if !allowed {
+            crate::utils::metrics::record_nasa_budget_denied(priority.as_str());
+        }
+        allowed
     }
 
     pub async fn fetch_apod(&self) -> Result<Value, ApiError> {
         self.fetch_with_retry("https://api.nasa.gov/planetary/apod").await
     }
 
-    pub async fn fetch_neo(&self, start_date: &str, end_date: &str) -> Result<Value, ApiError> {
+    /// APOD за конкретную дату (объект)
+    pub async fn fetch_apod_date(&self, date: &str) -> Result<Value, ApiError> {
+        let url = format!("https://api.nasa.gov/planetary/apod?date={}", date);
+        self.fetch_with_retry(&url).await
+    }
+
+    /// APOD за диапазон дат (массив, start_date/end_date включительно)
+    pub async fn fetch_apod_range(&self, start_date: &str, end_date: &str) -> Result<Value, ApiError> {
         let url = format!(
-            "https://api.nasa.gov/neo/rest/v1/feed?start_date={}&end_date={}",
+            "https://api.nasa.gov/planetary/apod?start_date={}&end_date={}",
             start_date, end_date
         );
         self.fetch_with_retry(&url).await
     }
 
-    pub async fn fetch_donki_flr(&self, start_date: &str, end_date: &str) -> Result<Value, ApiError> {
+    pub async fn fetch_neo(&self, start_date: &str, end_date: &str) -> Result<Value, ApiError> {
         let url = format!(
-            "https://api.nasa.gov/DONKI/FLR?startDate={}&endDate={}",
+            "https://api.nasa.gov/neo/rest/v1/feed?start_date={}&end_date={}",
             start_date, end_date
         );
         self.fetch_with_retry(&url).await
     }
 
-    pub async fn fetch_donki_cme(&self, start_date: &str, end_date: &str) -> Result<Value, ApiError> {
-        let url = format!(
-            "https://api.nasa.gov/DONKI/CME?startDate={}&endDate={}",
-            start_date, end_date
+    /// NeoWs lookup: объект с орбитальными элементами и всеми сближениями
+    pub async fn fetch_neo_lookup(&self, neo_id: &str) -> Result<Value, ApiError> {
+        let url = format!("https://api.nasa.gov/neo/rest/v1/neo/{}", neo_id);
+        self.fetch_with_retry(&url).await
+    }
+
+    /// События DONKI указанного типа за период (даты включительно)
+    pub async fn fetch_donki(
+        &self,
+        event_type: DonkiEventType,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Value, ApiError> {
+        let mut url = format!(
+            "https://api.nasa.gov/DONKI/{}?startDate={}&endDate={}",
+            event_type.api_path(),
+            start_date,
+            end_date
         );
+        if event_type == DonkiEventType::Notification {
+            url.push_str("&type=all");
+        }
+        self.fetch_with_retry(&url).await
+    }
+
+    /// EPIC: снимки последнего доступного дня
+    pub async fn fetch_epic_latest(&self, collection: EpicCollection) -> Result<Value, ApiError> {
+        let url = format!("https://api.nasa.gov/EPIC/api/{}/images", collection.as_str());
+        self.fetch_with_retry(&url).await
+    }
+
+    /// EPIC: снимки за дату (YYYY-MM-DD)
+    pub async fn fetch_epic_date(&self, collection: EpicCollection, date: &str) -> Result<Value, ApiError> {
+        let url = format!("https://api.nasa.gov/EPIC/api/{}/date/{}", collection.as_str(), date);
+        self.fetch_with_retry(&url).await
+    }
+
+    /// EPIC: даты, за которые есть снимки
+    pub async fn fetch_epic_available(&self, collection: EpicCollection) -> Result<Value, ApiError> {
+        let url = format!("https://api.nasa.gov/EPIC/api/{}/available", collection.as_str());
         self.fetch_with_retry(&url).await
     }
 
@@ -53,8 +120,12 @@ impl NasaClient {
 
         loop {
             match self.try_fetch(url).await {
-                Ok(data) => return Ok(data),
-                Err(e) if retries < max_retries => {
+                Ok(data) => {
+                    provenance::record_upstream("nasa");
+                    return Ok(data);
+                }
+                // Повтор после 429 только сожжёт ещё запрос
+                Err(e) if retries < max_retries && self.budget.allows(RequestPriority::Scheduled) => {
                     retries += 1;
                     tracing::warn!("NASA API fetch attempt {} failed: {}", retries, e);
                     tokio::time::sleep(Duration::from_millis(2000 * retries)).await;
@@ -70,24 +141,50 @@ impl NasaClient {
     }
 
     async fn try_fetch(&self, url: &str) -> Result<Value, String> {
-        let mut request = self.client.get(url);
-
-        if !self.api_key.is_empty() && self.api_key != "DEMO_KEY" {
-            request = request.query(&[("api_key", &self.api_key)]);
-        }
-
-        let response = request
+        let response = self
+            .client
+            .get(url)
+            .query(&[("api_key", &self.api_key)])
             .send()
             .await
             .map_err(|e| format!("Request failed: {}", e))?;
 
+        self.record_quota(response.headers());
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            self.budget.record_exhausted();
+            crate::utils::metrics::record_nasa_quota(&key_label(&self.api_key), None, Some(0));
+        }
+
         if !response.status().is_success() {
             return Err(format!("HTTP {}", response.status()));
         }
 
-        response
-            .json::<Value>()
+        // DONKI отвечает пустым телом, когда за период нет событий
+        let body = response
+            .text()
             .await
-            .map_err(|e| format!("JSON parse error: {}", e))
+            .map_err(|e| format!("Failed to read body: {}", e))?;
+        if body.trim().is_empty() {
+            return Ok(Value::Null);
+        }
+
+        serde_json::from_str(&body).map_err(|e| format!("JSON parse error: {}", e))
     }
-}
\ No newline at end of file
+
+    /// Обновить бюджет и метрики по заголовкам X-RateLimit-*
+    fn record_quota(&self, headers: &HeaderMap) {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<i64>().ok())
+        };
+        let limit = header("x-ratelimit-limit");
+        let remaining = header("x-ratelimit-remaining");
+
+        if remaining.is_some() {
+            self.budget.record(limit, remaining);
+            crate::utils::metrics::record_nasa_quota(&key_label(&self.api_key), limit, remaining);
+        }
+    }
+}
@@ -1,8 +1,16 @@
-use crate::domain::error::ApiError;
+use crate::domain::{error::ApiError, models::SpaceXQueryPage, provenance};
 use reqwest::Client;
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::time::Duration;
 
+const API_URL: &str = "https://api.spacexdata.com/v4";
+
+/// Размер страницы POST /v4/{collection}/query
+const QUERY_PAGE_SIZE: i64 = 100;
+
+/// Защита от бесконечной пагинации
+const MAX_QUERY_PAGES: i64 = 50;
+
 pub struct SpaceXClient {
     client: Client,
 }
@@ -19,14 +27,17 @@ impl SpaceXClient {
     }
 
     pub async fn fetch_next_launch(&self) -> Result<Value, ApiError> {
-        let url = "https://api.spacexdata.com/v4/launches/next";
+        let url = format!("{}/launches/next", API_URL);
 
         let mut retries = 0;
         let max_retries = 3;
 
         loop {
-            match self.try_fetch(url).await {
-                Ok(data) => return Ok(data),
+            match self.try_fetch(&url).await {
+                Ok(data) => {
+                    provenance::record_upstream("spacex");
+                    return Ok(data);
+                }
                 Err(e) if retries < max_retries => {
                     retries += 1;
                     tracing::warn!("SpaceX fetch attempt {} failed: {}", retries, e);
@@ -42,6 +53,71 @@ impl SpaceXClient {
         }
     }
 
+    /// Все документы коллекции (launches, rockets, launchpads, payloads) постранично через POST /query
+    pub async fn query_all(&self, collection: &str) -> Result<Vec<Value>, ApiError> {
+        let url = format!("{}/{}/query", API_URL, collection);
+        let mut docs = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let body = json!({
+                "query": {},
+                "options": { "page": page, "limit": QUERY_PAGE_SIZE, "pagination": true }
+            });
+            let response = self.query_with_retry(&url, &body).await?;
+            docs.extend(response.docs);
+
+            match response.next_page {
+                Some(next) if next > page && next <= MAX_QUERY_PAGES => page = next,
+                _ => break,
+            }
+        }
+
+        provenance::record_upstream("spacex");
+        Ok(docs)
+    }
+
+    async fn query_with_retry(&self, url: &str, body: &Value) -> Result<SpaceXQueryPage, ApiError> {
+        let mut retries = 0;
+        let max_retries = 3;
+
+        loop {
+            match self.try_query(url, body).await {
+                Ok(page) => return Ok(page),
+                Err(e) if retries < max_retries => {
+                    retries += 1;
+                    tracing::warn!("SpaceX query attempt {} failed: {}", retries, e);
+                    tokio::time::sleep(Duration::from_millis(1000 * retries)).await;
+                }
+                Err(e) => {
+                    return Err(ApiError::UpstreamError(format!(
+                        "SpaceX API query failed after {} retries: {}",
+                        max_retries, e
+                    )));
+                }
+            }
+        }
+    }
+
+    async fn try_query(&self, url: &str, body: &Value) -> Result<SpaceXQueryPage, String> {
+        let response = self
+            .client
+            .post(url)
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()));
+        }
+
+        response
+            .json::<SpaceXQueryPage>()
+            .await
+            .map_err(|e| format!("JSON parse error: {}", e))
+    }
+
     async fn try_fetch(&self, url: &str) -> Result<Value, String> {
         let response = self
             .client
@@ -10,14 +10,51 @@ pub struct Config {
     // External APIs
     pub nasa_api_url: String,
     pub nasa_api_key: String,
+    pub nasa_quota_reserve_percent: u8,
     pub where_iss_url: String,
+    pub jwst_api_url: String,
+    pub jwst_api_key: String,
+    // Скачивание продуктов JWST (FITS) по имени файла
+    pub jwst_product_url: String,
+    // Программы JWST, которые планировщик держит в каталоге
+    pub jwst_tracked_programs: Vec<String>,
+    // AstronomyAPI (Basic auth: app id + secret); без них /astro/events и /astro/positions недоступны
+    pub astro_api_url: String,
+    pub astro_app_id: String,
+    pub astro_app_secret: String,
+    // Демонстрационные данные при недоступности API (только в ответе, с meta.degraded)
+    pub upstream_fallbacks: bool,
     
     // Scheduler intervals (seconds)
     pub iss_every_seconds: u64,
     pub apod_every_seconds: u64,
     pub neo_every_seconds: u64,
     pub donki_every_seconds: u64,
+    pub epic_every_seconds: u64,
+    pub eonet_every_seconds: u64,
     pub spacex_every_seconds: u64,
+    pub jwst_every_seconds: u64,
+    
+    // OSDR tombstones retention (days)
+    pub osdr_tombstone_retention_days: i64,
+    
+    // SMTP для уведомлений watchlist (без SMTP_HOST email-канал отключён)
+    pub smtp_host: Option<String>,
+    pub smtp_port: u16,
+    pub smtp_starttls: bool,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    pub smtp_from: String,
+    
+    // Разрешить вебхуки на адреса локальной сети (только для dev: по умолчанию запрещены против SSRF)
+    pub webhook_allow_private_targets: bool,
+    
+    // Локальное зеркало изображений APOD/JWST
+    pub media_dir: String,
+    pub media_quota_mb: u64,
+    pub media_max_download_mb: u64,
+    pub media_thumb_size: u32,
+    pub media_mirror_every_seconds: u64,
     
     // Rate limiting
     pub rate_limit_per_minute: u32,
@@ -41,8 +78,31 @@ impl Config {
                 .unwrap_or_else(|_| "https://api.nasa.gov".to_string()),
             nasa_api_key: env::var("NASA_API_KEY")
                 .unwrap_or_else(|_| "DEMO_KEY".to_string()),
+            nasa_quota_reserve_percent: env::var("NASA_QUOTA_RESERVE_PERCENT")
+                .unwrap_or_else(|_| "20".to_string())
+                .parse()
+                .unwrap_or(20),
             where_iss_url: env::var("WHERE_ISS_URL")
                 .unwrap_or_else(|_| "https://api.wheretheiss.at/v1/satellites/25544".to_string()),
+            jwst_api_url: env::var("JWST_HOST")
+                .map(|url| url.trim_end_matches('/').to_string())
+                .unwrap_or_else(|_| "https://api.jwstapi.com".to_string()),
+            jwst_api_key: env::var("JWST_API_KEY").unwrap_or_default(),
+            jwst_product_url: env::var("JWST_PRODUCT_URL")
+                .unwrap_or_else(|_| "https://mast.stsci.edu/api/v0.1/Download/file?uri=mast:JWST/product/".to_string()),
+            jwst_tracked_programs: env::var("JWST_TRACKED_PROGRAMS")
+                .unwrap_or_else(|_| "2731,2732,2733,2734,2736".to_string())
+                .split(',')
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect(),
+            astro_api_url: env::var("ASTRO_API_URL")
+                .unwrap_or_else(|_| "https://api.astronomyapi.com/api/v2".to_string()),
+            astro_app_id: env::var("ASTRO_APP_ID").unwrap_or_default(),
+            astro_app_secret: env::var("ASTRO_APP_SECRET").unwrap_or_default(),
+            upstream_fallbacks: env::var("UPSTREAM_FALLBACKS")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(true),
             
             iss_every_seconds: env::var("ISS_EVERY_SECONDS")
                 .unwrap_or_else(|_| "120".to_string())
@@ -60,10 +120,62 @@ impl Config {
                 .unwrap_or_else(|_| "3600".to_string())
                 .parse()
                 .unwrap_or(3600),
+            epic_every_seconds: env::var("EPIC_EVERY_SECONDS")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .unwrap_or(3600),
+            eonet_every_seconds: env::var("EONET_EVERY_SECONDS")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .unwrap_or(3600),
             spacex_every_seconds: env::var("SPACEX_EVERY_SECONDS")
                 .unwrap_or_else(|_| "3600".to_string())
                 .parse()
                 .unwrap_or(3600),
+            jwst_every_seconds: env::var("JWST_EVERY_SECONDS")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .unwrap_or(3600),
+            
+            osdr_tombstone_retention_days: env::var("OSDR_TOMBSTONE_RETENTION_DAYS")
+                .unwrap_or_else(|_| "90".to_string())
+                .parse()
+                .unwrap_or(90),
+            
+            smtp_host: env::var("SMTP_HOST").ok().filter(|v| !v.is_empty()),
+            smtp_port: env::var("SMTP_PORT")
+                .unwrap_or_else(|_| "25".to_string())
+                .parse()
+                .unwrap_or(25),
+            smtp_starttls: env::var("SMTP_STARTTLS")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            smtp_username: env::var("SMTP_USERNAME").ok().filter(|v| !v.is_empty()),
+            smtp_password: env::var("SMTP_PASSWORD").ok().filter(|v| !v.is_empty()),
+            smtp_from: env::var("SMTP_FROM")
+                .unwrap_or_else(|_| "cassiopeia@localhost".to_string()),
+            webhook_allow_private_targets: env::var("WEBHOOK_ALLOW_PRIVATE_TARGETS")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false),
+            
+            media_dir: env::var("MEDIA_DIR")
+                .unwrap_or_else(|_| "/data/media".to_string()),
+            media_quota_mb: env::var("MEDIA_QUOTA_MB")
+                .unwrap_or_else(|_| "1024".to_string())
+                .parse()
+                .unwrap_or(1024),
+            media_max_download_mb: env::var("MEDIA_MAX_DOWNLOAD_MB")
+                .unwrap_or_else(|_| "50".to_string())
+                .parse()
+                .unwrap_or(50),
+            media_thumb_size: env::var("MEDIA_THUMB_SIZE")
+                .unwrap_or_else(|_| "320".to_string())
+                .parse()
+                .unwrap_or(320),
+            media_mirror_every_seconds: env::var("MEDIA_MIRROR_EVERY_SECONDS")
+                .unwrap_or_else(|_| "600".to_string())
+                .parse()
+                .unwrap_or(600),
             
             rate_limit_per_minute: env::var("RATE_LIMIT_PER_MINUTE")
                 .unwrap_or_else(|_| "30".to_string())
@@ -85,6 +197,21 @@ impl Config {
         if self.iss_every_seconds < 10 {
             return Err("ISS_EVERY_SECONDS must be >= 10".to_string());
         }
+        if self.nasa_quota_reserve_percent > 90 {
+            return Err("NASA_QUOTA_RESERVE_PERCENT must be <= 90".to_string());
+        }
+        if self.jwst_every_seconds < 60 {
+            return Err("JWST_EVERY_SECONDS must be >= 60".to_string());
+        }
+        if self.osdr_tombstone_retention_days < 1 {
+            return Err("OSDR_TOMBSTONE_RETENTION_DAYS must be >= 1".to_string());
+        }
+        if self.media_quota_mb < 1 {
+            return Err("MEDIA_QUOTA_MB must be >= 1".to_string());
+        }
+        if self.media_thumb_size < 16 {
+            return Err("MEDIA_THUMB_SIZE must be >= 16".to_string());
+        }
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,159 @@
+use crate::domain::models::OsdrDataset;
+use chrono::Datelike;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+const PUBLISHER: &str = "NASA Open Science Data Repository";
+
+/// Формат экспорта цитирования
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum CitationFormat {
+    #[default]
+    #[serde(rename = "bibtex")]
+    Bibtex,
+    #[serde(rename = "ris")]
+    Ris,
+    #[serde(rename = "csl-json")]
+    CslJson,
+}
+
+impl CitationFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            CitationFormat::Bibtex => "application/x-bibtex; charset=utf-8",
+            CitationFormat::Ris => "application/x-research-info-systems; charset=utf-8",
+            CitationFormat::CslJson => "application/vnd.citationstyles.csl+json; charset=utf-8",
+        }
+    }
+}
+
+/// Сформировать цитирования для набора датасетов в одном документе
+pub fn format_citations(datasets: &[OsdrDataset], format: CitationFormat) -> String {
+    match format {
+        CitationFormat::Bibtex => datasets
+            .iter()
+            .map(to_bibtex)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        CitationFormat::Ris => datasets.iter().map(to_ris).collect::<Vec<_>>().join(""),
+        CitationFormat::CslJson => {
+            let items: Vec<Value> = datasets.iter().map(to_csl_json).collect();
+            serde_json::to_string_pretty(&items).unwrap_or_else(|_| "[]".to_string())
+        }
+    }
+}
+
+/// Страница исследования в OSDR (GLDS-N и OSD-N имеют общий номер)
+pub fn dataset_url(accession: &str) -> String {
+    let number = accession
+        .strip_prefix("GLDS-")
+        .or_else(|| accession.strip_prefix("OSD-"))
+        .filter(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+
+    match number {
+        Some(n) => format!("https://osdr.nasa.gov/bio/repo/data/studies/OSD-{}", n),
+        None => format!("https://osdr.nasa.gov/bio/repo/search?q={}", accession),
+    }
+}
+
+fn to_bibtex(dataset: &OsdrDataset) -> String {
+    let key: String = dataset
+        .dataset_id
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect();
+
+    let mut fields = vec![
+        format!("  author = {{{{{}}}}}", PUBLISHER),
+        format!("  title = {{{{{}}}}}", escape_bibtex(&dataset.title)),
+    ];
+    if let Some(date) = dataset.release_date {
+        fields.push(format!("  year = {{{}}}", date.year()));
+        fields.push(format!("  month = {}", bibtex_month(date.month())));
+    }
+    fields.push(format!("  publisher = {{{}}}", PUBLISHER));
+    if let Some(doi) = &dataset.doi {
+        fields.push(format!("  doi = {{{}}}", doi));
+    }
+    fields.push(format!("  url = {{{}}}", dataset_url(&dataset.dataset_id)));
+    fields.push(format!("  note = {{Accession {}}}", escape_bibtex(&dataset.dataset_id)));
+
+    format!("@misc{{{},\n{}\n}}\n", key, fields.join(",\n"))
+}
+
+fn to_ris(dataset: &OsdrDataset) -> String {
+    let mut lines = vec![
+        "TY  - DATA".to_string(),
+        format!("AU  - {}", PUBLISHER),
+        format!("TI  - {}", single_line(&dataset.title)),
+    ];
+    if let Some(date) = dataset.release_date {
+        lines.push(format!("PY  - {}", date.year()));
+        lines.push(format!("DA  - {}", date.format("%Y/%m/%d")));
+    }
+    lines.push(format!("PB  - {}", PUBLISHER));
+    if let Some(doi) = &dataset.doi {
+        lines.push(format!("DO  - {}", doi));
+    }
+    lines.push(format!("UR  - {}", dataset_url(&dataset.dataset_id)));
+    lines.push(format!("ID  - {}", dataset.dataset_id));
+    if let Some(description) = &dataset.description {
+        lines.push(format!("AB  - {}", single_line(description)));
+    }
+    lines.push("ER  - ".to_string());
+
+    lines.join("\r\n") + "\r\n"
+}
+
+fn to_csl_json(dataset: &OsdrDataset) -> Value {
+    let mut item = json!({
+        "id": dataset.dataset_id,
+        "type": "dataset",
+        "title": dataset.title,
+        "author": [{ "literal": PUBLISHER }],
+        "publisher": PUBLISHER,
+        "number": dataset.dataset_id,
+        "URL": dataset_url(&dataset.dataset_id),
+    });
+
+    if let Some(date) = dataset.release_date {
+        item["issued"] = json!({ "date-parts": [[date.year(), date.month(), date.day()]] });
+    }
+    if let Some(doi) = &dataset.doi {
+        item["DOI"] = json!(doi);
+    }
+    if let Some(description) = &dataset.description {
+        item["abstract"] = json!(description);
+    }
+
+    item
+}
+
+fn escape_bibtex(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in single_line(value).chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' | '}' | '&' | '%' | '$' | '#' | '_' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn single_line(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn bibtex_month(month: u32) -> &'static str {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    MONTHS[(month as usize).saturating_sub(1) % 12]
+}
+
+#[cfg(test)]
+mod tests;
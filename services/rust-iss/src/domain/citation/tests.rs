@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use chrono::{NaiveDate, Utc};
+
+    fn sample_dataset() -> OsdrDataset {
+        OsdrDataset {
+            id: Some(1),
+            dataset_id: "GLDS-379".to_string(),
+            title: "Rodent Research-1 (RR-1): bone loss & immune_dysregulation".to_string(),
+            description: Some("Gene expression changes\nin mice".to_string()),
+            release_date: NaiveDate::from_ymd_opt(2019, 6, 1),
+            updated_at: Utc::now(),
+            doi: Some("10.26030/abcd-1234".to_string()),
+            withdrawn_at: None,
+        }
+    }
+
+    #[test]
+    fn test_bibtex_citation() {
+        let bibtex = format_citations(&[sample_dataset()], CitationFormat::Bibtex);
+
+        assert!(bibtex.starts_with("@misc{GLDS-379,\n"));
+        assert!(bibtex.contains("  author = {{NASA Open Science Data Repository}},"));
+        assert!(bibtex.contains("bone loss \\& immune\\_dysregulation"));
+        assert!(bibtex.contains("  year = {2019},"));
+        assert!(bibtex.contains("  month = jun,"));
+        assert!(bibtex.contains("  doi = {10.26030/abcd-1234},"));
+        assert!(bibtex.contains("  url = {https://osdr.nasa.gov/bio/repo/data/studies/OSD-379},"));
+        assert!(bibtex.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_ris_citation() {
+        let ris = format_citations(&[sample_dataset()], CitationFormat::Ris);
+        let lines: Vec<&str> = ris.split("\r\n").collect();
+
+        assert_eq!(lines[0], "TY  - DATA");
+        assert!(lines.contains(&"DA  - 2019/06/01"));
+        assert!(lines.contains(&"DO  - 10.26030/abcd-1234"));
+        assert!(lines.contains(&"AB  - Gene expression changes in mice"));
+        assert!(ris.ends_with("ER  - \r\n"));
+    }
+
+    #[test]
+    fn test_csl_json_citation() {
+        let mut without_doi = sample_dataset();
+        without_doi.dataset_id = "OSD-120".to_string();
+        without_doi.doi = None;
+        without_doi.release_date = None;
+
+        let csl = format_citations(&[sample_dataset(), without_doi], CitationFormat::CslJson);
+        let items: serde_json::Value = serde_json::from_str(&csl).unwrap();
+
+        assert_eq!(items.as_array().unwrap().len(), 2);
+        assert_eq!(items[0]["type"], "dataset");
+        assert_eq!(items[0]["DOI"], "10.26030/abcd-1234");
+        assert_eq!(items[0]["issued"]["date-parts"][0][1], 6);
+        assert!(items[1].get("DOI").is_none());
+        assert!(items[1].get("issued").is_none());
+    }
+
+    #[test]
+    fn test_citation_format_parsing() {
+        let format: CitationFormat = serde_json::from_str("\"csl-json\"").unwrap();
+        assert_eq!(format, CitationFormat::CslJson);
+        assert!(serde_json::from_str::<CitationFormat>("\"endnote\"").is_err());
+        assert_eq!(CitationFormat::default(), CitationFormat::Bibtex);
+    }
+
+    #[test]
+    fn test_dataset_url() {
+        assert_eq!(
+            dataset_url("GLDS-47"),
+            "https://osdr.nasa.gov/bio/repo/data/studies/OSD-47"
+        );
+        assert_eq!(
+            dataset_url("custom-id"),
+            "https://osdr.nasa.gov/bio/repo/search?q=custom-id"
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::domain::models::{DonkiChain, DonkiChainEvent, DonkiEvent, DonkiEventType};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+
+/// Разобрать время DONKI: "2024-01-01T12:34Z", с секундами или в RFC 3339
+pub fn parse_donki_time(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(time.with_timezone(&Utc));
+    }
+
+    ["%Y-%m-%dT%H:%MZ", "%Y-%m-%dT%H:%M:%SZ", "%Y-%m-%dT%H:%M:%S%.fZ"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .map(|time| time.and_utc())
+}
+
+/// Разобрать ответ DONKI; события без ID или времени пропускаются (возвращается их число)
+pub fn parse_events(event_type: DonkiEventType, response: Value) -> Result<(Vec<DonkiEvent>, usize), String> {
+    let items = match response {
+        Value::Array(items) => items,
+        // DONKI отдаёт пустое тело или null, когда событий нет
+        Value::Null => Vec::new(),
+        other => return Err(format!("Unexpected DONKI {} response: {}", event_type.as_str(), other)),
+    };
+
+    let total = items.len();
+    let events: Vec<DonkiEvent> = items
+        .into_iter()
+        .filter_map(|item| to_event(event_type, item))
+        .collect();
+    let skipped = total - events.len();
+
+    Ok((events, skipped))
+}
+
+fn to_event(event_type: DonkiEventType, payload: Value) -> Option<DonkiEvent> {
+    let activity_id = payload.get(event_type.id_field())?.as_str()?.to_string();
+    let start_time = parse_donki_time(payload.get(event_type.time_field())?.as_str()?)?;
+
+    let linked_ids = payload
+        .get("linkedEvents")
+        .and_then(Value::as_array)
+        .map(|links| {
+            links
+                .iter()
+                .filter_map(|link| link.get("activityID").and_then(Value::as_str))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(DonkiEvent {
+        activity_id,
+        event_type: event_type.as_str().to_string(),
+        start_time,
+        linked_ids,
+        payload,
+        fetched_at: Utc::now(),
+    })
+}
+
+/// Разбить события на связные цепочки по linkedEvents (связи считаются ненаправленными)
+pub fn build_chains(events: &[DonkiEvent]) -> Vec<DonkiChain> {
+    let index: HashMap<&str, usize> = events
+        .iter()
+        .enumerate()
+        .map(|(i, event)| (event.activity_id.as_str(), i))
+        .collect();
+
+    let mut parent: Vec<usize> = (0..events.len()).collect();
+    for (i, event) in events.iter().enumerate() {
+        for linked in &event.linked_ids {
+            if let Some(&j) = index.get(linked.as_str()) {
+                let (a, b) = (find(&mut parent, i), find(&mut parent, j));
+                if a != b {
+                    parent[a] = b;
+                }
+            }
+        }
+    }
+
+    let mut components: HashMap<usize, Vec<&DonkiEvent>> = HashMap::new();
+    for (i, event) in events.iter().enumerate() {
+        let root = find(&mut parent, i);
+        components.entry(root).or_default().push(event);
+    }
+
+    let mut chains: Vec<DonkiChain> = components
+        .into_values()
+        .map(|mut members| {
+            members.sort_by(|a, b| a.start_time.cmp(&b.start_time).then(a.activity_id.cmp(&b.activity_id)));
+            to_chain(&members, &index)
+        })
+        .collect();
+    chains.sort_by(|a, b| a.start_time.cmp(&b.start_time).then(a.chain_id.cmp(&b.chain_id)));
+
+    chains
+}
+
+fn to_chain(members: &[&DonkiEvent], index: &HashMap<&str, usize>) -> DonkiChain {
+    let mut event_types: Vec<String> = Vec::new();
+    for event in members {
+        if !event_types.contains(&event.event_type) {
+            event_types.push(event.event_type.clone());
+        }
+    }
+
+    let missing_links: BTreeSet<String> = members
+        .iter()
+        .flat_map(|event| event.linked_ids.iter())
+        .filter(|id| !index.contains_key(id.as_str()))
+        .cloned()
+        .collect();
+
+    DonkiChain {
+        chain_id: members[0].activity_id.clone(),
+        start_time: members[0].start_time,
+        end_time: members[members.len() - 1].start_time,
+        event_types,
+        timeline: members
+            .iter()
+            .map(|event| DonkiChainEvent {
+                activity_id: event.activity_id.clone(),
+                event_type: event.event_type.clone(),
+                start_time: event.start_time,
+                linked_ids: event.linked_ids.clone(),
+                link: event.payload.get("link").and_then(Value::as_str).map(str::to_string),
+            })
+            .collect(),
+        missing_links: missing_links.into_iter().collect(),
+    }
+}
+
+fn find(parent: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    // Сжатие путей
+    let mut node = i;
+    while parent[node] != root {
+        let next = parent[node];
+        parent[node] = root;
+        node = next;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests;
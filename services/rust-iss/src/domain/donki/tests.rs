@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_donki_time_formats() {
+        let expected = Utc.with_ymd_and_hms(2024, 5, 10, 17, 36, 0).unwrap();
+
+        assert_eq!(parse_donki_time("2024-05-10T17:36Z"), Some(expected));
+        assert_eq!(parse_donki_time("2024-05-10T17:36:00Z"), Some(expected));
+        assert_eq!(parse_donki_time("2024-05-10T17:36:00.000Z"), Some(expected));
+        assert_eq!(parse_donki_time("2024-05-10T19:36:00+02:00"), Some(expected));
+        assert_eq!(parse_donki_time("10.05.2024"), None);
+    }
+
+    #[test]
+    fn test_parse_events_extracts_ids_and_links() {
+        let response = json!([
+            {
+                "gstID": "2024-05-10T15:00:00-GST-001",
+                "startTime": "2024-05-10T15:00Z",
+                "allKpIndex": [{ "observedTime": "2024-05-10T18:00Z", "kpIndex": 8.67, "source": "NOAA" }],
+                "linkedEvents": [
+                    { "activityID": "2024-05-08T05:36:00-CME-001" },
+                    { "activityID": "2024-05-10T16:36:00-IPS-001" }
+                ]
+            },
+            { "gstID": "2024-05-12T00:00:00-GST-001", "startTime": "2024-05-12T00:00Z", "linkedEvents": null }
+        ]);
+
+        let (events, skipped) = parse_events(DonkiEventType::Gst, response).unwrap();
+
+        assert_eq!(skipped, 0);
+        assert_eq!(events[0].activity_id, "2024-05-10T15:00:00-GST-001");
+        assert_eq!(events[0].event_type, "GST");
+        assert_eq!(
+            events[0].linked_ids,
+            vec!["2024-05-08T05:36:00-CME-001", "2024-05-10T16:36:00-IPS-001"]
+        );
+        assert!(events[1].linked_ids.is_empty());
+        assert_eq!(events[0].payload["allKpIndex"][0]["kpIndex"], 8.67);
+    }
+
+    #[test]
+    fn test_parse_events_uses_type_specific_fields() {
+        let flares = json!([{ "flrID": "2024-05-14T16:46:00-FLR-001", "beginTime": "2024-05-14T16:46Z", "classType": "X8.7" }]);
+        let (events, _) = parse_events(DonkiEventType::Flr, flares).unwrap();
+        assert_eq!(events[0].start_time, Utc.with_ymd_and_hms(2024, 5, 14, 16, 46, 0).unwrap());
+
+        let notifications = json!([{
+            "messageType": "Report",
+            "messageID": "20240514-7D-001",
+            "messageIssueTime": "2024-05-14T12:00Z",
+            "messageBody": "Weekly report"
+        }]);
+        let (events, _) = parse_events(DonkiEventType::Notification, notifications).unwrap();
+        assert_eq!(events[0].activity_id, "20240514-7D-001");
+    }
+
+    #[test]
+    fn test_parse_events_skips_incomplete_items() {
+        let response = json!([
+            { "sepID": "2024-05-11T02:10:00-SEP-001", "eventTime": "2024-05-11T02:10Z" },
+            { "sepID": "no-time" },
+            { "eventTime": "2024-05-11T02:10Z" }
+        ]);
+
+        let (events, skipped) = parse_events(DonkiEventType::Sep, response).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(skipped, 2);
+    }
+
+    #[test]
+    fn test_parse_events_handles_empty_and_invalid_responses() {
+        assert!(parse_events(DonkiEventType::Hss, Value::Null).unwrap().0.is_empty());
+        assert!(parse_events(DonkiEventType::Hss, json!({ "error": "rate limited" })).is_err());
+    }
+
+    #[test]
+    fn test_event_type_api_paths() {
+        assert_eq!(DonkiEventType::Gst.api_path(), "GST");
+        assert_eq!(DonkiEventType::Notification.api_path(), "notifications");
+    }
+
+    fn event(id: &str, event_type: &str, hour: u32, linked: &[&str]) -> DonkiEvent {
+        DonkiEvent {
+            activity_id: id.to_string(),
+            event_type: event_type.to_string(),
+            start_time: Utc.with_ymd_and_hms(2024, 5, 10, hour, 0, 0).unwrap(),
+            linked_ids: linked.iter().map(|s| s.to_string()).collect(),
+            payload: json!({ "link": format!("https://kauai.ccmc.gsfc.nasa.gov/DONKI/view/{}", id) }),
+            fetched_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_build_chains_groups_linked_events() {
+        // FLR -> CME -> GST (связь только в одну сторону), SEP ссылается на CME; HSS отдельно
+        let events = vec![
+            event("GST-1", "GST", 20, &[]),
+            event("CME-1", "CME", 6, &["GST-1"]),
+            event("FLR-1", "FLR", 5, &["CME-1"]),
+            event("SEP-1", "SEP", 9, &["CME-1", "IPS-404"]),
+            event("HSS-1", "HSS", 12, &[]),
+        ];
+
+        let chains = build_chains(&events);
+        assert_eq!(chains.len(), 2);
+
+        let storm = &chains[0];
+        assert_eq!(storm.chain_id, "FLR-1");
+        assert_eq!(storm.event_types, vec!["FLR", "CME", "SEP", "GST"]);
+        assert_eq!(
+            storm.timeline.iter().map(|e| e.activity_id.as_str()).collect::<Vec<_>>(),
+            vec!["FLR-1", "CME-1", "SEP-1", "GST-1"]
+        );
+        assert_eq!(storm.end_time, Utc.with_ymd_and_hms(2024, 5, 10, 20, 0, 0).unwrap());
+        assert_eq!(storm.missing_links, vec!["IPS-404"]);
+        assert!(storm.timeline[0].link.as_deref().unwrap().ends_with("FLR-1"));
+
+        assert_eq!(chains[1].chain_id, "HSS-1");
+        assert_eq!(chains[1].timeline.len(), 1);
+    }
+
+    #[test]
+    fn test_build_chains_handles_cycles_and_empty_input() {
+        let events = vec![
+            event("A", "CME", 1, &["B"]),
+            event("B", "IPS", 2, &["C"]),
+            event("C", "GST", 3, &["A"]),
+        ];
+
+        let chains = build_chains(&events);
+        assert_eq!(chains.len(), 1);
+        assert_eq!(chains[0].timeline.len(), 3);
+        assert!(chains[0].missing_links.is_empty());
+
+        assert!(build_chains(&[]).is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    domain::models::{
+        EonetApiEvent, EonetApiGeometry, EonetApiResponse, EonetEvent, EonetGeometry, EventOverflight, IssPosition,
+    },
+    utils::geo::{centroid, haversine_km, interpolate},
+};
+use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
+
+/// Шаг интерполяции трассы МКС между записями iss_fetch_log (секунд)
+const TRACK_STEP_SECONDS: i64 = 10;
+
+/// Разрыв в логе, через который трассу не интерполируем (секунд)
+const MAX_TRACK_GAP_SECONDS: i64 = 600;
+
+/// Верхняя оценка скорости подспутниковой точки МКС (км/с)
+const ISS_GROUND_SPEED_KMS: f64 = 8.0;
+
+/// Разобрать ответ EONET /events; события без категории или геометрии пропускаются (их число - второй элемент)
+pub fn parse_events(response: Value) -> Result<(Vec<EonetEvent>, usize), String> {
+    let response: EonetApiResponse =
+        serde_json::from_value(response).map_err(|e| format!("Unexpected EONET response: {}", e))?;
+
+    let total = response.events.len();
+    let events: Vec<EonetEvent> = response.events.into_iter().filter_map(to_event).collect();
+    let skipped = total - events.len();
+
+    Ok((events, skipped))
+}
+
+fn to_event(event: EonetApiEvent) -> Option<EonetEvent> {
+    let category = event.categories.first()?.id.clone();
+
+    let mut geometry: Vec<EonetGeometry> = event.geometry.iter().filter_map(to_geometry).collect();
+    if geometry.is_empty() {
+        return None;
+    }
+    geometry.sort_by_key(|g| g.observed_at);
+    geometry.dedup_by_key(|g| g.observed_at);
+
+    Some(EonetEvent {
+        id: event.id,
+        title: event.title,
+        description: event.description.filter(|d| !d.is_empty()),
+        category,
+        link: event.link,
+        closed_at: event.closed,
+        sources: event.sources.into_iter().map(|s| s.url).collect(),
+        geometry,
+    })
+}
+
+/// Точка наблюдения; для полигона - центр внешнего контура
+fn to_geometry(geometry: &EonetApiGeometry) -> Option<EonetGeometry> {
+    let (latitude, longitude) = match geometry.kind.as_str() {
+        "Point" => lon_lat(&geometry.coordinates)?,
+        "Polygon" => {
+            let ring = geometry.coordinates.get(0)?.as_array()?;
+            let mut points: Vec<(f64, f64)> = ring.iter().filter_map(lon_lat).collect();
+            // Контур замкнут: последняя точка повторяет первую
+            if points.len() > 1 && points.first() == points.last() {
+                points.pop();
+            }
+            centroid(&points)?
+        }
+        _ => return None,
+    };
+
+    Some(EonetGeometry {
+        observed_at: geometry.date,
+        latitude,
+        longitude,
+        magnitude_value: geometry.magnitude_value,
+        magnitude_unit: geometry.magnitude_unit.clone(),
+    })
+}
+
+/// [lon, lat] -> (lat, lon)
+fn lon_lat(value: &Value) -> Option<(f64, f64)> {
+    let lon = value.get(0)?.as_f64()?;
+    let lat = value.get(1)?.as_f64()?;
+    ((-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon)).then_some((lat, lon))
+}
+
+/// Положение события на момент at: последнее наблюдение не позже at (до первого - первое)
+fn location_at(event: &EonetEvent, at: DateTime<Utc>) -> &EonetGeometry {
+    event
+        .geometry
+        .iter()
+        .rev()
+        .find(|g| g.observed_at <= at)
+        .unwrap_or(&event.geometry[0])
+}
+
+/// Событие активно с первого наблюдения до закрытия
+fn is_active(event: &EonetEvent, at: DateTime<Utc>) -> bool {
+    event.geometry.first().is_some_and(|g| g.observed_at <= at) && event.closed_at.is_none_or(|closed| at <= closed)
+}
+
+/// События, над которыми трасса МКС прошла ближе radius_km, пока событие было активно.
+/// Для каждого события - ближайший пролёт; трасса интерполируется между записями лога.
+pub fn find_overflights(events: &[EonetEvent], track: &[IssPosition], radius_km: f64) -> Vec<EventOverflight> {
+    let mut overflights: Vec<EventOverflight> = events
+        .iter()
+        .filter(|event| !event.geometry.is_empty())
+        .filter_map(|event| closest_pass(event, track, radius_km))
+        .collect();
+
+    overflights.sort_by_key(|o| std::cmp::Reverse(o.overflown_at));
+    overflights
+}
+
+fn closest_pass(event: &EonetEvent, track: &[IssPosition], radius_km: f64) -> Option<EventOverflight> {
+    let mut best: Option<EventOverflight> = None;
+
+    let mut consider = |at: DateTime<Utc>, lat: f64, lon: f64| {
+        if !is_active(event, at) {
+            return;
+        }
+        let location = location_at(event, at);
+        let distance = haversine_km(lat, lon, location.latitude, location.longitude);
+        if distance <= radius_km && best.as_ref().is_none_or(|b| distance < b.distance_km) {
+            best = Some(EventOverflight {
+                event_id: event.id.clone(),
+                title: event.title.clone(),
+                category: event.category.clone(),
+                link: event.link.clone(),
+                overflown_at: at,
+                distance_km: distance,
+                iss_latitude: lat,
+                iss_longitude: lon,
+                event_latitude: location.latitude,
+                event_longitude: location.longitude,
+            });
+        }
+    };
+
+    for pair in track.windows(2) {
+        let (a, b) = (&pair[0], &pair[1]);
+        let (ta, tb) = (a.timestamp.and_utc(), b.timestamp.and_utc());
+        let gap = (tb - ta).num_seconds();
+
+        if gap <= 0 || gap > MAX_TRACK_GAP_SECONDS {
+            consider(ta, a.latitude, a.longitude);
+            continue;
+        }
+
+        // Отрезок заведомо дальше радиуса - не интерполируем
+        let location = location_at(event, ta);
+        let nearest_possible = haversine_km(a.latitude, a.longitude, location.latitude, location.longitude)
+            - gap as f64 * ISS_GROUND_SPEED_KMS;
+        if nearest_possible > radius_km {
+            continue;
+        }
+
+        let steps = (gap + TRACK_STEP_SECONDS - 1) / TRACK_STEP_SECONDS;
+        for step in 0..steps {
+            let t = step as f64 / steps as f64;
+            let (lat, lon) = interpolate((a.latitude, a.longitude), (b.latitude, b.longitude), t);
+            consider(ta + Duration::milliseconds((gap as f64 * t * 1000.0) as i64), lat, lon);
+        }
+    }
+
+    if let Some(last) = track.last() {
+        consider(last.timestamp.and_utc(), last.latitude, last.longitude);
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn sample() -> Value {
+        json!({
+            "title": "EONET Events",
+            "events": [
+                {
+                    "id": "EONET_6500",
+                    "title": "Hurricane Test",
+                    "description": "",
+                    "link": "https://eonet.gsfc.nasa.gov/api/v3/events/EONET_6500",
+                    "closed": null,
+                    "categories": [{"id": "severeStorms", "title": "Severe Storms"}],
+                    "sources": [{"id": "JTWC", "url": "https://www.metoc.navy.mil/jtwc/"}],
+                    "geometry": [
+                        {"magnitudeValue": 65.0, "magnitudeUnit": "kts", "date": "2024-09-02T00:00:00Z",
+                         "type": "Point", "coordinates": [-80.0, 25.0]},
+                        {"magnitudeValue": 45.0, "magnitudeUnit": "kts", "date": "2024-09-01T00:00:00Z",
+                         "type": "Point", "coordinates": [-75.0, 20.0]}
+                    ]
+                },
+                {
+                    "id": "EONET_6501",
+                    "title": "Wildfire Test",
+                    "closed": "2024-09-03T00:00:00Z",
+                    "categories": [{"id": "wildfires", "title": "Wildfires"}],
+                    "sources": [],
+                    "geometry": [
+                        {"magnitudeValue": null, "magnitudeUnit": null, "date": "2024-09-01T00:00:00Z",
+                         "type": "Polygon", "coordinates": [[[10.0, 0.0], [12.0, 0.0], [12.0, 2.0], [10.0, 2.0], [10.0, 0.0]]]}
+                    ]
+                },
+                {
+                    "id": "EONET_6502",
+                    "title": "No geometry",
+                    "categories": [{"id": "volcanoes", "title": "Volcanoes"}],
+                    "geometry": []
+                }
+            ]
+        })
+    }
+
+    fn position(lat: f64, lon: f64, at: DateTime<Utc>) -> IssPosition {
+        IssPosition {
+            id: None,
+            latitude: lat,
+            longitude: lon,
+            altitude: 420.0,
+            velocity: 27600.0,
+            timestamp: at.naive_utc(),
+            fetched_at: at,
+        }
+    }
+
+    #[test]
+    fn test_parse_events() {
+        let (events, skipped) = parse_events(sample()).unwrap();
+
+        assert_eq!(skipped, 1);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].category, "severeStorms");
+        assert!(events[0].description.is_none());
+        assert_eq!(events[0].sources, vec!["https://www.metoc.navy.mil/jtwc/".to_string()]);
+        // Наблюдения упорядочены по времени
+        assert_eq!(events[0].geometry[0].latitude, 20.0);
+        assert_eq!(events[0].geometry[1].magnitude_value, Some(65.0));
+
+        let polygon = &events[1].geometry[0];
+        assert!((polygon.latitude - 1.0).abs() < 0.01);
+        assert!((polygon.longitude - 11.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_overflight_found_between_samples() {
+        let (events, _) = parse_events(sample()).unwrap();
+        let t0 = Utc.with_ymd_and_hms(2024, 9, 2, 12, 0, 0).unwrap();
+        // Две записи по 120 с по обе стороны от урагана (25N 80W): сами точки дальше 300 км
+        let track = vec![
+            position(22.0, -84.0, t0),
+            position(28.0, -76.0, t0 + Duration::seconds(120)),
+        ];
+
+        let overflights = find_overflights(&events, &track, 300.0);
+
+        assert_eq!(overflights.len(), 1);
+        assert_eq!(overflights[0].event_id, "EONET_6500");
+        assert!(overflights[0].distance_km < 100.0, "distance {}", overflights[0].distance_km);
+        assert!(overflights[0].overflown_at > t0 && overflights[0].overflown_at < t0 + Duration::seconds(120));
+        assert_eq!(overflights[0].event_latitude, 25.0);
+    }
+
+    #[test]
+    fn test_overflight_respects_active_window() {
+        let (events, _) = parse_events(sample()).unwrap();
+        // Над пожаром, но после закрытия события
+        let after_close = Utc.with_ymd_and_hms(2024, 9, 4, 0, 0, 0).unwrap();
+        assert!(find_overflights(&events, &[position(1.0, 11.0, after_close)], 100.0).is_empty());
+
+        // До первого наблюдения
+        let before = Utc.with_ymd_and_hms(2024, 8, 30, 0, 0, 0).unwrap();
+        assert!(find_overflights(&events, &[position(1.0, 11.0, before)], 100.0).is_empty());
+
+        let during = Utc.with_ymd_and_hms(2024, 9, 2, 0, 0, 0).unwrap();
+        let found = find_overflights(&events, &[position(1.0, 11.0, during)], 100.0);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].event_id, "EONET_6501");
+    }
+
+    #[test]
+    fn test_overflight_uses_latest_location() {
+        let (events, _) = parse_events(sample()).unwrap();
+        // 1 сентября ураган был у 20N 75W, позже - у 25N 80W
+        let first_day = Utc.with_ymd_and_hms(2024, 9, 1, 6, 0, 0).unwrap();
+        let found = find_overflights(&events, &[position(20.0, -75.0, first_day)], 50.0);
+        assert_eq!(found.len(), 1);
+
+        let next_day = Utc.with_ymd_and_hms(2024, 9, 2, 6, 0, 0).unwrap();
+        assert!(find_overflights(&events, &[position(20.0, -75.0, next_day)], 50.0).is_empty());
+    }
+}
@@ -0,0 +1,93 @@
+use crate::domain::models::{EpicApiImage, EpicCollection, EpicImage};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde_json::Value;
+
+/// Архив снимков EPIC (доступен без ключа API)
+const EPIC_ARCHIVE_URL: &str = "https://epic.gsfc.nasa.gov/archive";
+
+/// Разобрать список снимков EPIC; пустой ответ - снимков за день нет
+pub fn parse_images(collection: EpicCollection, response: Value) -> Result<Vec<EpicImage>, String> {
+    if response.is_null() {
+        return Ok(Vec::new());
+    }
+
+    let images: Vec<EpicApiImage> =
+        serde_json::from_value(response).map_err(|e| format!("Unexpected EPIC response: {}", e))?;
+
+    let mut images = images
+        .into_iter()
+        .map(|image| to_epic_image(collection, image))
+        .collect::<Result<Vec<_>, _>>()?;
+    images.sort_by_key(|image| image.taken_at);
+    Ok(images)
+}
+
+/// Разобрать список доступных дат (/EPIC/api/{collection}/available)
+pub fn parse_available_dates(response: Value) -> Result<Vec<NaiveDate>, String> {
+    if response.is_null() {
+        return Ok(Vec::new());
+    }
+
+    let dates: Vec<String> =
+        serde_json::from_value(response).map_err(|e| format!("Unexpected EPIC dates response: {}", e))?;
+
+    let mut dates = dates
+        .iter()
+        .map(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").map_err(|e| format!("Invalid EPIC date {}: {}", d, e)))
+        .collect::<Result<Vec<_>, _>>()?;
+    dates.sort();
+    Ok(dates)
+}
+
+pub fn to_epic_image(collection: EpicCollection, image: EpicApiImage) -> Result<EpicImage, String> {
+    let taken_at = NaiveDateTime::parse_from_str(&image.date, "%Y-%m-%d %H:%M:%S")
+        .map_err(|e| format!("EPIC {}: invalid date {}: {}", image.identifier, image.date, e))?
+        .and_utc();
+
+    Ok(EpicImage {
+        image_url: archive_url(collection, &image.image, taken_at, false),
+        thumbnail_url: archive_url(collection, &image.image, taken_at, true),
+        identifier: image.identifier,
+        collection,
+        caption: image.caption,
+        image: image.image,
+        version: image.version,
+        taken_at,
+        centroid: image.centroid_coordinates,
+        dscovr_position: image.dscovr_j2000_position,
+        lunar_position: image.lunar_j2000_position,
+        sun_position: image.sun_j2000_position,
+    })
+}
+
+/// URL файла в архиве: полноразмерный PNG или JPEG-миниатюра
+pub fn archive_url(collection: EpicCollection, image: &str, taken_at: DateTime<Utc>, thumbnail: bool) -> String {
+    let (dir, ext) = if thumbnail { ("thumbs", "jpg") } else { ("png", "png") };
+    format!(
+        "{}/{}/{}/{}/{}.{}",
+        EPIC_ARCHIVE_URL,
+        collection.as_str(),
+        taken_at.format("%Y/%m/%d"),
+        dir,
+        image,
+        ext
+    )
+}
+
+/// Дата съёмки по идентификатору EPIC (YYYYMMDDhhmmss)
+pub fn identifier_date(identifier: &str) -> Option<NaiveDate> {
+    if identifier.len() != 14 || !identifier.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    NaiveDate::parse_from_str(&identifier[..8], "%Y%m%d").ok()
+}
+
+/// Снимок, ближайший по времени к моменту at
+pub fn nearest(images: &[EpicImage], at: DateTime<Utc>) -> Option<&EpicImage> {
+    images
+        .iter()
+        .min_by_key(|image| (image.taken_at - at).num_seconds().abs())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn sample() -> Value {
+        json!([
+            {
+                "identifier": "20240101013633",
+                "caption": "This image was taken by NASA's EPIC camera onboard the NOAA DSCOVR spacecraft",
+                "image": "epic_1b_20240101013633",
+                "version": "03",
+                "date": "2024-01-01 01:31:45",
+                "centroid_coordinates": {"lat": -21.4, "lon": 160.2},
+                "dscovr_j2000_position": {"x": -1281428.0, "y": -637062.0, "z": -169048.0},
+                "lunar_j2000_position": {"x": 110532.0, "y": -346062.0, "z": -134040.0},
+                "sun_j2000_position": {"x": -26505466.0, "y": -132856664.0, "z": -57592620.0}
+            },
+            {
+                "identifier": "20240101003633",
+                "image": "epic_1b_20240101003633",
+                "date": "2024-01-01 00:31:45",
+                "centroid_coordinates": {"lat": -21.5, "lon": 175.3}
+            }
+        ])
+    }
+
+    #[test]
+    fn test_parse_images_sorted_with_urls() {
+        let images = parse_images(EpicCollection::Natural, sample()).unwrap();
+
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0].identifier, "20240101003633");
+        assert!(images[0].dscovr_position.is_none());
+        assert_eq!(
+            images[1].image_url,
+            "https://epic.gsfc.nasa.gov/archive/natural/2024/01/01/png/epic_1b_20240101013633.png"
+        );
+        assert_eq!(
+            images[1].thumbnail_url,
+            "https://epic.gsfc.nasa.gov/archive/natural/2024/01/01/thumbs/epic_1b_20240101013633.jpg"
+        );
+        assert_eq!(images[1].centroid.lon, 160.2);
+    }
+
+    #[test]
+    fn test_parse_images_empty() {
+        assert!(parse_images(EpicCollection::Enhanced, Value::Null).unwrap().is_empty());
+        assert!(parse_images(EpicCollection::Enhanced, json!([])).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_available_dates() {
+        let dates = parse_available_dates(json!(["2024-01-02", "2015-06-13"])).unwrap();
+        assert_eq!(dates, vec![
+            NaiveDate::from_ymd_opt(2015, 6, 13).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+        ]);
+        assert!(parse_available_dates(json!(["June 13"])).is_err());
+    }
+
+    #[test]
+    fn test_identifier_date() {
+        assert_eq!(identifier_date("20240101003633"), NaiveDate::from_ymd_opt(2024, 1, 1));
+        assert_eq!(identifier_date("2024010100363"), None);
+        assert_eq!(identifier_date("20241301003633"), None);
+    }
+
+    #[test]
+    fn test_nearest() {
+        let images = parse_images(EpicCollection::Natural, sample()).unwrap();
+        let at = Utc.with_ymd_and_hms(2024, 1, 1, 1, 10, 0).unwrap();
+
+        assert_eq!(nearest(&images, at).unwrap().identifier, "20240101013633");
+        assert!(nearest(&[], at).is_none());
+    }
+}
@@ -3,6 +3,7 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
+use super::provenance::{self, Provenance};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -26,6 +27,9 @@ pub struct ApiResponse<T> {
     pub data: Option<T>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ErrorInfo>,
+    /// Происхождение данных: источник, время получения, возраст кэша, degraded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Provenance>,
 }
 
 impl<T> ApiResponse<T> {
@@ -34,6 +38,7 @@ impl<T> ApiResponse<T> {
             ok: true,
             data: Some(data),
             error: None,
+            meta: provenance::current(),
         }
     }
 
@@ -46,6 +51,7 @@ impl<T> ApiResponse<T> {
                 message,
                 trace_id,
             }),
+            meta: provenance::current(),
         }
     }
 }
@@ -0,0 +1,333 @@
+use crate::domain::models::{FitsCard, FitsHdu, FitsSummary, FitsWcs};
+use serde_json::Value;
+
+/// Заголовок и данные FITS выровнены на блоки по 2880 байт (36 карточек по 80 символов)
+pub const BLOCK_SIZE: usize = 2880;
+const CARD_SIZE: usize = 80;
+
+/// Защита от мусора вместо FITS: заголовков такой длины не бывает
+const MAX_HEADER_BLOCKS: usize = 1000;
+
+/// Сколько HDU разбирать в одном файле
+pub const MAX_HDUS: usize = 64;
+
+/// Разобрать заголовок HDU с начала bytes; offset - его смещение в файле.
+/// Ok(None) - заголовок не уместился в bytes (нужно больше данных)
+pub fn parse_hdu(bytes: &[u8], offset: u64, index: usize) -> Result<Option<FitsHdu>, String> {
+    let expected = if index == 0 { "SIMPLE" } else { "XTENSION" };
+    let first = &bytes[..bytes.len().min(CARD_SIZE)];
+    if !expected.as_bytes().starts_with(first) && !first.starts_with(expected.as_bytes()) {
+        return Err(format!("Not a FITS header at byte {}: expected {}", offset, expected));
+    }
+
+    let mut cards: Vec<FitsCard> = Vec::new();
+    let mut pos = 0;
+    loop {
+        if pos + BLOCK_SIZE > bytes.len() {
+            return Ok(None);
+        }
+        if pos / BLOCK_SIZE >= MAX_HEADER_BLOCKS {
+            return Err(format!("FITS header at byte {} has no END card", offset));
+        }
+
+        let block = &bytes[pos..pos + BLOCK_SIZE];
+        pos += BLOCK_SIZE;
+
+        if !block.iter().all(|b| (0x20..=0x7e).contains(b)) {
+            return Err(format!("Non-ASCII FITS header at byte {}", offset));
+        }
+        // Блок проверен на ASCII выше
+        let block = std::str::from_utf8(block).unwrap_or_default();
+
+        for card in (0..block.len()).step_by(CARD_SIZE).map(|i| &block[i..i + CARD_SIZE]) {
+            let keyword = card[..8].trim_end();
+            match keyword {
+                "END" => return build_hdu(cards, offset, offset + pos as u64, index).map(Some),
+                "" => continue,
+                "CONTINUE" => append_continue(&mut cards, &card[8..]),
+                _ => cards.push(parse_card(card)),
+            }
+        }
+    }
+}
+
+/// Разобрать все HDU, уместившиеся в bytes (начало файла или файл целиком).
+/// Второе значение - true, если данные закончились раньше последнего HDU
+pub fn parse_file(bytes: &[u8]) -> Result<(Vec<FitsHdu>, bool), String> {
+    let mut hdus: Vec<FitsHdu> = Vec::new();
+    let mut offset = 0u64;
+
+    while hdus.len() < MAX_HDUS {
+        let start = offset as usize;
+        if start >= bytes.len() {
+            return Ok((hdus, start > bytes.len()));
+        }
+        match parse_hdu(&bytes[start..], offset, hdus.len())? {
+            Some(hdu) => {
+                offset = next_hdu_offset(&hdu);
+                hdus.push(hdu);
+            }
+            None => return Ok((hdus, true)),
+        }
+    }
+
+    Ok((hdus, true))
+}
+
+/// Смещение следующего HDU: данные текущего дополняются до целого блока
+pub fn next_hdu_offset(hdu: &FitsHdu) -> u64 {
+    let block = BLOCK_SIZE as u64;
+    hdu.data_offset + hdu.data_bytes.div_ceil(block) * block
+}
+
+fn build_hdu(cards: Vec<FitsCard>, header_offset: u64, data_offset: u64, index: usize) -> Result<FitsHdu, String> {
+    let bitpix = int_card(&cards, "BITPIX").ok_or("FITS header has no BITPIX")?;
+    let naxis_count = int_card(&cards, "NAXIS").ok_or("FITS header has no NAXIS")?;
+    if !(0..=999).contains(&naxis_count) || ![8, 16, 32, 64, -32, -64].contains(&bitpix) {
+        return Err(format!("Invalid FITS header: BITPIX={} NAXIS={}", bitpix, naxis_count));
+    }
+    let naxis = (1..=naxis_count)
+        .map(|i| int_card(&cards, &format!("NAXIS{}", i)).ok_or_else(|| format!("FITS header has no NAXIS{}", i)))
+        .collect::<Result<Vec<i64>, String>>()?;
+
+    let data_bytes = if naxis.is_empty() {
+        0
+    } else {
+        let pcount = int_card(&cards, "PCOUNT").unwrap_or(0);
+        let gcount = int_card(&cards, "GCOUNT").unwrap_or(1);
+        naxis
+            .iter()
+            .try_fold(1i64, |acc, n| acc.checked_mul(*n))
+            .and_then(|elements| elements.checked_add(pcount))
+            .and_then(|n| n.checked_mul(gcount))
+            .and_then(|n| n.checked_mul(bitpix.abs() / 8))
+            .and_then(|n| u64::try_from(n).ok())
+            .ok_or("Invalid FITS data size")?
+    };
+
+    let xtension = (index > 0).then(|| str_card(&cards, "XTENSION")).flatten();
+    let name = str_card(&cards, "EXTNAME").unwrap_or_else(|| {
+        if index == 0 { "PRIMARY".to_string() } else { format!("HDU{}", index) }
+    });
+
+    Ok(FitsHdu {
+        index,
+        name,
+        xtension,
+        bitpix,
+        naxis,
+        header_offset,
+        data_offset,
+        data_bytes,
+        cards,
+    })
+}
+
+/// Карточка "KEYWORD = value / comment" или комментарий (COMMENT, HISTORY)
+pub fn parse_card(card: &str) -> FitsCard {
+    let keyword = card[..8.min(card.len())].trim_end().to_string();
+
+    if card.get(8..10) != Some("= ") {
+        let text = card.get(8..).unwrap_or_default().trim();
+        return FitsCard {
+            keyword,
+            value: Value::Null,
+            comment: (!text.is_empty()).then(|| text.to_string()),
+        };
+    }
+
+    let (value, comment) = parse_value(&card[10..]);
+    FitsCard { keyword, value, comment }
+}
+
+fn parse_value(field: &str) -> (Value, Option<String>) {
+    let field = field.trim_start();
+
+    if let Some(rest) = field.strip_prefix('\'') {
+        // Кавычка внутри строки записывается как ''
+        let mut text = String::new();
+        let mut end = rest.len();
+        let mut chars = rest.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            if c != '\'' {
+                text.push(c);
+            } else if matches!(chars.peek(), Some((_, '\''))) {
+                chars.next();
+                text.push('\'');
+            } else {
+                end = i + 1;
+                break;
+            }
+        }
+        // Завершающие пробелы в строках FITS незначимы
+        return (Value::String(text.trim_end().to_string()), comment_after(&rest[end..]));
+    }
+
+    let (token, comment) = match field.find('/') {
+        Some(i) => (&field[..i], comment_after(&field[i..])),
+        None => (field, None),
+    };
+    (parse_scalar(token.trim()), comment)
+}
+
+fn comment_after(rest: &str) -> Option<String> {
+    rest.trim_start()
+        .strip_prefix('/')
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(str::to_string)
+}
+
+fn parse_scalar(token: &str) -> Value {
+    match token {
+        "" => Value::Null,
+        "T" => Value::Bool(true),
+        "F" => Value::Bool(false),
+        _ => {
+            if let Ok(n) = token.parse::<i64>() {
+                return Value::from(n);
+            }
+            // Показатель степени двойной точности: 1.5D+03
+            token
+                .replace(['D', 'd'], "E")
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number)
+                .unwrap_or_else(|| Value::String(token.to_string()))
+        }
+    }
+}
+
+/// Длинные строки: значение, оканчивающееся на &, продолжается в карточках CONTINUE
+fn append_continue(cards: &mut [FitsCard], field: &str) {
+    let Some(Value::String(text)) = cards.last_mut().map(|c| &mut c.value) else {
+        return;
+    };
+    let Some(prefix) = text.strip_suffix('&') else {
+        return;
+    };
+    if let (Value::String(next), _) = parse_value(field) {
+        *text = format!("{}{}", prefix, next);
+    }
+}
+
+fn card_value<'a>(cards: &'a [FitsCard], keyword: &str) -> Option<&'a Value> {
+    cards.iter().find(|c| c.keyword == keyword).map(|c| &c.value)
+}
+
+fn int_card(cards: &[FitsCard], keyword: &str) -> Option<i64> {
+    card_value(cards, keyword).and_then(Value::as_i64)
+}
+
+fn float_card(cards: &[FitsCard], keyword: &str) -> Option<f64> {
+    card_value(cards, keyword).and_then(Value::as_f64)
+}
+
+/// Непустое строковое (или числовое, как PROGRAM) значение
+fn str_card(cards: &[FitsCard], keyword: &str) -> Option<String> {
+    match card_value(cards, keyword)? {
+        Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Ключевые поля по всем HDU: приоритет у ключа, затем у более раннего HDU
+pub fn summarize(hdus: &[FitsHdu]) -> FitsSummary {
+    let text = |keys: &[&str]| {
+        keys.iter()
+            .find_map(|key| hdus.iter().find_map(|hdu| str_card(&hdu.cards, key)))
+    };
+    let number = |keys: &[&str]| {
+        keys.iter()
+            .find_map(|key| hdus.iter().find_map(|hdu| float_card(&hdu.cards, key)))
+    };
+
+    let date_obs = text(&["DATE-BEG"]).or_else(|| {
+        let date = text(&["DATE-OBS"])?;
+        match text(&["TIME-OBS"]) {
+            Some(time) if !date.contains('T') => Some(format!("{}T{}", date, time)),
+            _ => Some(date),
+        }
+    });
+
+    let image = hdus.iter().find(|hdu| card_value(&hdu.cards, "CTYPE1").is_some());
+
+    FitsSummary {
+        instrument: text(&["INSTRUME"]),
+        detector: text(&["DETECTOR"]),
+        filter: text(&["FILTER"]),
+        pupil: text(&["PUPIL"]),
+        exposure_time: number(&["EFFEXPTM", "EXPTIME", "XPOSURE", "TEXPTIME"]),
+        target: text(&["TARGPROP", "TARGNAME", "OBJECT"]),
+        program: text(&["PROGRAM"]),
+        date_obs,
+        image_size: image.map(|hdu| hdu.naxis.clone()),
+        wcs: image.and_then(build_wcs),
+    }
+}
+
+/// WCS из карточек CTYPEi/CRVALi/CRPIXi и CDi_j (или PCi_j с CDELTi)
+fn build_wcs(hdu: &FitsHdu) -> Option<FitsWcs> {
+    let cards = &hdu.cards;
+    let axes = int_card(cards, "WCSAXES")
+        .and_then(|n| usize::try_from(n).ok())
+        .unwrap_or(hdu.naxis.len())
+        .min(9);
+    if axes == 0 {
+        return None;
+    }
+
+    let per_axis = |prefix: &str, default: f64| -> Vec<f64> {
+        (1..=axes)
+            .map(|i| float_card(cards, &format!("{}{}", prefix, i)).unwrap_or(default))
+            .collect()
+    };
+
+    let has_cd = cards.iter().any(|c| c.keyword.starts_with("CD") && c.keyword.contains('_'));
+    let cdelt = per_axis("CDELT", 1.0);
+    let cd: Vec<Vec<f64>> = (1..=axes)
+        .map(|i| {
+            (1..=axes)
+                .map(|j| {
+                    if has_cd {
+                        float_card(cards, &format!("CD{}_{}", i, j)).unwrap_or(0.0)
+                    } else {
+                        let identity = if i == j { 1.0 } else { 0.0 };
+                        float_card(cards, &format!("PC{}_{}", i, j)).unwrap_or(identity) * cdelt[i - 1]
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let in_degrees = (1..=axes.min(2))
+        .all(|i| str_card(cards, &format!("CUNIT{}", i)).is_none_or(|u| u.eq_ignore_ascii_case("deg")));
+    let pixel_scale_arcsec = (axes >= 2 && in_degrees)
+        .then(|| (cd[0][0] * cd[1][1] - cd[0][1] * cd[1][0]).abs())
+        .filter(|det| *det > 0.0)
+        .map(|det| det.sqrt() * 3600.0);
+
+    Some(FitsWcs {
+        ctype: (1..=axes)
+            .map(|i| str_card(cards, &format!("CTYPE{}", i)).unwrap_or_default())
+            .collect(),
+        crval: per_axis("CRVAL", 0.0),
+        crpix: per_axis("CRPIX", 0.0),
+        cd,
+        radesys: str_card(cards, "RADESYS"),
+        pixel_scale_arcsec,
+    })
+}
+
+/// Имя файла продукта MAST: jw02731-o001_t017_nircam_clear-f187n_i2d.fits
+pub fn is_product_name(file: &str) -> bool {
+    file.len() <= 200
+        && file.ends_with(".fits")
+        && file.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+        && !file.starts_with('.')
+}
+
+#[cfg(test)]
+mod tests;
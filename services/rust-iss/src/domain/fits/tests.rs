@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    /// Заголовок из карточек: каждая дополняется до 80 символов, блок - пробелами до 2880
+    fn header(cards: &[&str]) -> Vec<u8> {
+        let mut bytes: Vec<u8> = cards
+            .iter()
+            .chain(std::iter::once(&"END"))
+            .flat_map(|card| format!("{:<80}", card).into_bytes())
+            .collect();
+        bytes.resize(bytes.len().div_ceil(BLOCK_SIZE) * BLOCK_SIZE, b' ');
+        bytes
+    }
+
+    /// Маленький продукт NIRCam: пустой первичный HDU и SCI-изображение 4x3 float32 с WCS
+    fn nircam_fixture() -> Vec<u8> {
+        let mut bytes = header(&[
+            "SIMPLE  =                    T / conforms to FITS standard",
+            "BITPIX  =                    8 / array data type",
+            "NAXIS   =                    0 / number of array dimensions",
+            "EXTEND  =                    T",
+            "PROGRAM = '02731   '           / JWST program number",
+            "TARGPROP= 'NGC3324 '           / Proposer's name for the target",
+            "INSTRUME= 'NIRCAM  '           / Instrument used to acquire the data",
+            "DETECTOR= 'NRCALONG'",
+            "FILTER  = 'F444W   '",
+            "PUPIL   = 'CLEAR   '",
+            "DATE-OBS= '2022-06-03'",
+            "TIME-OBS= '12:34:56.789'",
+            "EFFEXPTM=     1159.52         / [s] Effective exposure time",
+            "HISTORY processed by calwebb_image3",
+        ]);
+        bytes.extend(header(&[
+            "XTENSION= 'IMAGE   '           / Image extension",
+            "BITPIX  =                  -32",
+            "NAXIS   =                    2",
+            "NAXIS1  =                    4",
+            "NAXIS2  =                    3",
+            "PCOUNT  =                    0",
+            "GCOUNT  =                    1",
+            "EXTNAME = 'SCI     '",
+            "RADESYS = 'ICRS    '",
+            "CTYPE1  = 'RA---TAN'",
+            "CTYPE2  = 'DEC--TAN'",
+            "CUNIT1  = 'deg     '",
+            "CUNIT2  = 'deg     '",
+            "CRVAL1  =      159.2176389     ",
+            "CRVAL2  =     -58.61638889     ",
+            "CRPIX1  =                  2.5",
+            "CRPIX2  =                  2.0",
+            "CDELT1  =    1.748611111111D-5",
+            "CDELT2  =    1.748611111111D-5",
+            "PC1_1   =                 -1.0",
+            "PC2_2   =                  1.0",
+        ]));
+        // 4 * 3 * 4 байта данных, дополненных до блока
+        bytes.extend(vec![0u8; BLOCK_SIZE]);
+        bytes
+    }
+
+    #[test]
+    fn test_parse_file_with_extension() {
+        let (hdus, truncated) = parse_file(&nircam_fixture()).unwrap();
+        assert!(!truncated);
+        assert_eq!(hdus.len(), 2);
+
+        assert_eq!(hdus[0].name, "PRIMARY");
+        assert_eq!(hdus[0].data_bytes, 0);
+        assert_eq!(hdus[1].name, "SCI");
+        assert_eq!(hdus[1].xtension.as_deref(), Some("IMAGE"));
+        assert_eq!(hdus[1].header_offset, BLOCK_SIZE as u64);
+        assert_eq!(hdus[1].naxis, vec![4, 3]);
+        assert_eq!(hdus[1].data_bytes, 48);
+        assert_eq!(next_hdu_offset(&hdus[1]), 3 * BLOCK_SIZE as u64);
+
+        let history = hdus[0].cards.iter().find(|c| c.keyword == "HISTORY").unwrap();
+        assert_eq!(history.comment.as_deref(), Some("processed by calwebb_image3"));
+    }
+
+    #[test]
+    fn test_summary_and_wcs() {
+        let (hdus, _) = parse_file(&nircam_fixture()).unwrap();
+        let summary = summarize(&hdus);
+
+        assert_eq!(summary.instrument.as_deref(), Some("NIRCAM"));
+        assert_eq!(summary.filter.as_deref(), Some("F444W"));
+        assert_eq!(summary.pupil.as_deref(), Some("CLEAR"));
+        assert_eq!(summary.target.as_deref(), Some("NGC3324"));
+        assert_eq!(summary.program.as_deref(), Some("02731"));
+        assert_eq!(summary.exposure_time, Some(1159.52));
+        assert_eq!(summary.date_obs.as_deref(), Some("2022-06-03T12:34:56.789"));
+        assert_eq!(summary.image_size, Some(vec![4, 3]));
+
+        let wcs = summary.wcs.unwrap();
+        assert_eq!(wcs.ctype, vec!["RA---TAN".to_string(), "DEC--TAN".to_string()]);
+        assert_eq!(wcs.crval, vec![159.2176389, -58.61638889]);
+        assert_eq!(wcs.radesys.as_deref(), Some("ICRS"));
+        assert!((wcs.cd[0][0] + 1.748611111111e-5).abs() < 1e-15);
+        assert!((wcs.pixel_scale_arcsec.unwrap() - 0.06295).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_card_values() {
+        let card = parse_card("OBSERVER= 'O''Brien'           / quoted apostrophe");
+        assert_eq!(card.value, Value::String("O'Brien".to_string()));
+        assert_eq!(card.comment.as_deref(), Some("quoted apostrophe"));
+
+        assert_eq!(parse_card("SUBARRAY=                    F").value, Value::Bool(false));
+        assert_eq!(parse_card("GAIN    =                 2.05").value, Value::from(2.05));
+        assert_eq!(parse_card("UNDEF   =                     / no value").value, Value::Null);
+    }
+
+    #[test]
+    fn test_continue_long_string() {
+        let bytes = header(&[
+            "SIMPLE  =                    T",
+            "BITPIX  =                    8",
+            "NAXIS   =                    0",
+            "TITLE   = 'Cosmic Cliffs in the &'",
+            "CONTINUE  'Carina Nebula'",
+        ]);
+        let hdu = parse_hdu(&bytes, 0, 0).unwrap().unwrap();
+        let title = hdu.cards.iter().find(|c| c.keyword == "TITLE").unwrap();
+        assert_eq!(title.value, Value::String("Cosmic Cliffs in the Carina Nebula".to_string()));
+    }
+
+    #[test]
+    fn test_leading_bytes_are_truncated() {
+        let bytes = nircam_fixture();
+
+        // Только первичный заголовок и половина расширения
+        let (hdus, truncated) = parse_file(&bytes[..BLOCK_SIZE + 1000]).unwrap();
+        assert!(truncated);
+        assert_eq!(hdus.len(), 1);
+
+        // Заголовки целиком, но без данных изображения
+        let (hdus, truncated) = parse_file(&bytes[..2 * BLOCK_SIZE]).unwrap();
+        assert!(truncated);
+        assert_eq!(hdus.len(), 2);
+
+        assert_eq!(parse_hdu(&bytes[..100], 0, 0).unwrap(), None);
+    }
+
+    #[test]
+    fn test_rejects_non_fits() {
+        assert!(parse_file(b"<html><body>Not found</body></html>").is_err());
+        assert!(parse_hdu(&header(&["SIMPLE  =                    T"]), 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_product_name() {
+        assert!(is_product_name("jw02731-o001_t017_nircam_clear-f444w_i2d.fits"));
+        assert!(!is_product_name("jw02731-o001_t017_nircam_clear-f444w_i2d.jpg"));
+        assert!(!is_product_name("../secret.fits"));
+    }
+}
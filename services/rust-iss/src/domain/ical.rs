@@ -0,0 +1,231 @@
+use crate::domain::{
+    iss_pass::compass_point,
+    models::{IssPass, SpaceXLaunch},
+};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+pub const CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+const PRODID: &str = "-//Cassiopeia//rust-iss//EN";
+
+/// Домен в UID событий
+const UID_DOMAIN: &str = "cassiopeia";
+
+/// Максимальная длина строки без переноса (октетов, RFC 5545 3.1)
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Как часто клиентам перечитывать подписку
+const REFRESH_INTERVAL: &str = "PT1H";
+
+/// Начало или конец события: момент времени или целый день
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventTime {
+    At(DateTime<Utc>),
+    Day(NaiveDate),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    /// Не меняется между выгрузками - по нему клиент находит событие для обновления
+    pub uid: String,
+    /// Растёт при каждом существенном изменении события
+    pub sequence: i64,
+    pub start: EventTime,
+    pub end: EventTime,
+    pub summary: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub geo: Option<(f64, f64)>,
+    pub url: Option<String>,
+    pub tentative: bool,
+    pub last_modified: Option<DateTime<Utc>>,
+    /// Напоминание за столько минут до начала
+    pub alarm_minutes: i64,
+}
+
+/// Собрать VCALENDAR (строки через CRLF, длинные строки перенесены)
+pub fn render_calendar(name: &str, events: &[CalendarEvent], now: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(name)),
+        format!("REFRESH-INTERVAL;VALUE=DURATION:{}", REFRESH_INTERVAL),
+        format!("X-PUBLISHED-TTL:{}", REFRESH_INTERVAL),
+    ];
+
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", event.uid));
+        lines.push(format!("DTSTAMP:{}", format_utc(now)));
+        lines.push(format!("SEQUENCE:{}", event.sequence));
+        lines.push(format_time("DTSTART", event.start));
+        lines.push(format_time("DTEND", event.end));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+        if let Some(description) = &event.description {
+            lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+        }
+        if let Some(location) = &event.location {
+            lines.push(format!("LOCATION:{}", escape_text(location)));
+        }
+        if let Some((lat, lon)) = event.geo {
+            lines.push(format!("GEO:{:.6};{:.6}", lat, lon));
+        }
+        if let Some(url) = &event.url {
+            lines.push(format!("URL:{}", url));
+        }
+        lines.push(format!("STATUS:{}", if event.tentative { "TENTATIVE" } else { "CONFIRMED" }));
+        lines.push("TRANSP:TRANSPARENT".to_string());
+        if let Some(last_modified) = event.last_modified {
+            lines.push(format!("LAST-MODIFIED:{}", format_utc(last_modified)));
+        }
+        lines.push("BEGIN:VALARM".to_string());
+        lines.push("ACTION:DISPLAY".to_string());
+        lines.push(format!("DESCRIPTION:{}", escape_text(&event.summary)));
+        lines.push(format!("TRIGGER:-PT{}M", event.alarm_minutes));
+        lines.push("END:VALARM".to_string());
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    lines.iter().map(|line| fold_line(line) + "\r\n").collect()
+}
+
+/// Событие запуска; даты точнее дня - на час от T-0, с точностью до дня - на весь день.
+/// Запуски с датой до месяца, квартала или года в календарь не попадают
+pub fn launch_event(
+    launch: &SpaceXLaunch,
+    sequence: i64,
+    last_modified: Option<DateTime<Utc>>,
+    alarm_minutes: i64,
+) -> Option<CalendarEvent> {
+    let (start, end) = match launch.date_precision.as_str() {
+        "hour" => (EventTime::At(launch.date_utc), EventTime::At(launch.date_utc + Duration::hours(1))),
+        "day" => {
+            let day = launch.date_utc.date_naive();
+            (EventTime::Day(day), EventTime::Day(day.succ_opt()?))
+        }
+        _ => return None,
+    };
+
+    let rocket = launch.rocket_name.as_deref().unwrap_or("SpaceX");
+    let summary = if launch.net && launch.upcoming {
+        format!("NET {}: {}", rocket, launch.name)
+    } else {
+        format!("{}: {}", rocket, launch.name)
+    };
+
+    let mut description = Vec::new();
+    if let Some(details) = &launch.details {
+        description.push(details.clone());
+    }
+    match (launch.upcoming, launch.success) {
+        (true, _) if launch.tbd => description.push("Date to be determined".to_string()),
+        (true, _) => {}
+        (false, Some(true)) => description.push("Result: success".to_string()),
+        (false, Some(false)) => description.push("Result: failure".to_string()),
+        (false, None) => description.push("Result: pending".to_string()),
+    }
+    if let Some(webcast) = &launch.webcast_url {
+        description.push(format!("Webcast: {}", webcast));
+    }
+
+    Some(CalendarEvent {
+        uid: format!("spacex-{}@{}", launch.id, UID_DOMAIN),
+        sequence,
+        start,
+        end,
+        summary,
+        description: (!description.is_empty()).then(|| description.join("\n")),
+        location: launch.launchpad_name.clone(),
+        geo: None,
+        url: launch.webcast_url.clone(),
+        tentative: launch.upcoming && (launch.tbd || launch.net),
+        last_modified,
+        alarm_minutes,
+    })
+}
+
+/// Событие пролёта МКС. UID строится из номера витка и координат наблюдателя,
+/// SEQUENCE - из эпохи TLE: новые элементы орбиты дают новую версию прогноза
+pub fn pass_event(pass: &IssPass, lat: f64, lon: f64, tle_epoch: DateTime<Utc>, alarm_minutes: i64) -> CalendarEvent {
+    let description = format!(
+        "Rise {} UTC, azimuth {:.0}° ({})\nMaximum {:.0}° at {} UTC\nSet {} UTC, azimuth {:.0}° ({})",
+        pass.rise_at.format("%H:%M:%S"),
+        pass.rise_azimuth_deg,
+        compass_point(pass.rise_azimuth_deg),
+        pass.max_elevation_deg,
+        pass.culmination_at.format("%H:%M:%S"),
+        pass.set_at.format("%H:%M:%S"),
+        pass.set_azimuth_deg,
+        compass_point(pass.set_azimuth_deg),
+    );
+
+    CalendarEvent {
+        uid: format!("iss-pass-{}-{:.2}-{:.2}@{}", pass.revolution, lat, lon, UID_DOMAIN),
+        sequence: tle_epoch.timestamp() / 3600,
+        start: EventTime::At(pass.rise_at),
+        end: EventTime::At(pass.set_at),
+        summary: format!(
+            "ISS pass, max {:.0}° ({} → {})",
+            pass.max_elevation_deg,
+            compass_point(pass.rise_azimuth_deg),
+            compass_point(pass.set_azimuth_deg)
+        ),
+        description: Some(description),
+        location: None,
+        geo: Some((lat, lon)),
+        url: None,
+        tentative: false,
+        last_modified: Some(tle_epoch),
+        alarm_minutes,
+    }
+}
+
+fn format_utc(at: DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn format_time(property: &str, time: EventTime) -> String {
+    match time {
+        EventTime::At(at) => format!("{}:{}", property, format_utc(at)),
+        EventTime::Day(day) => format!("{};VALUE=DATE:{}", property, day.format("%Y%m%d")),
+    }
+}
+
+/// Экранирование значения TEXT (RFC 5545 3.3.11)
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Перенос строки длиннее 75 октетов: продолжение начинается с пробела, UTF-8 символы не разрезаются
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / MAX_LINE_OCTETS * 3);
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            // Пробел продолжения входит в лимит
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use chrono::TimeZone;
+
+    fn launch() -> SpaceXLaunch {
+        SpaceXLaunch {
+            id: "62dd70d5202306255024d139".to_string(),
+            name: "Crew-5".to_string(),
+            flight_number: 187,
+            date_utc: Utc.with_ymd_and_hms(2022, 10, 5, 16, 0, 0).unwrap(),
+            date_precision: "hour".to_string(),
+            upcoming: true,
+            success: None,
+            tbd: false,
+            net: true,
+            rocket_id: Some("5e9d0d95eda69973a809d1ec".to_string()),
+            rocket_name: Some("Falcon 9".to_string()),
+            launchpad_id: Some("5e9e4502f509094188566f88".to_string()),
+            launchpad_name: Some("KSC LC 39A".to_string()),
+            payload_ids: vec![],
+            details: Some("Crew rotation; four astronauts, to the ISS".to_string()),
+            patch_url: None,
+            webcast_url: Some("https://youtu.be/5EwW8ZkArL4".to_string()),
+            failure_reasons: vec![],
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2022, 10, 1, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_launch_event() {
+        let event = launch_event(&launch(), 2, None, 60).unwrap();
+        assert_eq!(event.uid, "spacex-62dd70d5202306255024d139@cassiopeia");
+        assert_eq!(event.summary, "NET Falcon 9: Crew-5");
+        assert!(event.tentative);
+        assert_eq!(event.end, EventTime::At(Utc.with_ymd_and_hms(2022, 10, 5, 17, 0, 0).unwrap()));
+
+        let mut by_day = launch();
+        by_day.date_precision = "day".to_string();
+        let event = launch_event(&by_day, 0, None, 60).unwrap();
+        assert_eq!(event.start, EventTime::Day(NaiveDate::from_ymd_opt(2022, 10, 5).unwrap()));
+        assert_eq!(event.end, EventTime::Day(NaiveDate::from_ymd_opt(2022, 10, 6).unwrap()));
+
+        let mut by_month = launch();
+        by_month.date_precision = "month".to_string();
+        assert!(launch_event(&by_month, 0, None, 60).is_none());
+    }
+
+    #[test]
+    fn test_render_calendar() {
+        let event = launch_event(&launch(), 3, Some(now()), 45).unwrap();
+        let ics = render_calendar("SpaceX launches", &[event], now());
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:"));
+        assert!(ics.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(!ics.replace("\r\n", "").contains('\n'));
+        assert!(ics.contains("\r\nUID:spacex-62dd70d5202306255024d139@cassiopeia\r\n"));
+        assert!(ics.contains("\r\nDTSTAMP:20221001T000000Z\r\n"));
+        assert!(ics.contains("\r\nSEQUENCE:3\r\n"));
+        assert!(ics.contains("\r\nDTSTART:20221005T160000Z\r\n"));
+        assert!(ics.contains("\r\nSTATUS:TENTATIVE\r\n"));
+        assert!(ics.contains("\r\nBEGIN:VALARM\r\nACTION:DISPLAY\r\n"));
+        assert!(ics.contains("\r\nTRIGGER:-PT45M\r\n"));
+        assert!(ics.contains("DESCRIPTION:Crew rotation\\; four astronauts\\, to the ISS\\n"));
+    }
+
+    #[test]
+    fn test_all_day_event_uses_date_values() {
+        let mut by_day = launch();
+        by_day.date_precision = "day".to_string();
+        let ics = render_calendar("SpaceX launches", &[launch_event(&by_day, 0, None, 60).unwrap()], now());
+        assert!(ics.contains("\r\nDTSTART;VALUE=DATE:20221005\r\nDTEND;VALUE=DATE:20221006\r\n"));
+    }
+
+    #[test]
+    fn test_long_lines_are_folded_on_char_boundaries() {
+        let line = format!("SUMMARY:{}", "Пролёт МКС ".repeat(12));
+        let folded = fold_line(&line);
+
+        for (index, part) in folded.split("\r\n").enumerate() {
+            assert!(part.len() <= MAX_LINE_OCTETS, "{} octets", part.len());
+            if index > 0 {
+                assert!(part.starts_with(' '));
+            }
+        }
+        let unfolded = folded.replace("\r\n ", "");
+        assert_eq!(unfolded, line);
+        assert_eq!(fold_line("SUMMARY:short"), "SUMMARY:short");
+    }
+
+    #[test]
+    fn test_pass_event_is_stable_across_predictions() {
+        let pass = IssPass {
+            rise_at: Utc.with_ymd_and_hms(2022, 10, 2, 18, 1, 5).unwrap(),
+            culmination_at: Utc.with_ymd_and_hms(2022, 10, 2, 18, 4, 30).unwrap(),
+            set_at: Utc.with_ymd_and_hms(2022, 10, 2, 18, 7, 55).unwrap(),
+            duration_seconds: 410,
+            max_elevation_deg: 67.4,
+            rise_azimuth_deg: 230.0,
+            set_azimuth_deg: 45.0,
+            revolution: 36512,
+        };
+        let first = pass_event(&pass, 55.7558, 37.6173, now(), 10);
+
+        // Следующий прогноз по свежему TLE сдвигает время на секунды
+        let mut updated = pass.clone();
+        updated.rise_at += Duration::seconds(4);
+        let second = pass_event(&updated, 55.7558, 37.6173, now() + Duration::hours(12), 10);
+
+        assert_eq!(first.uid, "iss-pass-36512-55.76-37.62@cassiopeia");
+        assert_eq!(first.uid, second.uid);
+        assert!(second.sequence > first.sequence);
+        assert_eq!(first.summary, "ISS pass, max 67° (SW → NE)");
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    domain::models::{IssPass, Tle},
+    utils::orbit::{look_angles, tle_position, tle_revolution},
+};
+use chrono::{DateTime, Duration, Utc};
+
+/// Шаг поиска пролётов (секунд); короче самого короткого пролёта над 0°
+const SEARCH_STEP_SECONDS: i64 = 20;
+
+/// Угол места и азимут МКС для наблюдателя
+fn angles(tle: &Tle, lat: f64, lon: f64, at: DateTime<Utc>) -> (f64, f64) {
+    look_angles(lat, lon, tle_position(tle, at), at)
+}
+
+/// Пролёты над наблюдателем с углом места не ниже min_elevation_deg в окне [start, end).
+/// Пролёты, начавшиеся до start или не закончившиеся к end, не включаются
+pub fn find_passes(
+    tle: &Tle,
+    lat: f64,
+    lon: f64,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    min_elevation_deg: f64,
+) -> Vec<IssPass> {
+    let above = |at: DateTime<Utc>| angles(tle, lat, lon, at).0 >= min_elevation_deg;
+    let step = Duration::seconds(SEARCH_STEP_SECONDS);
+
+    let mut passes = Vec::new();
+    let mut rise: Option<DateTime<Utc>> = None;
+    let mut was_above = above(start);
+    let mut t = start;
+
+    while t < end {
+        let next = t + step;
+        let is_above = above(next);
+
+        if !was_above && is_above {
+            rise = Some(crossing(&above, t, next).1);
+        } else if was_above && !is_above {
+            if let Some(rise_at) = rise.take() {
+                let set_at = crossing(&above, t, next).0;
+                if set_at < end {
+                    passes.push(describe_pass(tle, lat, lon, rise_at, set_at));
+                }
+            }
+        }
+
+        was_above = is_above;
+        t = next;
+    }
+
+    passes
+}
+
+/// Пересечение порога между before и after с точностью до секунды:
+/// последний момент в состоянии before и первый - в состоянии after
+fn crossing(
+    above: &impl Fn(DateTime<Utc>) -> bool,
+    before: DateTime<Utc>,
+    after: DateTime<Utc>,
+) -> (DateTime<Utc>, DateTime<Utc>) {
+    let rising = above(after);
+    let (mut low, mut high) = (before, after);
+    while high - low > Duration::seconds(1) {
+        let mid = low + (high - low) / 2;
+        if above(mid) == rising {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    (low, high)
+}
+
+fn describe_pass(tle: &Tle, lat: f64, lon: f64, rise_at: DateTime<Utc>, set_at: DateTime<Utc>) -> IssPass {
+    let (mut culmination_at, mut max_elevation_deg) = (rise_at, f64::MIN);
+    let mut t = rise_at;
+    while t <= set_at {
+        let elevation = angles(tle, lat, lon, t).0;
+        if elevation > max_elevation_deg {
+            culmination_at = t;
+            max_elevation_deg = elevation;
+        }
+        t += Duration::seconds(1);
+    }
+
+    IssPass {
+        rise_at,
+        culmination_at,
+        set_at,
+        duration_seconds: (set_at - rise_at).num_seconds(),
+        max_elevation_deg,
+        rise_azimuth_deg: angles(tle, lat, lon, rise_at).1,
+        set_azimuth_deg: angles(tle, lat, lon, set_at).1,
+        revolution: tle_revolution(tle, culmination_at),
+    }
+}
+
+/// Румб по азимуту (8 направлений)
+pub fn compass_point(azimuth_deg: f64) -> &'static str {
+    const POINTS: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+    POINTS[((azimuth_deg.rem_euclid(360.0) + 22.5) / 45.0) as usize % 8]
+}
+
+#[cfg(test)]
+mod tests;
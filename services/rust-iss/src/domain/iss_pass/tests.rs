@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use crate::utils::{geo::from_vector, orbit::{gmst, parse_tle}};
+
+    fn tle() -> Tle {
+        parse_tle(
+            "ISS (ZARYA)",
+            "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927",
+            "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537",
+        )
+        .unwrap()
+    }
+
+    /// Подспутниковая точка (геоцентрическая широта)
+    fn subpoint(tle: &Tle, at: DateTime<Utc>) -> (f64, f64) {
+        let p = tle_position(tle, at);
+        let (sin_g, cos_g) = gmst(at).sin_cos();
+        from_vector([cos_g * p[0] + sin_g * p[1], -sin_g * p[0] + cos_g * p[1], p[2]])
+    }
+
+    #[test]
+    fn test_overhead_pass() {
+        let tle = tle();
+        let overhead_at = tle.epoch + Duration::hours(30);
+        let (lat, lon) = subpoint(&tle, overhead_at);
+
+        let passes = find_passes(&tle, lat, lon, tle.epoch + Duration::hours(29), tle.epoch + Duration::hours(31), 10.0);
+        let pass = passes
+            .iter()
+            .find(|p| p.rise_at <= overhead_at && overhead_at <= p.set_at)
+            .expect("pass over the subsatellite point");
+
+        assert!(pass.max_elevation_deg > 80.0, "max elevation {}", pass.max_elevation_deg);
+        assert!((pass.culmination_at - overhead_at).num_seconds().abs() <= 10);
+        assert!((180..=480).contains(&pass.duration_seconds), "duration {}", pass.duration_seconds);
+        // Восход и заход с противоположных сторон неба
+        let turn = (pass.set_azimuth_deg - pass.rise_azimuth_deg).rem_euclid(360.0);
+        assert!((150.0..210.0).contains(&turn), "azimuths {} -> {}", pass.rise_azimuth_deg, pass.set_azimuth_deg);
+    }
+
+    #[test]
+    fn test_passes_respect_threshold_and_window() {
+        let tle = tle();
+        let (start, end) = (tle.epoch, tle.epoch + Duration::days(2));
+        let passes = find_passes(&tle, 55.75, 37.62, start, end, 10.0);
+
+        assert!(!passes.is_empty());
+        for pass in &passes {
+            assert!(start <= pass.rise_at && pass.set_at < end);
+            assert!(pass.max_elevation_deg >= 10.0);
+            assert!(angles(&tle, 55.75, 37.62, pass.rise_at).0 >= 10.0);
+            assert!(angles(&tle, 55.75, 37.62, pass.rise_at - Duration::seconds(1)).0 < 10.0);
+        }
+        for pair in passes.windows(2) {
+            assert!(pair[0].set_at < pair[1].rise_at);
+            assert!(pair[0].revolution < pair[1].revolution);
+        }
+
+        // У полюса МКС не поднимается выше 10°
+        assert!(find_passes(&tle, -89.0, 0.0, start, end, 10.0).is_empty());
+    }
+
+    #[test]
+    fn test_compass_point() {
+        assert_eq!(compass_point(0.0), "N");
+        assert_eq!(compass_point(350.0), "N");
+        assert_eq!(compass_point(44.0), "NE");
+        assert_eq!(compass_point(225.0), "SW");
+    }
+}
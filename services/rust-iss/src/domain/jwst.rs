@@ -0,0 +1,92 @@
+use crate::domain::models::{JwstApiImage, JwstImage};
+use chrono::Utc;
+use serde_json::Value;
+
+/// Разобрать ответ jwstapi.com (массив или {"body": [...]}) в записи каталога
+pub fn parse_images(response: Value, program: &str) -> Result<Vec<JwstImage>, String> {
+    let items = match response {
+        Value::Array(items) => items,
+        Value::Object(mut object) => match object.remove("body") {
+            Some(Value::Array(items)) => items,
+            _ => return Err("Unexpected JWST response: no body array".to_string()),
+        },
+        Value::Null => Vec::new(),
+        _ => return Err("Unexpected JWST response".to_string()),
+    };
+
+    items
+        .into_iter()
+        .map(|item| {
+            serde_json::from_value::<JwstApiImage>(item)
+                .map(|image| to_jwst_image(image, program))
+                .map_err(|e| format!("Unexpected JWST image: {}", e))
+        })
+        .collect()
+}
+
+pub fn to_jwst_image(image: JwstApiImage, program: &str) -> JwstImage {
+    let details = image.details.as_ref();
+    let now = Utc::now().naive_utc();
+
+    let program = match &image.program {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) if !s.is_empty() => s.clone(),
+        _ => program.to_string(),
+    };
+
+    let instrument = details
+        .and_then(|d| d.instruments.first())
+        .and_then(|i| i.as_str().or_else(|| i.get("instrument").and_then(Value::as_str)))
+        .map(str::to_uppercase);
+
+    let suffix = details
+        .and_then(|d| d.suffix.clone())
+        .or(image.suffix.clone())
+        .map(|s| s.trim_start_matches('_').to_lowercase())
+        .filter(|s| !s.is_empty());
+
+    let filters = match details.map(|d| &d.filters) {
+        Some(filters) if !filters.is_empty() => filters.iter().map(|f| f.to_uppercase()).collect(),
+        _ => filters_from_name(&image.id),
+    };
+
+    JwstImage {
+        id: None,
+        observation_id: image.observation_id,
+        program,
+        description: details.and_then(|d| d.description.clone()).filter(|d| !d.is_empty()),
+        title: image.id,
+        image_url: image.location,
+        thumbnail_url: image.thumbnail.filter(|t| !t.is_empty()),
+        file_type: image.file_type.to_lowercase(),
+        suffix,
+        instrument,
+        filters,
+        observation_date: None,
+        updated_at: now,
+        first_seen_at: now,
+        fits: None,
+    }
+}
+
+/// Фильтры из имени файла MAST: jw02731-o001_t017_nircam_clear-f187n_i2d.jpg -> [F187N]
+pub fn filters_from_name(name: &str) -> Vec<String> {
+    let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+    stem.split(['_', '-'])
+        .filter(|token| is_filter_name(token))
+        .map(str::to_uppercase)
+        .collect()
+}
+
+/// F + 3-4 цифры длины волны + ширина полосы (W, W2, M, N, LP)
+fn is_filter_name(token: &str) -> bool {
+    let token = token.to_ascii_lowercase();
+    let Some(rest) = token.strip_prefix('f') else {
+        return false;
+    };
+    let digits = rest.chars().take_while(char::is_ascii_digit).count();
+    (3..=4).contains(&digits) && matches!(&rest[digits..], "w" | "w2" | "m" | "n" | "lp")
+}
+
+#[cfg(test)]
+mod tests;
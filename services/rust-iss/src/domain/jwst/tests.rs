@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_jwstapi_body() {
+        let response = json!({
+            "statusCode": 200,
+            "body": [{
+                "id": "jw02734-o002_t001_nircam_clear-f444w_i2d.jpg",
+                "observation_id": "jw02734-o002_t001_nircam_clear-f444w",
+                "program": 2734,
+                "details": {
+                    "mission": "JWST",
+                    "instruments": [{"instrument": "NIRCam"}],
+                    "suffix": "_i2d",
+                    "description": "image/jpeg"
+                },
+                "file_type": "JPG",
+                "thumbnail": "",
+                "location": "https://example.com/jw02734_i2d.jpg"
+            }]
+        });
+
+        let images = parse_images(response, "0").unwrap();
+
+        assert_eq!(images.len(), 1);
+        let image = &images[0];
+        assert_eq!(image.program, "2734");
+        assert_eq!(image.instrument.as_deref(), Some("NIRCAM"));
+        assert_eq!(image.suffix.as_deref(), Some("i2d"));
+        assert_eq!(image.filters, vec!["F444W".to_string()]);
+        assert_eq!(image.file_type, "jpg");
+        assert!(image.thumbnail_url.is_none());
+        assert_eq!(image.title, "jw02734-o002_t001_nircam_clear-f444w_i2d.jpg");
+    }
+
+    #[test]
+    fn test_parse_plain_array_with_string_instruments() {
+        let response = json!([{
+            "id": "demo",
+            "program": "",
+            "observation_id": "jw02731-o001_t001_miri_f1130w",
+            "suffix": "i2d",
+            "details": {"instruments": ["MIRI"], "filters": ["f1130w"]},
+            "file_type": "jpg",
+            "location": "https://example.com/miri.jpg"
+        }]);
+
+        let images = parse_images(response, "2731").unwrap();
+
+        assert_eq!(images[0].program, "2731");
+        assert_eq!(images[0].instrument.as_deref(), Some("MIRI"));
+        assert_eq!(images[0].filters, vec!["F1130W".to_string()]);
+        assert_eq!(images[0].suffix.as_deref(), Some("i2d"));
+    }
+
+    #[test]
+    fn test_parse_rejects_unexpected_shape() {
+        assert!(parse_images(json!({"error": "nope"}), "1").is_err());
+        assert!(parse_images(json!([{"id": "x"}]), "1").is_err());
+        assert!(parse_images(Value::Null, "1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_filters_from_name() {
+        assert_eq!(filters_from_name("jw02731-o001_t017_nircam_clear-f187n_i2d.jpg"), vec!["F187N"]);
+        assert_eq!(filters_from_name("jw01345_nircam_f150w2-f164n_cal.fits"), vec!["F150W2", "F164N"]);
+        assert!(filters_from_name("jw01345_fgs_image_cal.fits").is_empty());
+    }
+}
@@ -1,5 +1,19 @@
+pub mod citation;
+pub mod donki;
+pub mod eonet;
+pub mod epic;
 pub mod error;
+pub mod fits;
+pub mod ical;
+pub mod iss_pass;
+pub mod jwst;
 pub mod models;
+pub mod neo;
+pub mod provenance;
+pub mod sky;
+pub mod space_weather;
+pub mod spacex;
+pub mod watchlist;
 
 pub use error::{ApiError, ApiResponse, ErrorDetail};
 pub use models::*;
\ No newline at end of file
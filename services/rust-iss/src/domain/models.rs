@@ -30,6 +30,74 @@ pub struct IssHistoryQuery {
     pub end_date: Option<DateTime<Utc>>,
 }
 
+/// Ответ WhereTheISS /tles
+#[derive(Debug, Deserialize)]
+pub struct IssApiTle {
+    pub header: Option<String>,
+    pub line1: String,
+    pub line2: String,
+}
+
+/// Двухстрочные элементы орбиты (TLE), углы в градусах
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tle {
+    pub name: String,
+    pub line1: String,
+    pub line2: String,
+    pub epoch: DateTime<Utc>,
+    pub inclination_deg: f64,
+    pub ascending_node_deg: f64,
+    pub eccentricity: f64,
+    pub perigee_argument_deg: f64,
+    pub mean_anomaly_deg: f64,
+    /// Среднее движение (оборотов в сутки)
+    pub mean_motion_rev_per_day: f64,
+    /// Половина первой производной среднего движения (оборотов в сутки²)
+    pub mean_motion_dot: f64,
+    /// Номер витка на эпоху
+    pub revolution: i64,
+}
+
+/// Пролёт МКС над наблюдателем выше заданного угла места
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssPass {
+    pub rise_at: DateTime<Utc>,
+    pub culmination_at: DateTime<Utc>,
+    pub set_at: DateTime<Utc>,
+    pub duration_seconds: i64,
+    pub max_elevation_deg: f64,
+    pub rise_azimuth_deg: f64,
+    pub set_azimuth_deg: f64,
+    /// Номер витка в момент кульминации
+    pub revolution: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssPassPrediction {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub min_elevation_deg: f64,
+    /// Эпоха TLE, по которой рассчитан прогноз
+    pub tle_epoch: DateTime<Utc>,
+    pub passes: Vec<IssPass>,
+}
+
+/// Параметры /iss/passes и /calendar/iss-passes.ics
+#[derive(Debug, Validate, Deserialize)]
+pub struct IssPassesQuery {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: f64,
+    #[validate(range(min = 1, max = 7))]
+    pub days: Option<i64>,
+    #[validate(range(min = 0.0, max = 80.0))]
+    pub min_elevation: Option<f64>,
+    /// За сколько минут до начала напомнить (только для календаря)
+    #[validate(range(min = 0, max = 1440))]
+    pub alarm_minutes: Option<i64>,
+}
+
 // ===========================
 // OSDR Models
 // ===========================
@@ -42,11 +110,31 @@ pub struct OsdrDataset {
     pub description: Option<String>,
     pub release_date: Option<chrono::NaiveDate>,
     pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub doi: Option<String>,
+    /// Момент, когда датасет пропал из каталога OSDR (tombstone)
+    #[serde(default)]
+    pub withdrawn_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelatedOsdrDataset {
+    pub dataset_id: String,
+    pub title: String,
+    pub score: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OsdrListQuery {
+    pub include_withdrawn: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct OsdrApiResponse {
     pub results: Vec<OsdrApiDataset>,
+    /// Ответ получен от OSDR, а не из демо-данных; только такой каталог может отзывать датасеты
+    #[serde(skip)]
+    pub from_upstream: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -57,6 +145,113 @@ pub struct OsdrApiDataset {
     pub description: Option<String>,
     #[serde(rename = "publicReleaseDate")]
     pub release_date: Option<String>,
+    #[serde(default)]
+    pub doi: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OsdrSyncSource {
+    Scheduler,
+    Manual,
+}
+
+impl OsdrSyncSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OsdrSyncSource::Scheduler => "scheduler",
+            OsdrSyncSource::Manual => "manual",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OsdrSyncStats {
+    pub inserted: i32,
+    pub updated: i32,
+    pub unchanged: i32,
+    pub failed: i32,
+    pub withdrawn: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct OsdrSyncRun {
+    pub id: i64,
+    pub source: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub inserted: i32,
+    pub updated: i32,
+    pub unchanged: i32,
+    pub failed: i32,
+    pub withdrawn: i32,
+    pub error: Option<String>,
+}
+
+// ===========================
+// OSDR Watchlists
+// ===========================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifyChannel {
+    Webhook,
+    Email,
+}
+
+impl NotifyChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotifyChannel::Webhook => "webhook",
+            NotifyChannel::Email => "email",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "webhook" => Some(NotifyChannel::Webhook),
+            "email" => Some(NotifyChannel::Email),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OsdrWatchlist {
+    pub id: i64,
+    pub name: String,
+    pub keywords: Vec<String>,
+    pub organisms: Vec<String>,
+    pub channel: NotifyChannel,
+    pub target: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct CreateWatchlistRequest {
+    #[validate(length(min = 1, max = 200))]
+    pub name: String,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub organisms: Vec<String>,
+    pub channel: NotifyChannel,
+    #[validate(length(min = 3, max = 2000))]
+    pub target: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct WatchNotification {
+    pub id: i64,
+    pub watchlist_id: i64,
+    pub dataset_id: String,
+    pub title: String,
+    pub change: String,
+    pub matched_terms: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub attempts: i32,
+    pub delivery_error: Option<String>,
 }
 
 // ===========================
@@ -75,6 +270,719 @@ pub struct ApodEntry {
     pub fetched_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApodApiEntry {
+    pub date: chrono::NaiveDate,
+    pub title: String,
+    #[serde(default)]
+    pub explanation: String,
+    // У записей с media_type "other" url может отсутствовать
+    #[serde(default)]
+    pub url: String,
+    pub hdurl: Option<String>,
+    #[serde(default)]
+    pub media_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApodRangeQuery {
+    pub start: Option<String>,
+    pub end: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ApodBackfillReport {
+    pub start: chrono::NaiveDate,
+    pub end: chrono::NaiveDate,
+    pub missing_before: usize,
+    pub stored: usize,
+    pub still_missing: Vec<chrono::NaiveDate>,
+    /// Ошибка NASA API, на которой backfill остановился
+    pub error: Option<String>,
+}
+
+// ===========================
+// NEO Models
+// ===========================
+
+/// Сближение объекта с телом Солнечной системы
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CloseApproach {
+    pub approach_date: chrono::NaiveDate,
+    pub approach_at: DateTime<Utc>,
+    pub relative_velocity_kps: f64,
+    pub miss_distance_km: f64,
+    pub miss_distance_lunar: f64,
+    pub miss_distance_au: f64,
+    pub orbiting_body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeoObject {
+    pub id: String,
+    pub name: String,
+    pub nasa_jpl_url: Option<String>,
+    pub absolute_magnitude_h: Option<f64>,
+    pub diameter_min_km: Option<f64>,
+    pub diameter_max_km: Option<f64>,
+    pub is_potentially_hazardous: bool,
+    pub is_sentry_object: bool,
+    pub close_approaches: Vec<CloseApproach>,
+}
+
+/// Строка neo_close_approaches: сближение вместе с краткими данными объекта
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct NeoApproach {
+    pub neo_id: String,
+    pub name: String,
+    pub is_potentially_hazardous: bool,
+    pub absolute_magnitude_h: Option<f64>,
+    pub diameter_min_km: Option<f64>,
+    pub diameter_max_km: Option<f64>,
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub approach: CloseApproach,
+}
+
+/// Кеплеровы элементы орбиты (эклиптика J2000, углы в градусах)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrbitalElements {
+    pub orbit_id: String,
+    /// Эпоха оскуляции (юлианская дата)
+    pub epoch_jd: f64,
+    pub eccentricity: f64,
+    pub semi_major_axis_au: f64,
+    pub inclination_deg: f64,
+    pub ascending_node_deg: f64,
+    pub perihelion_argument_deg: f64,
+    pub mean_anomaly_deg: f64,
+    pub mean_motion_deg_per_day: f64,
+    pub orbital_period_days: f64,
+    pub perihelion_distance_au: f64,
+    pub aphelion_distance_au: f64,
+    pub orbit_class: Option<String>,
+}
+
+/// Гелиоцентрическое положение в эклиптических координатах J2000
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeliocentricPosition {
+    pub date: chrono::NaiveDate,
+    pub x_au: f64,
+    pub y_au: f64,
+    pub z_au: f64,
+    pub distance_au: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeoDetails {
+    #[serde(flatten)]
+    pub object: NeoObject,
+    pub diameter_min_m: Option<f64>,
+    pub diameter_max_m: Option<f64>,
+    pub orbital_elements: OrbitalElements,
+    pub positions: Vec<HeliocentricPosition>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NeoLookupQuery {
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub step_days: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NeoSort {
+    #[default]
+    Date,
+    MissDistance,
+    Velocity,
+    Size,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct NeoFeedQuery {
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub hazardous: Option<bool>,
+    #[validate(range(min = 0.0))]
+    pub max_miss_km: Option<f64>,
+    #[serde(default)]
+    pub sort: NeoSort,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NeoApiFeed {
+    pub near_earth_objects: std::collections::HashMap<String, Vec<NeoApiObject>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NeoApiObject {
+    pub id: String,
+    pub name: String,
+    pub nasa_jpl_url: Option<String>,
+    pub absolute_magnitude_h: Option<f64>,
+    pub estimated_diameter: Option<NeoApiDiameter>,
+    pub is_potentially_hazardous_asteroid: bool,
+    #[serde(default)]
+    pub is_sentry_object: bool,
+    #[serde(default)]
+    pub close_approach_data: Vec<NeoApiApproach>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NeoApiLookup {
+    #[serde(flatten)]
+    pub object: NeoApiObject,
+    pub orbital_data: NeoApiOrbitalData,
+}
+
+/// Орбитальные элементы NeoWs /neo/{id}, числа приходят строками
+#[derive(Debug, Deserialize)]
+pub struct NeoApiOrbitalData {
+    pub orbit_id: String,
+    pub epoch_osculation: String,
+    pub eccentricity: String,
+    pub semi_major_axis: String,
+    pub inclination: String,
+    pub ascending_node_longitude: String,
+    pub perihelion_argument: String,
+    pub mean_anomaly: String,
+    pub mean_motion: String,
+    pub orbital_period: String,
+    pub perihelion_distance: String,
+    pub aphelion_distance: String,
+    pub orbit_class: Option<NeoApiOrbitClass>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NeoApiOrbitClass {
+    pub orbit_class_type: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NeoApiDiameter {
+    pub kilometers: NeoApiDiameterRange,
+    pub meters: Option<NeoApiDiameterRange>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NeoApiDiameterRange {
+    pub estimated_diameter_min: f64,
+    pub estimated_diameter_max: f64,
+}
+
+/// В NeoWs скорости и расстояния приходят строками
+#[derive(Debug, Deserialize)]
+pub struct NeoApiApproach {
+    pub close_approach_date: chrono::NaiveDate,
+    pub epoch_date_close_approach: i64,
+    pub relative_velocity: NeoApiVelocity,
+    pub miss_distance: NeoApiMissDistance,
+    pub orbiting_body: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NeoApiVelocity {
+    pub kilometers_per_second: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NeoApiMissDistance {
+    pub astronomical: String,
+    pub lunar: String,
+    pub kilometers: String,
+}
+
+// ===========================
+// DONKI Models
+// ===========================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DonkiEventType {
+    Flr,
+    Cme,
+    Gst,
+    Sep,
+    Ips,
+    Hss,
+    Rbe,
+    Notification,
+}
+
+impl DonkiEventType {
+    pub const ALL: [DonkiEventType; 8] = [
+        DonkiEventType::Flr,
+        DonkiEventType::Cme,
+        DonkiEventType::Gst,
+        DonkiEventType::Sep,
+        DonkiEventType::Ips,
+        DonkiEventType::Hss,
+        DonkiEventType::Rbe,
+        DonkiEventType::Notification,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DonkiEventType::Flr => "FLR",
+            DonkiEventType::Cme => "CME",
+            DonkiEventType::Gst => "GST",
+            DonkiEventType::Sep => "SEP",
+            DonkiEventType::Ips => "IPS",
+            DonkiEventType::Hss => "HSS",
+            DonkiEventType::Rbe => "RBE",
+            DonkiEventType::Notification => "NOTIFICATION",
+        }
+    }
+
+    /// Путь эндпоинта DONKI API
+    pub fn api_path(&self) -> &'static str {
+        match self {
+            DonkiEventType::Notification => "notifications",
+            other => other.as_str(),
+        }
+    }
+
+    /// Поле с идентификатором события в ответе DONKI
+    pub fn id_field(&self) -> &'static str {
+        match self {
+            DonkiEventType::Flr => "flrID",
+            DonkiEventType::Cme | DonkiEventType::Ips => "activityID",
+            DonkiEventType::Gst => "gstID",
+            DonkiEventType::Sep => "sepID",
+            DonkiEventType::Hss => "hssID",
+            DonkiEventType::Rbe => "rbeID",
+            DonkiEventType::Notification => "messageID",
+        }
+    }
+
+    /// Поле с моментом начала события в ответе DONKI
+    pub fn time_field(&self) -> &'static str {
+        match self {
+            DonkiEventType::Flr => "beginTime",
+            DonkiEventType::Cme | DonkiEventType::Gst => "startTime",
+            DonkiEventType::Sep | DonkiEventType::Ips | DonkiEventType::Hss | DonkiEventType::Rbe => "eventTime",
+            DonkiEventType::Notification => "messageIssueTime",
+        }
+    }
+}
+
+/// Событие DONKI в donki_events: общие поля и исходный JSON
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct DonkiEvent {
+    pub activity_id: String,
+    pub event_type: String,
+    pub start_time: DateTime<Utc>,
+    pub linked_ids: Vec<String>,
+    pub payload: serde_json::Value,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// Связная цепочка событий DONKI (компонента графа linkedEvents)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DonkiChain {
+    /// ID самого раннего события цепочки
+    pub chain_id: String,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    /// Типы событий в порядке первого появления
+    pub event_types: Vec<String>,
+    pub timeline: Vec<DonkiChainEvent>,
+    /// Связи на события, которых нет в базе
+    pub missing_links: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DonkiChainEvent {
+    pub activity_id: String,
+    pub event_type: String,
+    pub start_time: DateTime<Utc>,
+    pub linked_ids: Vec<String>,
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DonkiDateQuery {
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DonkiRangeQuery {
+    pub start: Option<String>,
+    pub end: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpaceWeatherLevel {
+    Quiet,
+    Active,
+    Storm,
+}
+
+/// Дневной индекс космической погоды по сохранённым событиям DONKI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpaceWeatherDay {
+    pub date: chrono::NaiveDate,
+    pub score: f64,
+    pub level: SpaceWeatherLevel,
+    pub flares_x: u32,
+    pub flares_m: u32,
+    pub flares_c: u32,
+    pub cme_count: u32,
+    pub max_cme_speed_kps: Option<f64>,
+    pub max_kp: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpaceWeatherIndex {
+    /// Сегодняшний день (UTC)
+    pub current: SpaceWeatherDay,
+    pub days: Vec<SpaceWeatherDay>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkedEvent {
+    #[serde(rename = "activityID")]
+    pub activity_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonkiInstrument {
+    pub display_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KpIndex {
+    pub observed_time: String,
+    pub kp_index: f64,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeomagneticStorm {
+    #[serde(rename = "gstID")]
+    pub gst_id: String,
+    pub start_time: String,
+    #[serde(default)]
+    pub all_kp_index: Vec<KpIndex>,
+    pub linked_events: Option<Vec<LinkedEvent>>,
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolarEnergeticParticle {
+    #[serde(rename = "sepID")]
+    pub sep_id: String,
+    pub event_time: String,
+    #[serde(default)]
+    pub instruments: Vec<DonkiInstrument>,
+    pub linked_events: Option<Vec<LinkedEvent>>,
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterplanetaryShock {
+    #[serde(rename = "activityID")]
+    pub activity_id: String,
+    pub catalog: Option<String>,
+    pub location: Option<String>,
+    pub event_time: String,
+    #[serde(default)]
+    pub instruments: Vec<DonkiInstrument>,
+    pub linked_events: Option<Vec<LinkedEvent>>,
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HighSpeedStream {
+    #[serde(rename = "hssID")]
+    pub hss_id: String,
+    pub event_time: String,
+    #[serde(default)]
+    pub instruments: Vec<DonkiInstrument>,
+    pub linked_events: Option<Vec<LinkedEvent>>,
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RadiationBeltEnhancement {
+    #[serde(rename = "rbeID")]
+    pub rbe_id: String,
+    pub event_time: String,
+    #[serde(default)]
+    pub instruments: Vec<DonkiInstrument>,
+    pub linked_events: Option<Vec<LinkedEvent>>,
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonkiNotification {
+    #[serde(rename = "messageID")]
+    pub message_id: String,
+    pub message_type: String,
+    #[serde(rename = "messageURL")]
+    pub message_url: Option<String>,
+    pub message_issue_time: String,
+    pub message_body: String,
+}
+
+// ===========================
+// EPIC Models
+// ===========================
+
+/// Коллекция снимков EPIC (DSCOVR)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EpicCollection {
+    Natural,
+    Enhanced,
+}
+
+impl EpicCollection {
+    pub const ALL: [EpicCollection; 2] = [EpicCollection::Natural, EpicCollection::Enhanced];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EpicCollection::Natural => "natural",
+            EpicCollection::Enhanced => "enhanced",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GeoPoint {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+/// Положение в системе J2000, км от центра Земли
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct J2000Position {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpicImage {
+    pub identifier: String,
+    pub collection: EpicCollection,
+    pub caption: String,
+    pub image: String,
+    pub version: Option<String>,
+    pub taken_at: DateTime<Utc>,
+    /// Точка Земли в центре снимка
+    pub centroid: GeoPoint,
+    pub dscovr_position: Option<J2000Position>,
+    pub lunar_position: Option<J2000Position>,
+    pub sun_position: Option<J2000Position>,
+    pub image_url: String,
+    pub thumbnail_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EpicImagesQuery {
+    pub date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EpicNearestQuery {
+    /// RFC 3339; по умолчанию - сейчас
+    pub at: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct EpicApiImage {
+    pub identifier: String,
+    #[serde(default)]
+    pub caption: String,
+    pub image: String,
+    pub version: Option<String>,
+    /// "YYYY-MM-DD HH:MM:SS", UTC
+    pub date: String,
+    pub centroid_coordinates: GeoPoint,
+    pub dscovr_j2000_position: Option<J2000Position>,
+    pub lunar_j2000_position: Option<J2000Position>,
+    pub sun_j2000_position: Option<J2000Position>,
+}
+
+// ===========================
+// EONET Models
+// ===========================
+
+/// Категории EONET, которые мы собираем
+pub const EONET_CATEGORIES: [&str; 3] = ["wildfires", "severeStorms", "volcanoes"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EonetEvent {
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub category: String,
+    pub link: Option<String>,
+    pub closed_at: Option<DateTime<Utc>>,
+    /// URL первоисточников (InciWeb, JTWC, SIVolcano и т.п.)
+    pub sources: Vec<String>,
+    /// Наблюдения по времени; для полигонов - центр
+    pub geometry: Vec<EonetGeometry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EonetGeometry {
+    pub observed_at: DateTime<Utc>,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub magnitude_value: Option<f64>,
+    pub magnitude_unit: Option<String>,
+}
+
+/// Пролёт МКС над природным событием: ближайшая точка трассы
+#[derive(Debug, Clone, Serialize)]
+pub struct EventOverflight {
+    pub event_id: String,
+    pub title: String,
+    pub category: String,
+    pub link: Option<String>,
+    pub overflown_at: DateTime<Utc>,
+    pub distance_km: f64,
+    pub iss_latitude: f64,
+    pub iss_longitude: f64,
+    pub event_latitude: f64,
+    pub event_longitude: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EonetStatus {
+    #[default]
+    Open,
+    Closed,
+    All,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct EonetEventsQuery {
+    pub category: Option<String>,
+    #[serde(default)]
+    pub status: EonetStatus,
+    #[validate(range(min = 1, max = 1000))]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct OverflownEventsQuery {
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    #[validate(range(min = 1.0, max = 2500.0))]
+    pub radius_km: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EonetApiResponse {
+    #[serde(default)]
+    pub events: Vec<EonetApiEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EonetApiEvent {
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub link: Option<String>,
+    pub closed: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub categories: Vec<EonetApiCategory>,
+    #[serde(default)]
+    pub sources: Vec<EonetApiSource>,
+    #[serde(default)]
+    pub geometry: Vec<EonetApiGeometry>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EonetApiCategory {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EonetApiSource {
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EonetApiGeometry {
+    pub magnitude_value: Option<f64>,
+    pub magnitude_unit: Option<String>,
+    pub date: DateTime<Utc>,
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Point: [lon, lat]; Polygon: [[[lon, lat], ...]]
+    pub coordinates: serde_json::Value,
+}
+
+// ===========================
+// Media Store
+// ===========================
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MediaObject {
+    /// SHA-256 оригинала (hex)
+    pub hash: String,
+    pub content_type: String,
+    pub width: i32,
+    pub height: i32,
+    /// Суммарный размер на диске: оригинал и все варианты
+    pub bytes: i64,
+    pub created_at: DateTime<Utc>,
+    pub last_accessed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MediaVariant {
+    #[default]
+    Original,
+    Thumb,
+    Webp,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MediaQuery {
+    #[serde(default)]
+    pub variant: MediaVariant,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MediaLookupQuery {
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MediaMirrorReport {
+    pub mirrored: usize,
+    pub deduplicated: usize,
+    pub failed: usize,
+    pub evicted: usize,
+    /// Объекты с пропавшими файлами, поставленные на повторное скачивание
+    pub repaired: usize,
+}
+
 // ===========================
 // Cache Model
 // ===========================
@@ -86,19 +994,549 @@ pub struct CachedData<T> {
 }
 
 // ===========================
-// JWST Model (for tests)
+// JWST Models
 // ===========================
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Файл JWST из каталога jwst_images (один на observation_id)
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct JwstImage {
     pub id: Option<i64>,
     pub observation_id: String,
+    pub program: String,
+    /// Имя файла в jwstapi.com
     pub title: String,
     pub description: Option<String>,
+    /// В ответе - location/thumbnail, как у jwstapi.com (их читает php-web)
+    #[serde(rename = "location")]
     pub image_url: String,
-    pub observation_date: Option<NaiveDateTime>,
+    #[serde(rename = "thumbnail")]
+    pub thumbnail_url: Option<String>,
+    pub file_type: String,
+    /// Уровень обработки без подчёркивания: i2d, cal, rate...
+    pub suffix: Option<String>,
     pub instrument: Option<String>,
+    pub filters: Vec<String>,
+    pub observation_date: Option<NaiveDateTime>,
     pub updated_at: NaiveDateTime,
+    /// Когда файл впервые попал в каталог
+    pub first_seen_at: NaiveDateTime,
+    /// Ключевые поля FITS-заголовка продукта (после /jwst/products/:file/header)
+    pub fits: Option<sqlx::types::Json<FitsSummary>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct JwstImagesQuery {
+    pub program: Option<String>,
+    pub instrument: Option<String>,
+    pub filter: Option<String>,
+    pub suffix: Option<String>,
+    #[validate(range(min = 1, max = 1000))]
+    pub limit: Option<i64>,
+}
+
+/// Параметры /jwst/new (без since - с начала последнего планового обновления)
+#[derive(Debug, Deserialize, Validate)]
+pub struct JwstNewQuery {
+    pub since: Option<DateTime<Utc>>,
+    #[validate(range(min = 1, max = 1000))]
+    pub limit: Option<i64>,
+}
+
+/// Новые наблюдения отслеживаемых программ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JwstNewObservations {
+    pub since: DateTime<Utc>,
+    pub programs: Vec<String>,
+    pub last_refresh_at: Option<DateTime<Utc>>,
+    pub images: Vec<JwstImage>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JwstApiImage {
+    /// Имя файла
+    pub id: String,
+    pub observation_id: String,
+    #[serde(default)]
+    pub program: serde_json::Value,
+    pub details: Option<JwstApiDetails>,
+    pub suffix: Option<String>,
+    pub file_type: String,
+    pub thumbnail: Option<String>,
+    pub location: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JwstApiDetails {
+    pub description: Option<String>,
+    pub suffix: Option<String>,
+    /// Строки или объекты {"instrument": "NIRCAM"}
+    #[serde(default)]
+    pub instruments: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub filters: Vec<String>,
+}
+
+// ===========================
+// FITS Models
+// ===========================
+
+/// Карточка заголовка FITS; value - строка, число, логическое или null (значение не задано)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FitsCard {
+    pub keyword: String,
+    pub value: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+/// Заголовок одного HDU (первичного или расширения)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FitsHdu {
+    pub index: usize,
+    /// EXTNAME, у первичного HDU - PRIMARY
+    pub name: String,
+    /// IMAGE, BINTABLE... (None у первичного HDU)
+    pub xtension: Option<String>,
+    pub bitpix: i64,
+    pub naxis: Vec<i64>,
+    /// Смещения в файле: начало заголовка и начало данных
+    pub header_offset: u64,
+    pub data_offset: u64,
+    pub data_bytes: u64,
+    pub cards: Vec<FitsCard>,
+}
+
+/// Мировые координаты (WCS) изображения
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FitsWcs {
+    pub ctype: Vec<String>,
+    pub crval: Vec<f64>,
+    pub crpix: Vec<f64>,
+    /// Матрица CD (или PC, умноженная на CDELT)
+    pub cd: Vec<Vec<f64>>,
+    pub radesys: Option<String>,
+    /// Масштаб пикселя в угловых секундах (для осей в градусах)
+    pub pixel_scale_arcsec: Option<f64>,
+}
+
+/// Ключевые поля заголовка, собранные по всем HDU
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FitsSummary {
+    pub instrument: Option<String>,
+    pub detector: Option<String>,
+    pub filter: Option<String>,
+    pub pupil: Option<String>,
+    /// Эффективная экспозиция, секунды
+    pub exposure_time: Option<f64>,
+    pub target: Option<String>,
+    pub program: Option<String>,
+    pub date_obs: Option<String>,
+    /// Размер изображения с WCS
+    pub image_size: Option<Vec<i64>>,
+    pub wcs: Option<FitsWcs>,
+}
+
+/// Результат разбора заголовков FITS
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FitsHeader {
+    pub file: String,
+    pub source_url: Option<String>,
+    pub hdus: Vec<FitsHdu>,
+    pub summary: FitsSummary,
+    /// Разобраны не все HDU: данные закончились раньше (или достигнут лимит)
+    pub truncated: bool,
+}
+
+// ===========================
+// SpaceX Models
+// ===========================
+
+/// Запуск SpaceX из spacex_launches; rocket_name/launchpad_name заполняются при чтении из БД
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SpaceXLaunch {
+    pub id: String,
+    pub name: String,
+    pub flight_number: i32,
+    pub date_utc: DateTime<Utc>,
+    /// half, quarter, year, month, day, hour
+    pub date_precision: String,
+    pub upcoming: bool,
+    pub success: Option<bool>,
+    pub tbd: bool,
+    pub net: bool,
+    pub rocket_id: Option<String>,
+    pub rocket_name: Option<String>,
+    pub launchpad_id: Option<String>,
+    pub launchpad_name: Option<String>,
+    pub payload_ids: Vec<String>,
+    pub details: Option<String>,
+    pub patch_url: Option<String>,
+    pub webcast_url: Option<String>,
+    pub failure_reasons: Vec<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SpaceXRocket {
+    pub id: String,
+    pub name: String,
+    pub rocket_type: Option<String>,
+    pub active: bool,
+    pub stages: Option<i32>,
+    pub cost_per_launch: Option<i64>,
+    pub success_rate_pct: Option<i32>,
+    pub first_flight: Option<chrono::NaiveDate>,
+    pub country: Option<String>,
+    pub company: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SpaceXLaunchpad {
+    pub id: String,
+    pub name: String,
+    pub full_name: Option<String>,
+    pub locality: Option<String>,
+    pub region: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub status: Option<String>,
+    pub timezone: Option<String>,
+    pub launch_attempts: i32,
+    pub launch_successes: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SpaceXPayload {
+    pub id: String,
+    pub name: Option<String>,
+    pub payload_type: Option<String>,
+    pub launch_id: Option<String>,
+    pub customers: Vec<String>,
+    pub mass_kg: Option<f64>,
+    pub orbit: Option<String>,
+    pub regime: Option<String>,
+    pub reused: bool,
+}
+
+/// Запуск со связанными ракетой, площадкой и полезными нагрузками
+#[derive(Debug, Clone, Serialize)]
+pub struct SpaceXLaunchDetails {
+    #[serde(flatten)]
+    pub launch: SpaceXLaunch,
+    pub rocket: Option<SpaceXRocket>,
+    pub launchpad: Option<SpaceXLaunchpad>,
+    pub payloads: Vec<SpaceXPayload>,
+}
+
+/// Итог синхронизации архива SpaceX
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SpaceXSyncReport {
+    pub rockets: usize,
+    pub launchpads: usize,
+    pub launches: usize,
+    pub payloads: usize,
+    /// Документы, которые не удалось разобрать
+    pub skipped: usize,
+    /// Записи, добавленные в launch_schedule_history
+    pub schedule_changes: usize,
+}
+
+/// Тип записи истории расписания: перенос даты или смена статуса запуска
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LaunchChangeKind {
+    /// Изменились date_utc, date_precision, tbd или net предстоящего запуска
+    Slip,
+    /// upcoming -> success/failure
+    Status,
+}
+
+impl LaunchChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LaunchChangeKind::Slip => "slip",
+            LaunchChangeKind::Status => "status",
+        }
+    }
+}
+
+/// Запись launch_schedule_history: состояние расписания до и после изменения
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LaunchScheduleChange {
+    pub launch_id: String,
+    pub launch_name: String,
+    pub kind: String,
+    pub previous_date_utc: DateTime<Utc>,
+    pub date_utc: DateTime<Utc>,
+    /// Насколько сдвинулся запуск (положительное - на более позднее время)
+    pub slip_seconds: i64,
+    pub previous_precision: String,
+    pub date_precision: String,
+    pub previous_tbd: bool,
+    pub tbd: bool,
+    pub previous_net: bool,
+    pub net: bool,
+    /// upcoming, success, failure или unknown (итог ещё не опубликован)
+    pub previous_status: String,
+    pub status: String,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// Параметры /calendar/launches.ics
+#[derive(Debug, Deserialize, Validate)]
+pub struct LaunchCalendarQuery {
+    /// За сколько минут до запуска напомнить
+    #[validate(range(min = 0, max = 1440))]
+    pub alarm_minutes: Option<i64>,
+}
+
+/// Параметры /spacex/changes (без since - за последние 7 дней)
+#[derive(Debug, Deserialize, Validate)]
+pub struct LaunchChangesQuery {
+    pub since: Option<DateTime<Utc>>,
+    pub kind: Option<LaunchChangeKind>,
+    #[validate(range(min = 1, max = 500))]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SpaceXLaunchesQuery {
+    pub upcoming: Option<bool>,
+    /// ID или название ракеты (Falcon 9)
+    pub rocket: Option<String>,
+    pub success: Option<bool>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+    #[validate(range(min = 1, max = 500))]
+    pub limit: Option<i64>,
+}
+
+/// Страница ответа POST /v4/{collection}/query
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpaceXQueryPage {
+    #[serde(default)]
+    pub docs: Vec<serde_json::Value>,
+    pub next_page: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpaceXApiLaunch {
+    pub id: String,
+    pub name: String,
+    pub flight_number: i32,
+    pub date_utc: DateTime<Utc>,
+    pub date_precision: String,
+    pub upcoming: bool,
+    pub success: Option<bool>,
+    #[serde(default)]
+    pub tbd: Option<bool>,
+    #[serde(default)]
+    pub net: Option<bool>,
+    pub rocket: Option<String>,
+    pub launchpad: Option<String>,
+    #[serde(default)]
+    pub payloads: Vec<String>,
+    pub details: Option<String>,
+    #[serde(default)]
+    pub links: Option<SpaceXApiLinks>,
+    #[serde(default)]
+    pub failures: Vec<SpaceXApiFailure>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SpaceXApiLinks {
+    pub patch: Option<SpaceXApiPatch>,
+    pub webcast: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpaceXApiPatch {
+    pub small: Option<String>,
+    pub large: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpaceXApiFailure {
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpaceXApiRocket {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub rocket_type: Option<String>,
+    #[serde(default)]
+    pub active: bool,
+    pub stages: Option<i32>,
+    pub cost_per_launch: Option<i64>,
+    pub success_rate_pct: Option<i32>,
+    pub first_flight: Option<String>,
+    pub country: Option<String>,
+    pub company: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpaceXApiLaunchpad {
+    pub id: String,
+    pub name: String,
+    pub full_name: Option<String>,
+    pub locality: Option<String>,
+    pub region: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub status: Option<String>,
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub launch_attempts: i32,
+    #[serde(default)]
+    pub launch_successes: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpaceXApiPayload {
+    pub id: String,
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub payload_type: Option<String>,
+    pub launch: Option<String>,
+    #[serde(default)]
+    pub customers: Vec<String>,
+    pub mass_kg: Option<f64>,
+    pub orbit: Option<String>,
+    pub regime: Option<String>,
+    #[serde(default)]
+    pub reused: bool,
+}
+
+// ===========================
+// Astronomy Models
+// ===========================
+
+/// Тела, для которых AstronomyAPI отдаёт события (затмения)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AstroBody {
+    Sun,
+    Moon,
+}
+
+impl AstroBody {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AstroBody::Sun => "sun",
+            AstroBody::Moon => "moon",
+        }
+    }
+}
+
+/// Наблюдатель и диапазон дат запроса к AstronomyAPI
+#[derive(Debug, Clone, PartialEq)]
+pub struct AstroObserver {
+    pub lat: f64,
+    pub lon: f64,
+    /// Высота над уровнем моря (м)
+    pub elevation: f64,
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
+    pub time: chrono::NaiveTime,
+}
+
+/// Параметры /astro/events (без дат - 30 дней с сегодняшнего)
+#[derive(Debug, Deserialize, Validate)]
+pub struct AstroEventsQuery {
+    pub body: AstroBody,
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: f64,
+    #[validate(range(min = -500.0, max = 9000.0))]
+    pub elevation: Option<f64>,
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+}
+
+/// Параметры /astro/positions (без дат - сегодня и 3 дня вперёд, время - начало текущего часа UTC)
+#[derive(Debug, Deserialize, Validate)]
+pub struct AstroPositionsQuery {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: f64,
+    #[validate(range(min = -500.0, max = 9000.0))]
+    pub elevation: Option<f64>,
+    pub from: Option<chrono::NaiveDate>,
+    pub to: Option<chrono::NaiveDate>,
+    pub time: Option<chrono::NaiveTime>,
+}
+
+/// Утренние и вечерние сумерки (None - Солнце не пересекает порог в эти сутки)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Twilight {
+    pub dawn: Option<DateTime<Utc>>,
+    pub dusk: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SunEphemeris {
+    pub sunrise: Option<DateTime<Utc>>,
+    pub sunset: Option<DateTime<Utc>>,
+    pub solar_noon: Option<DateTime<Utc>>,
+    /// Время над горизонтом за сутки (86400 - полярный день, 0 - полярная ночь)
+    pub day_length_seconds: i64,
+    pub civil_twilight: Twilight,
+    pub nautical_twilight: Twilight,
+    pub astronomical_twilight: Twilight,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MoonPhase {
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MoonEphemeris {
+    pub moonrise: Option<DateTime<Utc>>,
+    pub moonset: Option<DateTime<Utc>>,
+    /// Фаза на середину суток
+    pub phase: MoonPhase,
+    /// Освещённая доля диска (0..1)
+    pub illumination: f64,
+    /// Дней после новолуния
+    pub age_days: f64,
+}
+
+/// Эфемериды Солнца и Луны на сутки; время в UTC
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkyReport {
+    pub date: chrono::NaiveDate,
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Начало суток по местному среднему солнечному времени (долгота / 15°)
+    pub day_start: DateTime<Utc>,
+    pub sun: SunEphemeris,
+    pub moon: MoonEphemeris,
+}
+
+/// Параметры /astro/sky (без date - текущая дата UTC)
+#[derive(Debug, Deserialize, Validate)]
+pub struct SkyQuery {
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: f64,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: f64,
+    pub date: Option<chrono::NaiveDate>,
 }
 
 #[cfg(test)]
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use super::super::*;
-    use chrono::{NaiveDateTime, Utc};
+    use chrono::{DateTime, NaiveDateTime, Utc};
     use validator::Validate;
 
     #[test]
@@ -110,6 +110,8 @@ mod tests {
             description: Some("Gene expression analysis".to_string()),
             release_date: Some(NaiveDateTime::from_timestamp_opt(1638360000, 0).unwrap()),
             updated_at: NaiveDateTime::from_timestamp_opt(1638360000, 0).unwrap(),
+            doi: None,
+            withdrawn_at: None,
         };
 
         assert_eq!(dataset.dataset_id, "OSD-123");
@@ -134,6 +136,17 @@ mod tests {
         
         let osdr_response = response.unwrap();
         assert_eq!(osdr_response.results.len(), 1);
+        // Флаг ставит только клиент после успешного ответа OSDR
+        assert!(!osdr_response.from_upstream);
+    }
+
+    #[test]
+    fn test_osdr_sync_source_serialization() {
+        assert_eq!(OsdrSyncSource::Scheduler.as_str(), "scheduler");
+        assert_eq!(OsdrSyncSource::Manual.as_str(), "manual");
+
+        let json = serde_json::to_string(&OsdrSyncSource::Manual).unwrap();
+        assert_eq!(json, "\"manual\"");
     }
 
     #[test]
@@ -141,16 +154,26 @@ mod tests {
         let image = JwstImage {
             id: Some(1),
             observation_id: "jw02731-001".to_string(),
+            program: "2731".to_string(),
             title: "JWST Deep Field".to_string(),
             description: Some("Deep space observation".to_string()),
             image_url: "https://example.com/image.png".to_string(),
-            observation_date: Some(NaiveDateTime::from_timestamp_opt(1638360000, 0).unwrap()),
+            thumbnail_url: None,
+            file_type: "png".to_string(),
+            suffix: Some("i2d".to_string()),
             instrument: Some("NIRCam".to_string()),
+            filters: vec!["F200W".to_string()],
+            observation_date: Some(NaiveDateTime::from_timestamp_opt(1638360000, 0).unwrap()),
             updated_at: NaiveDateTime::from_timestamp_opt(1638360000, 0).unwrap(),
+            first_seen_at: DateTime::from_timestamp(1638360000, 0).unwrap().naive_utc(),
+            fits: None,
         };
 
         assert_eq!(image.observation_id, "jw02731-001");
         assert_eq!(image.instrument, Some("NIRCam".to_string()));
+
+        let json = serde_json::to_value(&image).unwrap();
+        assert_eq!(json["location"], "https://example.com/image.png");
     }
 
     #[test]
@@ -0,0 +1,110 @@
+use crate::domain::models::{
+    CloseApproach, NeoApiApproach, NeoApiFeed, NeoApiLookup, NeoApiObject, NeoApiOrbitalData, NeoDetails,
+    NeoObject, OrbitalElements,
+};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// Разобрать ответ NeoWs /feed в список объектов (один объект может встречаться в нескольких днях)
+pub fn parse_feed(feed: Value) -> Result<Vec<NeoObject>, String> {
+    let feed: NeoApiFeed =
+        serde_json::from_value(feed).map_err(|e| format!("Unexpected NEO feed format: {}", e))?;
+
+    let mut days: Vec<_> = feed.near_earth_objects.into_iter().collect();
+    days.sort_by(|a, b| a.0.cmp(&b.0));
+
+    days.into_iter()
+        .flat_map(|(_, objects)| objects)
+        .map(to_neo_object)
+        .collect()
+}
+
+/// Разобрать ответ NeoWs /neo/{id}; положения на орбите заполняются отдельно
+pub fn parse_lookup(lookup: Value, fetched_at: DateTime<Utc>) -> Result<NeoDetails, String> {
+    let lookup: NeoApiLookup =
+        serde_json::from_value(lookup).map_err(|e| format!("Unexpected NEO lookup format: {}", e))?;
+
+    let meters = lookup
+        .object
+        .estimated_diameter
+        .as_ref()
+        .and_then(|d| d.meters.as_ref())
+        .map(|m| (m.estimated_diameter_min, m.estimated_diameter_max));
+    let orbital_elements = to_orbital_elements(lookup.orbital_data)
+        .map_err(|e| format!("NEO {}: {}", lookup.object.id, e))?;
+
+    Ok(NeoDetails {
+        object: to_neo_object(lookup.object)?,
+        diameter_min_m: meters.map(|m| m.0),
+        diameter_max_m: meters.map(|m| m.1),
+        orbital_elements,
+        positions: Vec::new(),
+        fetched_at,
+    })
+}
+
+pub fn to_neo_object(object: NeoApiObject) -> Result<NeoObject, String> {
+    let close_approaches = object
+        .close_approach_data
+        .into_iter()
+        .map(to_close_approach)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("NEO {}: {}", object.id, e))?;
+
+    let diameter = object.estimated_diameter.map(|d| d.kilometers);
+
+    Ok(NeoObject {
+        id: object.id,
+        name: object.name,
+        nasa_jpl_url: object.nasa_jpl_url,
+        absolute_magnitude_h: object.absolute_magnitude_h,
+        diameter_min_km: diameter.as_ref().map(|d| d.estimated_diameter_min),
+        diameter_max_km: diameter.as_ref().map(|d| d.estimated_diameter_max),
+        is_potentially_hazardous: object.is_potentially_hazardous_asteroid,
+        is_sentry_object: object.is_sentry_object,
+        close_approaches,
+    })
+}
+
+fn to_close_approach(approach: NeoApiApproach) -> Result<CloseApproach, String> {
+    let approach_at = DateTime::from_timestamp_millis(approach.epoch_date_close_approach)
+        .ok_or_else(|| format!("invalid approach epoch {}", approach.epoch_date_close_approach))?;
+
+    Ok(CloseApproach {
+        approach_date: approach.close_approach_date,
+        approach_at,
+        relative_velocity_kps: parse_number("relative_velocity", &approach.relative_velocity.kilometers_per_second)?,
+        miss_distance_km: parse_number("miss_distance", &approach.miss_distance.kilometers)?,
+        miss_distance_lunar: parse_number("miss_distance", &approach.miss_distance.lunar)?,
+        miss_distance_au: parse_number("miss_distance", &approach.miss_distance.astronomical)?,
+        orbiting_body: approach.orbiting_body,
+    })
+}
+
+fn to_orbital_elements(data: NeoApiOrbitalData) -> Result<OrbitalElements, String> {
+    Ok(OrbitalElements {
+        epoch_jd: parse_number("epoch_osculation", &data.epoch_osculation)?,
+        eccentricity: parse_number("eccentricity", &data.eccentricity)?,
+        semi_major_axis_au: parse_number("semi_major_axis", &data.semi_major_axis)?,
+        inclination_deg: parse_number("inclination", &data.inclination)?,
+        ascending_node_deg: parse_number("ascending_node_longitude", &data.ascending_node_longitude)?,
+        perihelion_argument_deg: parse_number("perihelion_argument", &data.perihelion_argument)?,
+        mean_anomaly_deg: parse_number("mean_anomaly", &data.mean_anomaly)?,
+        mean_motion_deg_per_day: parse_number("mean_motion", &data.mean_motion)?,
+        orbital_period_days: parse_number("orbital_period", &data.orbital_period)?,
+        perihelion_distance_au: parse_number("perihelion_distance", &data.perihelion_distance)?,
+        aphelion_distance_au: parse_number("aphelion_distance", &data.aphelion_distance)?,
+        orbit_class: data.orbit_class.map(|c| c.orbit_class_type),
+        orbit_id: data.orbit_id,
+    })
+}
+
+fn parse_number(field: &str, value: &str) -> Result<f64, String> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid {} value '{}'", field, value))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use serde_json::json;
+
+    fn feed() -> Value {
+        json!({
+            "element_count": 2,
+            "near_earth_objects": {
+                "2024-01-02": [{
+                    "id": "3542519",
+                    "name": "(2010 PK9)",
+                    "nasa_jpl_url": "https://ssd.jpl.nasa.gov/tools/sbdb_lookup.html#/?sstr=3542519",
+                    "absolute_magnitude_h": 21.1,
+                    "estimated_diameter": {
+                        "kilometers": { "estimated_diameter_min": 0.1677, "estimated_diameter_max": 0.3750 }
+                    },
+                    "is_potentially_hazardous_asteroid": true,
+                    "close_approach_data": [{
+                        "close_approach_date": "2024-01-02",
+                        "close_approach_date_full": "2024-Jan-02 04:20",
+                        "epoch_date_close_approach": 1704169200000u64,
+                        "relative_velocity": { "kilometers_per_second": "17.6523", "kilometers_per_hour": "63548.3" },
+                        "miss_distance": {
+                            "astronomical": "0.2514",
+                            "lunar": "97.79",
+                            "kilometers": "37608923.37",
+                            "miles": "23368870.9"
+                        },
+                        "orbiting_body": "Earth"
+                    }],
+                    "is_sentry_object": false
+                }],
+                "2024-01-01": [{
+                    "id": "2465633",
+                    "name": "465633 (2009 JR5)",
+                    "absolute_magnitude_h": 20.44,
+                    "is_potentially_hazardous_asteroid": false,
+                    "close_approach_data": []
+                }]
+            }
+        })
+    }
+
+    #[test]
+    fn test_parse_feed_orders_by_day() {
+        let objects = parse_feed(feed()).unwrap();
+
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects[0].id, "2465633");
+        assert_eq!(objects[1].id, "3542519");
+    }
+
+    #[test]
+    fn test_parse_feed_converts_string_numbers() {
+        let objects = parse_feed(feed()).unwrap();
+        let neo = &objects[1];
+
+        assert!(neo.is_potentially_hazardous);
+        assert_eq!(neo.diameter_max_km, Some(0.3750));
+
+        let approach = &neo.close_approaches[0];
+        assert_eq!(approach.approach_date, NaiveDate::from_ymd_opt(2024, 1, 2).unwrap());
+        assert_eq!(approach.approach_at, Utc.with_ymd_and_hms(2024, 1, 2, 4, 20, 0).unwrap());
+        assert_eq!(approach.relative_velocity_kps, 17.6523);
+        assert_eq!(approach.miss_distance_km, 37608923.37);
+        assert_eq!(approach.miss_distance_lunar, 97.79);
+        assert_eq!(approach.orbiting_body, "Earth");
+    }
+
+    #[test]
+    fn test_parse_feed_without_diameter() {
+        let objects = parse_feed(feed()).unwrap();
+
+        assert!(objects[0].diameter_min_km.is_none());
+        assert!(objects[0].close_approaches.is_empty());
+    }
+
+    #[test]
+    fn test_parse_feed_rejects_bad_numbers() {
+        let mut feed = feed();
+        feed["near_earth_objects"]["2024-01-02"][0]["close_approach_data"][0]["miss_distance"]["kilometers"] =
+            json!("n/a");
+
+        let error = parse_feed(feed).unwrap_err();
+        assert!(error.contains("3542519"));
+    }
+
+    #[test]
+    fn test_parse_lookup_reads_orbital_elements() {
+        let mut lookup = feed()["near_earth_objects"]["2024-01-02"][0].clone();
+        lookup["estimated_diameter"]["meters"] =
+            json!({ "estimated_diameter_min": 167.7, "estimated_diameter_max": 375.0 });
+        lookup["orbital_data"] = json!({
+            "orbit_id": "28",
+            "epoch_osculation": "2460600.5",
+            "eccentricity": ".2226",
+            "semi_major_axis": "1.458",
+            "inclination": "10.83",
+            "ascending_node_longitude": "304.3",
+            "perihelion_argument": "178.9",
+            "mean_anomaly": "310.5",
+            "mean_motion": ".5597",
+            "orbital_period": "643.2",
+            "perihelion_distance": "1.133",
+            "aphelion_distance": "1.783",
+            "equinox": "J2000",
+            "orbit_class": { "orbit_class_type": "AMO", "orbit_class_description": "Amor" }
+        });
+
+        let details = parse_lookup(lookup, Utc::now()).unwrap();
+
+        assert_eq!(details.object.id, "3542519");
+        assert_eq!(details.diameter_max_m, Some(375.0));
+        assert_eq!(details.orbital_elements.eccentricity, 0.2226);
+        assert_eq!(details.orbital_elements.epoch_jd, 2460600.5);
+        assert_eq!(details.orbital_elements.orbit_class.as_deref(), Some("AMO"));
+        assert!(details.positions.is_empty());
+    }
+
+    #[test]
+    fn test_parse_lookup_requires_orbital_data() {
+        let lookup = feed()["near_earth_objects"]["2024-01-01"][0].clone();
+        assert!(parse_lookup(lookup, Utc::now()).is_err());
+    }
+}
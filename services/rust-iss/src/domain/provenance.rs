@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::future::Future;
+
+/// Откуда взяты данные ответа.
+/// Порядок важен: при смешанных источниках в конверт попадает наименее свежий
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataSource {
+    /// Только собственная БД
+    Database,
+    /// Внешний API в рамках этого запроса
+    Upstream,
+    /// Redis-кэш
+    Cache,
+    /// Демонстрационные данные вместо недоступного API
+    Fallback,
+}
+
+/// Метаданные происхождения данных в конверте ответа (поле meta)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Provenance {
+    pub source: DataSource,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub upstreams: Vec<String>,
+    pub fetched_at: Option<DateTime<Utc>>,
+    pub cache_age_seconds: Option<i64>,
+    pub degraded: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+impl Default for Provenance {
+    fn default() -> Self {
+        Self {
+            source: DataSource::Database,
+            upstreams: Vec::new(),
+            fetched_at: None,
+            cache_age_seconds: None,
+            degraded: false,
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl Provenance {
+    /// Данные получены от внешнего API
+    pub fn add_upstream(&mut self, upstream: &str, fetched_at: DateTime<Utc>) {
+        self.source = self.source.max(DataSource::Upstream);
+        if !self.upstreams.iter().any(|u| u == upstream) {
+            self.upstreams.push(upstream.to_string());
+        }
+        self.fetched_at = Some(oldest(self.fetched_at, fetched_at));
+    }
+
+    /// Данные взяты из кэша, сохранённые в cached_at
+    pub fn add_cache_hit(&mut self, cached_at: DateTime<Utc>, now: DateTime<Utc>) {
+        self.source = self.source.max(DataSource::Cache);
+        let age = (now - cached_at).num_seconds().max(0);
+        self.cache_age_seconds = Some(self.cache_age_seconds.map_or(age, |a| a.max(age)));
+        self.fetched_at = Some(oldest(self.fetched_at, cached_at));
+    }
+
+    /// Отдана устаревшая копия вместо свежих данных
+    pub fn add_stale(&mut self, reason: String) {
+        self.degraded = true;
+        self.warnings.push(reason);
+    }
+
+    /// Отданы демонстрационные данные
+    pub fn add_fallback(&mut self, reason: String) {
+        self.source = DataSource::Fallback;
+        self.degraded = true;
+        self.warnings.push(reason);
+    }
+}
+
+fn oldest(current: Option<DateTime<Utc>>, candidate: DateTime<Utc>) -> DateTime<Utc> {
+    current.map_or(candidate, |c| c.min(candidate))
+}
+
+tokio::task_local! {
+    static CURRENT: RefCell<Provenance>;
+}
+
+/// Выполнить обработку запроса, собирая происхождение данных
+pub async fn scope<F: Future>(future: F) -> F::Output {
+    CURRENT.scope(RefCell::new(Provenance::default()), future).await
+}
+
+/// Происхождение данных текущего запроса (None вне обработки запроса, например в планировщике)
+pub fn current() -> Option<Provenance> {
+    CURRENT.try_with(|p| p.borrow().clone()).ok()
+}
+
+fn update(f: impl FnOnce(&mut Provenance)) {
+    let _ = CURRENT.try_with(|p| f(&mut p.borrow_mut()));
+}
+
+pub fn record_upstream(upstream: &str) {
+    update(|p| p.add_upstream(upstream, Utc::now()));
+}
+
+pub fn record_cache_hit(cached_at: DateTime<Utc>) {
+    update(|p| p.add_cache_hit(cached_at, Utc::now()));
+}
+
+pub fn record_stale(reason: impl Into<String>) {
+    update(|p| p.add_stale(reason.into()));
+}
+
+pub fn record_fallback(reason: impl Into<String>) {
+    update(|p| p.add_fallback(reason.into()));
+}
+
+#[cfg(test)]
+mod tests;
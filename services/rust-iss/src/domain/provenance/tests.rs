@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn test_default_is_database_and_not_degraded() {
+        let provenance = Provenance::default();
+        assert_eq!(provenance.source, DataSource::Database);
+        assert!(!provenance.degraded);
+        assert!(provenance.fetched_at.is_none());
+    }
+
+    #[test]
+    fn test_cache_hit_outranks_upstream_and_keeps_oldest_time() {
+        let mut provenance = Provenance::default();
+        provenance.add_upstream("nasa", at(100));
+        provenance.add_cache_hit(at(40), at(100));
+        provenance.add_cache_hit(at(90), at(100));
+
+        assert_eq!(provenance.source, DataSource::Cache);
+        assert_eq!(provenance.cache_age_seconds, Some(60));
+        assert_eq!(provenance.fetched_at, Some(at(40)));
+        assert_eq!(provenance.upstreams, vec!["nasa".to_string()]);
+    }
+
+    #[test]
+    fn test_fallback_marks_degraded() {
+        let mut provenance = Provenance::default();
+        provenance.add_cache_hit(at(0), at(0) + Duration::seconds(5));
+        provenance.add_fallback("JWST API unavailable".to_string());
+        provenance.add_upstream("jwst", at(10));
+
+        assert_eq!(provenance.source, DataSource::Fallback);
+        assert!(provenance.degraded);
+        assert_eq!(provenance.warnings.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_scope_collects_records() {
+        assert!(current().is_none());
+        record_stale("ignored outside of a request");
+
+        let collected = scope(async {
+            record_upstream("osdr");
+            record_stale("served stored copy");
+            current()
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(collected.source, DataSource::Upstream);
+        assert!(collected.degraded);
+        assert_eq!(collected.warnings, vec!["served stored copy".to_string()]);
+    }
+}
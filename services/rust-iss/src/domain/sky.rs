@@ -0,0 +1,170 @@
+use crate::{
+    domain::models::{MoonEphemeris, MoonPhase, SkyReport, SunEphemeris, Twilight},
+    utils::ephemeris::{altitude, hour_angle, moon_position, sun_position},
+};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+/// Шаг поиска восходов и заходов (минут); светила не пересекают порог дважды за шаг
+const SCAN_STEP_MINUTES: i64 = 10;
+
+/// Высота центра Солнца при восходе/заходе: рефракция 34' и полудиаметр 16'
+const SUNRISE_ALTITUDE_DEG: f64 = -0.833;
+const CIVIL_TWILIGHT_DEG: f64 = -6.0;
+const NAUTICAL_TWILIGHT_DEG: f64 = -12.0;
+const ASTRONOMICAL_TWILIGHT_DEG: f64 = -18.0;
+
+/// Средний синодический месяц (сутки)
+const SYNODIC_MONTH_DAYS: f64 = 29.530588853;
+
+/// Переход функции через порог: момент (с точностью до секунды) и направление
+#[derive(Debug, Clone, Copy)]
+struct Crossing {
+    at: DateTime<Utc>,
+    rising: bool,
+}
+
+/// Эфемериды Солнца и Луны на сутки date по местному среднему солнечному времени
+pub fn sky_report(lat: f64, lon: f64, date: NaiveDate) -> SkyReport {
+    let midnight = date.and_hms_opt(0, 0, 0).expect("valid midnight").and_utc();
+    let day_start = midnight - Duration::seconds((lon / 15.0 * 3600.0).round() as i64);
+
+    SkyReport {
+        date,
+        latitude: lat,
+        longitude: lon,
+        day_start,
+        sun: sun_ephemeris(lat, lon, day_start),
+        moon: moon_ephemeris(lat, lon, day_start),
+    }
+}
+
+fn sun_ephemeris(lat: f64, lon: f64, start: DateTime<Utc>) -> SunEphemeris {
+    let end = start + Duration::days(1);
+    let sun_altitude = |at: DateTime<Utc>| altitude(&sun_position(at), lat, lon, at);
+
+    let horizon = find_crossings(&|at| sun_altitude(at) - SUNRISE_ALTITUDE_DEG, start, end);
+    let twilight = |threshold: f64| {
+        let crossings = find_crossings(&|at| sun_altitude(at) - threshold, start, end);
+        Twilight {
+            dawn: first(&crossings, true),
+            dusk: first(&crossings, false),
+        }
+    };
+
+    let transit = find_crossings(&|at| sun_hour_angle(lon, at), start, end);
+
+    SunEphemeris {
+        sunrise: first(&horizon, true),
+        sunset: first(&horizon, false),
+        solar_noon: first(&transit, true),
+        day_length_seconds: time_above(sun_altitude(start) > SUNRISE_ALTITUDE_DEG, &horizon, start, end),
+        civil_twilight: twilight(CIVIL_TWILIGHT_DEG),
+        nautical_twilight: twilight(NAUTICAL_TWILIGHT_DEG),
+        astronomical_twilight: twilight(ASTRONOMICAL_TWILIGHT_DEG),
+    }
+}
+
+/// Часовой угол Солнца (градусы): верхняя кульминация - переход через ноль вверх,
+/// нижняя - разрыв 360°, который find_crossings пропускает
+fn sun_hour_angle(lon: f64, at: DateTime<Utc>) -> f64 {
+    hour_angle(&sun_position(at), lon, at).to_degrees()
+}
+
+fn moon_ephemeris(lat: f64, lon: f64, start: DateTime<Utc>) -> MoonEphemeris {
+    let end = start + Duration::days(1);
+
+    // Порог для Луны зависит от параллакса: h0 = 0.7275·π - 34'
+    let above_horizon = |at: DateTime<Utc>| {
+        let moon = moon_position(at);
+        altitude(&moon, lat, lon, at) - (0.7275 * moon.parallax.to_degrees() - 0.5667)
+    };
+    let horizon = find_crossings(&above_horizon, start, end);
+
+    let noon = start + Duration::hours(12);
+    let (sun, moon) = (sun_position(noon), moon_position(noon));
+    // Элонгация по эклиптической долготе задаёт фазу, угловое расстояние - освещённость
+    let elongation = (moon.longitude - sun.longitude).to_degrees().rem_euclid(360.0);
+    let separation = (sun.declination.sin() * moon.declination.sin()
+        + sun.declination.cos() * moon.declination.cos() * (sun.right_ascension - moon.right_ascension).cos())
+    .clamp(-1.0, 1.0)
+    .acos();
+
+    MoonEphemeris {
+        moonrise: first(&horizon, true),
+        moonset: first(&horizon, false),
+        phase: moon_phase(elongation),
+        illumination: (1.0 - separation.cos()) / 2.0,
+        age_days: elongation / 360.0 * SYNODIC_MONTH_DAYS,
+    }
+}
+
+/// Фаза по элонгации Луны (градусы): восемь секторов по 45° с центрами на главных фазах
+pub fn moon_phase(elongation_deg: f64) -> MoonPhase {
+    const PHASES: [MoonPhase; 8] = [
+        MoonPhase::NewMoon,
+        MoonPhase::WaxingCrescent,
+        MoonPhase::FirstQuarter,
+        MoonPhase::WaxingGibbous,
+        MoonPhase::FullMoon,
+        MoonPhase::WaningGibbous,
+        MoonPhase::LastQuarter,
+        MoonPhase::WaningCrescent,
+    ];
+    PHASES[((elongation_deg.rem_euclid(360.0) + 22.5) / 45.0) as usize % 8]
+}
+
+/// Все переходы f (градусы) через ноль в [start, end); разрывы функции (скачок больше 180°) не считаются
+fn find_crossings(f: &impl Fn(DateTime<Utc>) -> f64, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<Crossing> {
+    let step = Duration::minutes(SCAN_STEP_MINUTES);
+    let mut crossings = Vec::new();
+    let mut t = start;
+    let mut value = f(t);
+
+    while t < end {
+        let next = (t + step).min(end);
+        let next_value = f(next);
+
+        if (value > 0.0) != (next_value > 0.0) && (next_value - value).abs() < 180.0 {
+            let rising = next_value > 0.0;
+            let (mut low, mut high) = (t, next);
+            while high - low > Duration::seconds(1) {
+                let mid = low + (high - low) / 2;
+                if (f(mid) > 0.0) == rising {
+                    high = mid;
+                } else {
+                    low = mid;
+                }
+            }
+            crossings.push(Crossing { at: high, rising });
+        }
+
+        t = next;
+        value = next_value;
+    }
+
+    crossings
+}
+
+fn first(crossings: &[Crossing], rising: bool) -> Option<DateTime<Utc>> {
+    crossings.iter().find(|c| c.rising == rising).map(|c| c.at)
+}
+
+/// Время над порогом в [start, end) по начальному состоянию и переходам
+fn time_above(mut above: bool, crossings: &[Crossing], start: DateTime<Utc>, end: DateTime<Utc>) -> i64 {
+    let mut total = 0;
+    let mut since = start;
+    for crossing in crossings {
+        if above {
+            total += (crossing.at - since).num_seconds();
+        }
+        above = crossing.rising;
+        since = crossing.at;
+    }
+    if above {
+        total += (end - since).num_seconds();
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use chrono::TimeZone;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn assert_near(actual: Option<DateTime<Utc>>, expected: DateTime<Utc>, minutes: i64) {
+        let actual = actual.expect("event within the day");
+        assert!(
+            (actual - expected).num_seconds().abs() <= minutes * 60,
+            "{} vs expected {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_london_midsummer() {
+        let report = sky_report(51.5074, -0.1278, date(2024, 6, 21));
+        let sun = &report.sun;
+
+        assert_near(sun.sunrise, utc(2024, 6, 21, 3, 43), 2);
+        assert_near(sun.sunset, utc(2024, 6, 21, 20, 21), 2);
+        assert_near(sun.solar_noon, utc(2024, 6, 21, 12, 2), 1);
+        assert_near(sun.civil_twilight.dawn, utc(2024, 6, 21, 2, 57), 3);
+        assert_near(sun.civil_twilight.dusk, utc(2024, 6, 21, 21, 8), 3);
+
+        // Летом на этой широте Солнце не опускается ниже -18°
+        assert!(sun.nautical_twilight.dawn.is_some());
+        assert_eq!(sun.astronomical_twilight, Twilight::default());
+        assert!((16 * 3600..17 * 3600).contains(&sun.day_length_seconds));
+    }
+
+    #[test]
+    fn test_polar_day_and_night() {
+        let summer = sky_report(69.65, 18.96, date(2024, 6, 21));
+        assert_eq!((summer.sun.sunrise, summer.sun.sunset), (None, None));
+        assert_eq!(summer.sun.day_length_seconds, 86400);
+
+        let winter = sky_report(69.65, 18.96, date(2024, 12, 21));
+        assert_eq!((winter.sun.sunrise, winter.sun.sunset), (None, None));
+        assert_eq!(winter.sun.day_length_seconds, 0);
+        assert!(winter.sun.civil_twilight.dawn.is_some());
+    }
+
+    #[test]
+    fn test_moon_phases() {
+        // Полнолуние 22.06.2024 01:08 UTC: Луна восходит вечером и заходит утром
+        let full = sky_report(51.5074, -0.1278, date(2024, 6, 22)).moon;
+        assert_eq!(full.phase, MoonPhase::FullMoon);
+        assert!(full.illumination > 0.97, "illumination {}", full.illumination);
+        assert!((full.age_days - 15.3).abs() < 1.0, "age {}", full.age_days);
+        assert_near(full.moonrise, utc(2024, 6, 22, 21, 0), 90);
+        assert_near(full.moonset, utc(2024, 6, 22, 4, 0), 90);
+
+        // Новолуние 06.07.2024 22:57 UTC
+        let new = sky_report(51.5074, -0.1278, date(2024, 7, 6)).moon;
+        assert_eq!(new.phase, MoonPhase::NewMoon);
+        assert!(new.illumination < 0.02, "illumination {}", new.illumination);
+    }
+
+    #[test]
+    fn test_moon_phase_sectors() {
+        assert_eq!(moon_phase(0.0), MoonPhase::NewMoon);
+        assert_eq!(moon_phase(350.0), MoonPhase::NewMoon);
+        assert_eq!(moon_phase(90.0), MoonPhase::FirstQuarter);
+        assert_eq!(moon_phase(200.0), MoonPhase::FullMoon);
+        assert_eq!(moon_phase(300.0), MoonPhase::WaningCrescent);
+    }
+
+    #[test]
+    fn test_transit_search_skips_lower_culmination() {
+        let start = utc(2024, 6, 21, 0, 0);
+        let crossings = find_crossings(&|at| sun_hour_angle(-0.1278, at), start, start + Duration::days(1));
+
+        // Разрыв часового угла в нижней кульминации - не переход
+        assert_eq!(crossings.len(), 1);
+        assert!(crossings[0].rising);
+        assert_near(Some(crossings[0].at), utc(2024, 6, 21, 12, 2), 2);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::domain::{
+    donki::parse_donki_time,
+    models::{DonkiEvent, SpaceWeatherDay, SpaceWeatherLevel},
+};
+use chrono::{Duration, NaiveDate};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Вес вспышки по классу GOES
+const FLARE_WEIGHT_X: f64 = 10.0;
+const FLARE_WEIGHT_M: f64 = 3.0;
+const FLARE_WEIGHT_C: f64 = 1.0;
+
+/// Скорость CME, дающая 1 балл (км/с)
+const CME_SPEED_UNIT: f64 = 500.0;
+
+/// Баллов за каждую единицу Kp выше 4 (Kp 5 = G1)
+const KP_WEIGHT: f64 = 5.0;
+
+const ACTIVE_SCORE: f64 = 10.0;
+const STORM_SCORE: f64 = 30.0;
+
+/// Посчитать дневной индекс для каждой даты диапазона (включительно).
+/// Вспышки и CME учитываются в день начала, Kp - в день наблюдения.
+pub fn daily_index(
+    start: NaiveDate,
+    end: NaiveDate,
+    flares: &[DonkiEvent],
+    cmes: &[DonkiEvent],
+    storms: &[DonkiEvent],
+) -> Vec<SpaceWeatherDay> {
+    let mut days: BTreeMap<NaiveDate, SpaceWeatherDay> = BTreeMap::new();
+    let mut date = start;
+    while date <= end {
+        days.insert(date, empty_day(date));
+        date += Duration::days(1);
+    }
+
+    for flare in flares {
+        if let Some(day) = days.get_mut(&flare.start_time.date_naive()) {
+            match flare_class(&flare.payload) {
+                Some('X') => day.flares_x += 1,
+                Some('M') => day.flares_m += 1,
+                Some('C') => day.flares_c += 1,
+                _ => {}
+            }
+        }
+    }
+
+    for cme in cmes {
+        if let Some(day) = days.get_mut(&cme.start_time.date_naive()) {
+            day.cme_count += 1;
+            if let Some(speed) = cme_speed(&cme.payload) {
+                day.max_cme_speed_kps = Some(day.max_cme_speed_kps.map_or(speed, |max| max.max(speed)));
+            }
+        }
+    }
+
+    for storm in storms {
+        for (observed, kp) in kp_observations(&storm.payload) {
+            if let Some(day) = days.get_mut(&observed) {
+                day.max_kp = Some(day.max_kp.map_or(kp, |max| max.max(kp)));
+            }
+        }
+    }
+
+    days.into_values().map(score_day).collect()
+}
+
+fn empty_day(date: NaiveDate) -> SpaceWeatherDay {
+    SpaceWeatherDay {
+        date,
+        score: 0.0,
+        level: SpaceWeatherLevel::Quiet,
+        flares_x: 0,
+        flares_m: 0,
+        flares_c: 0,
+        cme_count: 0,
+        max_cme_speed_kps: None,
+        max_kp: None,
+    }
+}
+
+fn score_day(mut day: SpaceWeatherDay) -> SpaceWeatherDay {
+    let flare_score = f64::from(day.flares_x) * FLARE_WEIGHT_X
+        + f64::from(day.flares_m) * FLARE_WEIGHT_M
+        + f64::from(day.flares_c) * FLARE_WEIGHT_C;
+    let cme_score = day.max_cme_speed_kps.map_or(0.0, |speed| speed / CME_SPEED_UNIT);
+    let kp = day.max_kp.unwrap_or(0.0);
+    let storm_score = (kp - 4.0).max(0.0) * KP_WEIGHT;
+
+    day.score = ((flare_score + cme_score + storm_score) * 10.0).round() / 10.0;
+    day.level = if kp >= 5.0 || day.score >= STORM_SCORE {
+        SpaceWeatherLevel::Storm
+    } else if kp >= 4.0 || day.score >= ACTIVE_SCORE {
+        SpaceWeatherLevel::Active
+    } else {
+        SpaceWeatherLevel::Quiet
+    };
+    day
+}
+
+/// Буква класса вспышки из classType ("X8.7" -> 'X')
+fn flare_class(payload: &Value) -> Option<char> {
+    payload
+        .get("classType")?
+        .as_str()?
+        .trim()
+        .chars()
+        .next()
+        .map(|c| c.to_ascii_uppercase())
+}
+
+/// Скорость CME: из самого точного анализа, иначе максимальная
+fn cme_speed(payload: &Value) -> Option<f64> {
+    let analyses = payload.get("cmeAnalyses")?.as_array()?;
+
+    analyses
+        .iter()
+        .find(|a| a.get("isMostAccurate").and_then(Value::as_bool) == Some(true))
+        .and_then(|a| a.get("speed").and_then(Value::as_f64))
+        .or_else(|| {
+            analyses
+                .iter()
+                .filter_map(|a| a.get("speed").and_then(Value::as_f64))
+                .reduce(f64::max)
+        })
+}
+
+fn kp_observations(payload: &Value) -> Vec<(NaiveDate, f64)> {
+    payload
+        .get("allKpIndex")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|kp| {
+            let observed = parse_donki_time(kp.get("observedTime")?.as_str()?)?;
+            Some((observed.date_naive(), kp.get("kpIndex")?.as_f64()?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests;
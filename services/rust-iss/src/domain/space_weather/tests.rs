@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    fn event(event_type: &str, day: u32, payload: Value) -> DonkiEvent {
+        DonkiEvent {
+            activity_id: format!("{}-{}", event_type, day),
+            event_type: event_type.to_string(),
+            start_time: Utc.with_ymd_and_hms(2024, 5, day, 6, 0, 0).unwrap(),
+            linked_ids: Vec::new(),
+            payload,
+            fetched_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_quiet_days_without_events() {
+        let days = daily_index(date(1), date(3), &[], &[], &[]);
+
+        assert_eq!(days.len(), 3);
+        assert!(days.iter().all(|d| d.score == 0.0 && d.level == SpaceWeatherLevel::Quiet));
+    }
+
+    #[test]
+    fn test_flares_weighted_by_class() {
+        let flares = vec![
+            event("FLR", 1, json!({ "classType": "X1.2" })),
+            event("FLR", 1, json!({ "classType": "M5.0" })),
+            event("FLR", 1, json!({ "classType": "C3.1" })),
+            event("FLR", 2, json!({ "classType": "C1.0" })),
+            event("FLR", 2, json!({ "classType": "B9.0" })),
+        ];
+
+        let days = daily_index(date(1), date(2), &flares, &[], &[]);
+
+        assert_eq!((days[0].flares_x, days[0].flares_m, days[0].flares_c), (1, 1, 1));
+        assert_eq!(days[0].score, 14.0);
+        assert_eq!(days[0].level, SpaceWeatherLevel::Active);
+        assert_eq!(days[1].score, 1.0);
+        assert_eq!(days[1].level, SpaceWeatherLevel::Quiet);
+    }
+
+    #[test]
+    fn test_cme_speed_prefers_most_accurate_analysis() {
+        let cmes = vec![event(
+            "CME",
+            1,
+            json!({ "cmeAnalyses": [
+                { "speed": 2000.0, "isMostAccurate": false },
+                { "speed": 1500.0, "isMostAccurate": true }
+            ] }),
+        )];
+
+        let days = daily_index(date(1), date(1), &[], &cmes, &[]);
+
+        assert_eq!(days[0].cme_count, 1);
+        assert_eq!(days[0].max_cme_speed_kps, Some(1500.0));
+        assert_eq!(days[0].score, 3.0);
+    }
+
+    #[test]
+    fn test_storm_kp_counted_on_observation_day() {
+        // Буря началась 10-го, но Kp 9 наблюдался 11-го
+        let storms = vec![event(
+            "GST",
+            10,
+            json!({ "allKpIndex": [
+                { "observedTime": "2024-05-10T21:00Z", "kpIndex": 4.33 },
+                { "observedTime": "2024-05-11T03:00Z", "kpIndex": 9.0 }
+            ] }),
+        )];
+
+        let days = daily_index(date(10), date(12), &[], &[], &storms);
+
+        assert_eq!(days[0].max_kp, Some(4.33));
+        assert_eq!(days[0].level, SpaceWeatherLevel::Active);
+        assert_eq!(days[1].max_kp, Some(9.0));
+        assert_eq!(days[1].score, 25.0);
+        assert_eq!(days[1].level, SpaceWeatherLevel::Storm);
+        assert_eq!(days[2].level, SpaceWeatherLevel::Quiet);
+    }
+
+    #[test]
+    fn test_events_outside_range_ignored() {
+        let flares = vec![event("FLR", 5, json!({ "classType": "X9.0" }))];
+        let days = daily_index(date(1), date(2), &flares, &[], &[]);
+
+        assert!(days.iter().all(|d| d.flares_x == 0));
+    }
+}
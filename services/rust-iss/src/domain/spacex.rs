@@ -0,0 +1,153 @@
+use crate::domain::models::{
+    LaunchChangeKind, LaunchScheduleChange, SpaceXApiLaunch, SpaceXApiLaunchpad, SpaceXApiPayload,
+    SpaceXApiRocket, SpaceXLaunch, SpaceXLaunchpad, SpaceXPayload, SpaceXRocket,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Разобрать документы коллекции SpaceX API; неразобранные пропускаются (их число - второй элемент)
+pub fn parse_docs<A: DeserializeOwned, T>(docs: Vec<Value>, convert: impl Fn(A) -> T) -> (Vec<T>, usize) {
+    let total = docs.len();
+    let items: Vec<T> = docs
+        .into_iter()
+        .filter_map(|doc| serde_json::from_value::<A>(doc).ok())
+        .map(convert)
+        .collect();
+    let skipped = total - items.len();
+    (items, skipped)
+}
+
+pub fn to_launch(launch: SpaceXApiLaunch) -> SpaceXLaunch {
+    let links = launch.links.unwrap_or_default();
+    let patch_url = links.patch.and_then(|p| p.small.or(p.large));
+
+    SpaceXLaunch {
+        id: launch.id,
+        name: launch.name,
+        flight_number: launch.flight_number,
+        date_utc: launch.date_utc,
+        date_precision: launch.date_precision,
+        upcoming: launch.upcoming,
+        success: launch.success,
+        tbd: launch.tbd.unwrap_or(false),
+        net: launch.net.unwrap_or(false),
+        rocket_id: launch.rocket,
+        rocket_name: None,
+        launchpad_id: launch.launchpad,
+        launchpad_name: None,
+        payload_ids: launch.payloads,
+        details: launch.details.filter(|d| !d.is_empty()),
+        patch_url,
+        webcast_url: links.webcast.filter(|w| !w.is_empty()),
+        failure_reasons: launch.failures.into_iter().filter_map(|f| f.reason).collect(),
+        updated_at: Utc::now(),
+    }
+}
+
+pub fn to_rocket(rocket: SpaceXApiRocket) -> SpaceXRocket {
+    SpaceXRocket {
+        id: rocket.id,
+        name: rocket.name,
+        rocket_type: rocket.rocket_type,
+        active: rocket.active,
+        stages: rocket.stages,
+        cost_per_launch: rocket.cost_per_launch,
+        success_rate_pct: rocket.success_rate_pct,
+        first_flight: rocket
+            .first_flight
+            .and_then(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok()),
+        country: rocket.country,
+        company: rocket.company,
+        description: rocket.description,
+    }
+}
+
+pub fn to_launchpad(pad: SpaceXApiLaunchpad) -> SpaceXLaunchpad {
+    SpaceXLaunchpad {
+        id: pad.id,
+        name: pad.name,
+        full_name: pad.full_name,
+        locality: pad.locality,
+        region: pad.region,
+        latitude: pad.latitude,
+        longitude: pad.longitude,
+        status: pad.status,
+        timezone: pad.timezone,
+        launch_attempts: pad.launch_attempts,
+        launch_successes: pad.launch_successes,
+    }
+}
+
+pub fn to_payload(payload: SpaceXApiPayload) -> SpaceXPayload {
+    SpaceXPayload {
+        id: payload.id,
+        name: payload.name,
+        payload_type: payload.payload_type,
+        launch_id: payload.launch,
+        customers: payload.customers,
+        mass_kg: payload.mass_kg,
+        orbit: payload.orbit,
+        regime: payload.regime,
+        reused: payload.reused,
+    }
+}
+
+/// Статус запуска для истории расписания
+pub fn launch_status(launch: &SpaceXLaunch) -> &'static str {
+    if launch.upcoming {
+        return "upcoming";
+    }
+    match launch.success {
+        Some(true) => "success",
+        Some(false) => "failure",
+        None => "unknown",
+    }
+}
+
+/// Изменения между сохранённым и свежим состоянием запуска.
+/// Переносы фиксируются только для предстоящих запусков, смена статуса - пока итог не известен
+pub fn schedule_changes(
+    stored: &SpaceXLaunch,
+    fresh: &SpaceXLaunch,
+    detected_at: DateTime<Utc>,
+) -> Vec<LaunchScheduleChange> {
+    let previous_status = launch_status(stored);
+    let status = launch_status(fresh);
+
+    let rescheduled = stored.date_utc != fresh.date_utc
+        || stored.date_precision != fresh.date_precision
+        || stored.tbd != fresh.tbd
+        || stored.net != fresh.net;
+    let transitioned = previous_status != status && matches!(previous_status, "upcoming" | "unknown");
+
+    let change = |kind: LaunchChangeKind| LaunchScheduleChange {
+        launch_id: fresh.id.clone(),
+        launch_name: fresh.name.clone(),
+        kind: kind.as_str().to_string(),
+        previous_date_utc: stored.date_utc,
+        date_utc: fresh.date_utc,
+        slip_seconds: (fresh.date_utc - stored.date_utc).num_seconds(),
+        previous_precision: stored.date_precision.clone(),
+        date_precision: fresh.date_precision.clone(),
+        previous_tbd: stored.tbd,
+        tbd: fresh.tbd,
+        previous_net: stored.net,
+        net: fresh.net,
+        previous_status: previous_status.to_string(),
+        status: status.to_string(),
+        detected_at,
+    };
+
+    let mut changes = Vec::new();
+    if stored.upcoming && rescheduled {
+        changes.push(change(LaunchChangeKind::Slip));
+    }
+    if transitioned {
+        changes.push(change(LaunchChangeKind::Status));
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests;
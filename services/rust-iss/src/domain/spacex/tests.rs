@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use serde_json::json;
+
+    fn launch_doc() -> Value {
+        json!({
+            "id": "5eb87cd9ffd86e000604b32a",
+            "name": "FalconSat",
+            "flight_number": 1,
+            "date_utc": "2006-03-24T22:30:00.000Z",
+            "date_unix": 1143239400,
+            "date_precision": "hour",
+            "upcoming": false,
+            "success": false,
+            "tbd": false,
+            "net": false,
+            "rocket": "5e9d0d95eda69955f709d1eb",
+            "launchpad": "5e9e4502f5090995de566f86",
+            "payloads": ["5eb0e4b5b6c3bb0006eeb1e1"],
+            "details": "Engine failure at 33 seconds and loss of vehicle",
+            "failures": [{"time": 33, "altitude": null, "reason": "merlin engine failure"}],
+            "links": {
+                "patch": {"small": "https://images2.imgbox.com/94/f2/NN6Ph45r_o.png", "large": null},
+                "webcast": "https://www.youtube.com/watch?v=0a_00nJ_Y88"
+            }
+        })
+    }
+
+    #[test]
+    fn test_to_launch() {
+        let (launches, skipped) = parse_docs(vec![launch_doc()], to_launch);
+        assert_eq!(skipped, 0);
+
+        let launch = &launches[0];
+        assert_eq!(launch.flight_number, 1);
+        assert_eq!(launch.date_utc.to_rfc3339(), "2006-03-24T22:30:00+00:00");
+        assert_eq!(launch.success, Some(false));
+        assert_eq!(launch.rocket_id.as_deref(), Some("5e9d0d95eda69955f709d1eb"));
+        assert_eq!(launch.failure_reasons, vec!["merlin engine failure".to_string()]);
+        assert_eq!(launch.patch_url.as_deref(), Some("https://images2.imgbox.com/94/f2/NN6Ph45r_o.png"));
+    }
+
+    #[test]
+    fn test_upcoming_launch_with_nulls() {
+        let mut doc = launch_doc();
+        doc["upcoming"] = json!(true);
+        doc["success"] = Value::Null;
+        doc["tbd"] = Value::Null;
+        doc["links"] = Value::Null;
+        doc["details"] = json!("");
+
+        let (launches, _) = parse_docs(vec![doc], to_launch);
+        let launch = &launches[0];
+        assert!(launch.upcoming);
+        assert_eq!(launch.success, None);
+        assert!(!launch.tbd);
+        assert_eq!(launch.patch_url, None);
+        assert_eq!(launch.details, None);
+    }
+
+    #[test]
+    fn test_skips_malformed_docs() {
+        let (rockets, skipped) = parse_docs(
+            vec![
+                json!({"id": "5e9d0d95eda69973a809d1ec", "name": "Falcon 9", "type": "rocket", "active": true,
+                       "stages": 2, "cost_per_launch": 50000000, "success_rate_pct": 98,
+                       "first_flight": "2010-06-04", "country": "United States", "company": "SpaceX"}),
+                json!({"name": "no id"}),
+            ],
+            to_rocket,
+        );
+        assert_eq!(skipped, 1);
+        assert_eq!(rockets[0].first_flight, NaiveDate::from_ymd_opt(2010, 6, 4));
+        assert_eq!(rockets[0].rocket_type.as_deref(), Some("rocket"));
+    }
+
+    #[test]
+    fn test_to_payload() {
+        let (payloads, _) = parse_docs(
+            vec![json!({"id": "p1", "name": "Starlink-1", "type": "Satellite", "launch": "l1",
+                        "customers": ["SpaceX"], "mass_kg": 15600, "orbit": "VLEO", "regime": "very-low-earth",
+                        "reused": false})],
+            to_payload,
+        );
+        assert_eq!(payloads[0].mass_kg, Some(15600.0));
+        assert_eq!(payloads[0].launch_id.as_deref(), Some("l1"));
+    }
+
+    fn upcoming_launch() -> SpaceXLaunch {
+        let mut doc = launch_doc();
+        doc["upcoming"] = json!(true);
+        doc["success"] = Value::Null;
+        doc["date_utc"] = json!("2026-11-01T12:00:00.000Z");
+        let (mut launches, _) = parse_docs(vec![doc], to_launch);
+        launches.remove(0)
+    }
+
+    #[test]
+    fn test_slip_detected_for_upcoming_launch() {
+        let stored = upcoming_launch();
+        let mut fresh = stored.clone();
+        fresh.date_utc = stored.date_utc + chrono::Duration::hours(26);
+        fresh.date_precision = "day".to_string();
+        fresh.net = true;
+
+        let changes = schedule_changes(&stored, &fresh, Utc::now());
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, "slip");
+        assert_eq!(changes[0].slip_seconds, 26 * 3600);
+        assert_eq!(changes[0].previous_precision, "hour");
+        assert_eq!(changes[0].date_precision, "day");
+        assert!(!changes[0].previous_net && changes[0].net);
+
+        assert!(schedule_changes(&stored, &stored.clone(), Utc::now()).is_empty());
+    }
+
+    #[test]
+    fn test_status_transitions() {
+        let stored = upcoming_launch();
+        let mut launched = stored.clone();
+        launched.upcoming = false;
+
+        // Итог ещё не опубликован, затем - успех
+        let changes = schedule_changes(&stored, &launched, Utc::now());
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, "status");
+        assert_eq!((changes[0].previous_status.as_str(), changes[0].status.as_str()), ("upcoming", "unknown"));
+
+        let mut succeeded = launched.clone();
+        succeeded.success = Some(true);
+        let changes = schedule_changes(&launched, &succeeded, Utc::now());
+        assert_eq!(changes[0].status, "success");
+
+        // Прошедшие запуски с известным итогом больше не отслеживаются
+        let mut corrected = succeeded.clone();
+        corrected.success = Some(false);
+        corrected.date_utc = succeeded.date_utc + chrono::Duration::minutes(5);
+        assert!(schedule_changes(&succeeded, &corrected, Utc::now()).is_empty());
+    }
+}
@@ -0,0 +1,84 @@
+use crate::domain::models::{OsdrDataset, OsdrWatchlist};
+
+/// Синонимы организмов. Каталог OSDR хранит только заголовок и описание,
+/// поэтому организм ищется по тексту датасета
+const ORGANISM_SYNONYMS: &[(&str, &[&str])] = &[
+    ("mouse", &["mouse", "mice", "mus musculus", "murine"]),
+    ("rat", &["rat", "rats", "rattus"]),
+    ("rodent", &["rodent", "rodents", "mouse", "mice", "mus musculus", "murine", "rat", "rats", "rattus"]),
+    ("human", &["human", "humans", "homo sapiens", "astronaut", "astronauts"]),
+    ("arabidopsis", &["arabidopsis", "thale cress"]),
+    ("c. elegans", &["c. elegans", "caenorhabditis", "nematode", "nematodes"]),
+    ("drosophila", &["drosophila", "fruit fly", "fruit flies"]),
+    ("zebrafish", &["zebrafish", "danio rerio"]),
+    ("yeast", &["yeast", "saccharomyces"]),
+    ("e. coli", &["e. coli", "escherichia coli"]),
+];
+
+/// Проверить датасет на соответствие watchlist.
+///
+/// Совпадение требует хотя бы одного ключевого слова (если они заданы) и хотя бы
+/// одного организма (если они заданы). Возвращает совпавшие термины.
+pub fn match_dataset(watchlist: &OsdrWatchlist, dataset: &OsdrDataset) -> Option<Vec<String>> {
+    let text = normalize(&format!(
+        "{} {}",
+        dataset.title,
+        dataset.description.as_deref().unwrap_or("")
+    ));
+
+    let keywords: Vec<String> = watchlist
+        .keywords
+        .iter()
+        .filter(|keyword| contains_term(&text, &normalize(keyword)))
+        .cloned()
+        .collect();
+
+    let organisms: Vec<String> = watchlist
+        .organisms
+        .iter()
+        .filter(|organism| organism_terms(organism).iter().any(|term| contains_term(&text, term)))
+        .cloned()
+        .collect();
+
+    let keywords_ok = watchlist.keywords.is_empty() || !keywords.is_empty();
+    let organisms_ok = watchlist.organisms.is_empty() || !organisms.is_empty();
+    if !keywords_ok || !organisms_ok || (keywords.is_empty() && organisms.is_empty()) {
+        return None;
+    }
+
+    Some(keywords.into_iter().chain(organisms).collect())
+}
+
+fn organism_terms(organism: &str) -> Vec<String> {
+    let organism = normalize(organism);
+    ORGANISM_SYNONYMS
+        .iter()
+        .find(|(name, synonyms)| *name == organism || synonyms.contains(&organism.as_str()))
+        .map(|(_, synonyms)| synonyms.iter().map(|s| s.to_string()).collect())
+        .unwrap_or_else(|| vec![organism])
+}
+
+fn normalize(value: &str) -> String {
+    value
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Поиск термина по границам слов, чтобы "rat" не совпадал с "gravitropism"
+fn contains_term(text: &str, term: &str) -> bool {
+    if term.is_empty() {
+        return false;
+    }
+
+    text.match_indices(term).any(|(start, _)| {
+        let end = start + term.len();
+        let before_ok = !text[..start].chars().next_back().is_some_and(char::is_alphanumeric);
+        let after_ok = !text[end..].chars().next().is_some_and(char::is_alphanumeric);
+        before_ok && after_ok
+    })
+}
+
+#[cfg(test)]
+mod tests;
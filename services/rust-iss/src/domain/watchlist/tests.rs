@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use crate::domain::models::NotifyChannel;
+    use chrono::Utc;
+
+    fn watchlist(keywords: &[&str], organisms: &[&str]) -> OsdrWatchlist {
+        OsdrWatchlist {
+            id: 1,
+            name: "Biology group".to_string(),
+            keywords: keywords.iter().map(|s| s.to_string()).collect(),
+            organisms: organisms.iter().map(|s| s.to_string()).collect(),
+            channel: NotifyChannel::Webhook,
+            target: "http://localhost/hook".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn dataset(title: &str, description: Option<&str>) -> OsdrDataset {
+        OsdrDataset {
+            id: None,
+            dataset_id: "GLDS-379".to_string(),
+            title: title.to_string(),
+            description: description.map(|s| s.to_string()),
+            release_date: None,
+            updated_at: Utc::now(),
+            doi: None,
+            withdrawn_at: None,
+        }
+    }
+
+    #[test]
+    fn test_keyword_match_is_case_insensitive() {
+        let matched = match_dataset(
+            &watchlist(&["Rodent Research"], &[]),
+            &dataset("RODENT RESEARCH-1: bone loss", None),
+        );
+        assert_eq!(matched, Some(vec!["Rodent Research".to_string()]));
+    }
+
+    #[test]
+    fn test_organism_synonyms_match_description() {
+        let matched = match_dataset(
+            &watchlist(&[], &["rodent"]),
+            &dataset("RR-1 bone loss", Some("Gene expression in mice exposed to spaceflight")),
+        );
+        assert_eq!(matched, Some(vec!["rodent".to_string()]));
+    }
+
+    #[test]
+    fn test_terms_match_on_word_boundaries() {
+        let matched = match_dataset(
+            &watchlist(&[], &["rat"]),
+            &dataset("Plant root gravitropism in microgravity", None),
+        );
+        assert!(matched.is_none());
+    }
+
+    #[test]
+    fn test_keywords_and_organisms_must_both_match() {
+        let list = watchlist(&["bone"], &["mouse"]);
+
+        assert!(match_dataset(&list, &dataset("Bone density in rats", None)).is_none());
+        assert_eq!(
+            match_dataset(&list, &dataset("Bone density in mice", None)),
+            Some(vec!["bone".to_string(), "mouse".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_empty_watchlist_never_matches() {
+        assert!(match_dataset(&watchlist(&[], &[]), &dataset("Anything", None)).is_none());
+    }
+}
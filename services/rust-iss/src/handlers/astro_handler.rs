@@ -0,0 +1,102 @@
+use crate::{
+    domain::{
+        error::{ApiError, ApiResponse, ErrorDetail},
+        models::{AstroEventsQuery, AstroObserver, AstroPositionsQuery, SkyQuery, SkyReport},
+    },
+    services::{astronomy_service::validate_astro_date, AstronomyService},
+};
+use axum::{extract::{Query, State}, Json};
+use chrono::{Duration, NaiveDate, NaiveTime, Timelike, Utc};
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use validator::Validate;
+
+pub type SharedAstronomyService = Arc<Mutex<AstronomyService>>;
+
+/// GET /astro/events?body=sun|moon&lat=&lon=&elevation=&from=&to= - Затмения и события тела (AstronomyAPI)
+pub async fn get_astro_events(
+    State(service): State<SharedAstronomyService>,
+    Query(query): Query<AstroEventsQuery>,
+) -> Result<Json<ApiResponse<Value>>, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    let from = query.from.unwrap_or_else(|| Utc::now().date_naive());
+    validate_range(from, query.to)?;
+    let observer = AstroObserver {
+        lat: query.lat,
+        lon: query.lon,
+        elevation: query.elevation.unwrap_or(0.0),
+        from,
+        to: query.to.unwrap_or(from + Duration::days(30)),
+        time: NaiveTime::MIN,
+    };
+
+    let mut service = service.lock().await;
+    let events = service.get_events(query.body, &observer).await?;
+    Ok(Json(ApiResponse::success(events)))
+}
+
+/// GET /astro/positions?lat=&lon=&elevation=&from=&to=&time= - Положения Солнца, Луны и планет (AstronomyAPI)
+pub async fn get_astro_positions(
+    State(service): State<SharedAstronomyService>,
+    Query(query): Query<AstroPositionsQuery>,
+) -> Result<Json<ApiResponse<Value>>, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    let now = Utc::now();
+    let from = query.from.unwrap_or_else(|| now.date_naive());
+    validate_range(from, query.to)?;
+    let observer = AstroObserver {
+        lat: query.lat,
+        lon: query.lon,
+        elevation: query.elevation.unwrap_or(0.0),
+        from,
+        to: query.to.unwrap_or(from + Duration::days(3)),
+        time: query
+            .time
+            .unwrap_or_else(|| NaiveTime::from_hms_opt(now.hour(), 0, 0).unwrap_or(NaiveTime::MIN)),
+    };
+
+    let mut service = service.lock().await;
+    let positions = service.get_positions(&observer).await?;
+    Ok(Json(ApiResponse::success(positions)))
+}
+
+/// GET /astro/sky?lat=&lon=&date= - Восход/заход Солнца и Луны, сумерки и фаза Луны (локальный расчёт)
+pub async fn get_astro_sky(
+    State(service): State<SharedAstronomyService>,
+    Query(query): Query<SkyQuery>,
+) -> Result<Json<ApiResponse<SkyReport>>, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    let date = query.date.unwrap_or_else(|| Utc::now().date_naive());
+
+    let service = service.lock().await;
+    let report = service.get_sky(query.lat, query.lon, date)?;
+    Ok(Json(ApiResponse::success(report)))
+}
+
+/// Границы from/to проверяются до вычисления to по умолчанию (from + N дней)
+fn validate_range(from: NaiveDate, to: Option<NaiveDate>) -> Result<(), ApiError> {
+    validate_astro_date("from", from)?;
+    if let Some(to) = to {
+        validate_astro_date("to", to)?;
+    }
+    Ok(())
+}
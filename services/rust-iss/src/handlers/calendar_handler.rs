@@ -0,0 +1,74 @@
+use crate::{
+    domain::{
+        error::{ApiError, ErrorDetail},
+        ical::{pass_event, render_calendar, CONTENT_TYPE},
+        models::{IssPassesQuery, LaunchCalendarQuery},
+    },
+    AppState,
+};
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use validator::Validate;
+
+/// GET /calendar/launches.ics?alarm_minutes= - Подписка на запуски SpaceX (напоминание за 60 минут)
+pub async fn get_launches_calendar(
+    State(state): State<AppState>,
+    Query(query): Query<LaunchCalendarQuery>,
+) -> Result<Response, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    let mut service = state.spacex_service.lock().await;
+    let events = service.get_calendar_events(query.alarm_minutes.unwrap_or(60)).await?;
+    let body = render_calendar("SpaceX launches", &events, Utc::now());
+
+    Ok(calendar_response(body, "launches.ics"))
+}
+
+/// GET /calendar/iss-passes.ics?lat=&lon=&days=&min_elevation=&alarm_minutes= - Подписка на пролёты МКС
+/// (3 суток, угол места от 10°, напоминание за 10 минут)
+pub async fn get_iss_passes_calendar(
+    State(state): State<AppState>,
+    Query(query): Query<IssPassesQuery>,
+) -> Result<Response, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    let mut service = state.iss_service.lock().await;
+    let prediction = service
+        .predict_passes(query.lat, query.lon, query.days.unwrap_or(3), query.min_elevation.unwrap_or(10.0))
+        .await?;
+
+    let alarm_minutes = query.alarm_minutes.unwrap_or(10);
+    let events: Vec<_> = prediction
+        .passes
+        .iter()
+        .map(|pass| pass_event(pass, query.lat, query.lon, prediction.tle_epoch, alarm_minutes))
+        .collect();
+    let body = render_calendar(&format!("ISS passes ({:.2}, {:.2})", query.lat, query.lon), &events, Utc::now());
+
+    Ok(calendar_response(body, "iss-passes.ics"))
+}
+
+fn calendar_response(body: String, filename: &str) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, CONTENT_TYPE.to_string()),
+            (header::CONTENT_DISPOSITION, format!("inline; filename=\"{}\"", filename)),
+        ],
+        body,
+    )
+        .into_response()
+}
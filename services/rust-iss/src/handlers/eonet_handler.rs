@@ -0,0 +1,44 @@
+use crate::{
+    domain::{
+        error::{ApiError, ApiResponse, ErrorDetail},
+        models::{EonetEvent, EonetEventsQuery, EONET_CATEGORIES},
+    },
+    services::EonetService,
+};
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use validator::Validate;
+
+pub type SharedEonetService = Arc<Mutex<EonetService>>;
+
+/// GET /eonet/events?category=&status=&limit= - Природные события EONET (по умолчанию открытые)
+pub async fn get_eonet_events(
+    State(service): State<SharedEonetService>,
+    Query(query): Query<EonetEventsQuery>,
+) -> Result<Json<ApiResponse<Vec<EonetEvent>>>, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    if let Some(category) = query.category.as_deref() {
+        if !EONET_CATEGORIES.contains(&category) {
+            return Err(ApiError::ValidationError(vec![ErrorDetail {
+                field: "category".to_string(),
+                message: format!("Expected one of: {}", EONET_CATEGORIES.join(", ")),
+            }]));
+        }
+    }
+
+    let mut service = service.lock().await;
+    let events = service
+        .get_events(query.category.as_deref(), query.status, query.limit.unwrap_or(100))
+        .await?;
+    Ok(Json(ApiResponse::success(events)))
+}
@@ -0,0 +1,95 @@
+use crate::{
+    domain::{
+        error::{ApiError, ApiResponse, ErrorDetail},
+        models::{EpicCollection, EpicImage, EpicImagesQuery, EpicNearestQuery},
+    },
+    services::EpicService,
+};
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub type SharedEpicService = Arc<Mutex<EpicService>>;
+
+/// GET /epic/:collection/images?date= - Снимки Земли EPIC (natural|enhanced; по умолчанию последний день)
+pub async fn get_epic_images(
+    State(service): State<SharedEpicService>,
+    Path(collection): Path<String>,
+    Query(query): Query<EpicImagesQuery>,
+) -> Result<Json<ApiResponse<Vec<EpicImage>>>, ApiError> {
+    let collection = parse_collection(&collection)?;
+    let mut service = service.lock().await;
+
+    let images = match query.date.as_deref() {
+        Some(date) => {
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+                ApiError::ValidationError(vec![ErrorDetail {
+                    field: "date".to_string(),
+                    message: "Expected date in YYYY-MM-DD format".to_string(),
+                }])
+            })?;
+            service.get_by_date(collection, date).await?
+        }
+        None => service.get_latest(collection).await?,
+    };
+    Ok(Json(ApiResponse::success(images)))
+}
+
+/// GET /epic/:collection/images/:identifier - Метаданные снимка EPIC
+pub async fn get_epic_image(
+    State(service): State<SharedEpicService>,
+    Path((collection, identifier)): Path<(String, String)>,
+) -> Result<Json<ApiResponse<EpicImage>>, ApiError> {
+    let collection = parse_collection(&collection)?;
+    let mut service = service.lock().await;
+    let image = service.get_image(collection, &identifier).await?;
+    Ok(Json(ApiResponse::success(image)))
+}
+
+/// GET /epic/:collection/dates - Даты, за которые есть снимки EPIC
+pub async fn get_epic_dates(
+    State(service): State<SharedEpicService>,
+    Path(collection): Path<String>,
+) -> Result<Json<ApiResponse<Vec<NaiveDate>>>, ApiError> {
+    let collection = parse_collection(&collection)?;
+    let mut service = service.lock().await;
+    let dates = service.get_available_dates(collection).await?;
+    Ok(Json(ApiResponse::success(dates)))
+}
+
+/// GET /epic/:collection/nearest?at= - Снимок EPIC, ближайший к моменту (RFC 3339, по умолчанию сейчас)
+pub async fn get_epic_nearest(
+    State(service): State<SharedEpicService>,
+    Path(collection): Path<String>,
+    Query(query): Query<EpicNearestQuery>,
+) -> Result<Json<ApiResponse<EpicImage>>, ApiError> {
+    let collection = parse_collection(&collection)?;
+    let at = match query.at.as_deref() {
+        Some(at) => DateTime::parse_from_rfc3339(at)
+            .map_err(|_| {
+                ApiError::ValidationError(vec![ErrorDetail {
+                    field: "at".to_string(),
+                    message: "Expected RFC 3339 timestamp".to_string(),
+                }])
+            })?
+            .with_timezone(&Utc),
+        None => Utc::now(),
+    };
+
+    let mut service = service.lock().await;
+    let image = service.get_nearest(collection, at).await?;
+    Ok(Json(ApiResponse::success(image)))
+}
+
+fn parse_collection(value: &str) -> Result<EpicCollection, ApiError> {
+    EpicCollection::parse(value).ok_or_else(|| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "collection".to_string(),
+            message: "Expected natural or enhanced".to_string(),
+        }])
+    })
+}
@@ -1,12 +1,13 @@
 use crate::{
     domain::{
         error::{ApiError, ApiResponse, ErrorDetail},
-        models::{IssHistoryQuery, IssPosition},
+        models::{EventOverflight, IssHistoryQuery, IssPassPrediction, IssPassesQuery, IssPosition, OverflownEventsQuery},
     },
     services::IssService,
     AppState,
 };
 use axum::{extract::{Query, State}, Json};
+use chrono::{Duration, Utc};
 use validator::Validate;
 
 /// GET /iss/current - Получить текущую позицию МКС
@@ -44,4 +45,47 @@ pub async fn get_history(
     let history = service.get_history(query.start_date, query.end_date, limit).await?;
 
     Ok(Json(ApiResponse::success(history)))
-}
\ No newline at end of file
+}
+
+/// GET /iss/overflown-events?start=&end=&radius_km= - Природные события EONET, над которыми пролетела МКС
+/// (по умолчанию последние сутки, радиус 500 км)
+pub async fn get_overflown_events(
+    State(state): State<AppState>,
+    Query(query): Query<OverflownEventsQuery>,
+) -> Result<Json<ApiResponse<Vec<EventOverflight>>>, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    let end = query.end.unwrap_or_else(Utc::now);
+    let start = query.start.unwrap_or(end - Duration::days(1));
+
+    let mut service = state.eonet_service.lock().await;
+    let overflights = service
+        .get_overflown_events(start, end, query.radius_km.unwrap_or(500.0))
+        .await?;
+    Ok(Json(ApiResponse::success(overflights)))
+}
+
+/// GET /iss/passes?lat=&lon=&days=&min_elevation= - Прогноз пролётов МКС над точкой
+/// (по умолчанию 3 суток, угол места от 10°)
+pub async fn get_passes(
+    State(state): State<AppState>,
+    Query(query): Query<IssPassesQuery>,
+) -> Result<Json<ApiResponse<IssPassPrediction>>, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    let mut service = state.iss_service.lock().await;
+    let prediction = service
+        .predict_passes(query.lat, query.lon, query.days.unwrap_or(3), query.min_elevation.unwrap_or(10.0))
+        .await?;
+    Ok(Json(ApiResponse::success(prediction)))
+}
@@ -1,20 +1,91 @@
 use crate::{
-    domain::error::{ApiError, ApiResponse},
+    domain::{
+        error::{ApiError, ApiResponse, ErrorDetail},
+        models::{FitsHeader, JwstImage, JwstImagesQuery, JwstNewObservations, JwstNewQuery},
+    },
+    repo::jwst_repo::JwstImageFilter,
     services::JwstService,
 };
-use axum::{extract::{Path, State}, Json};
-use serde_json::Value;
+use axum::{body::Bytes, extract::{Path, Query, State}, Json};
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use validator::Validate;
 
 pub type SharedJwstService = Arc<Mutex<JwstService>>;
 
+/// Максимальный размер загружаемого FITS
+pub const FITS_UPLOAD_LIMIT: usize = 64 * 1024 * 1024;
+
+/// GET /jwst/images?program=&instrument=&filter=&suffix=&limit= - Каталог изображений JWST из БД
+pub async fn list_images(
+    State(service): State<SharedJwstService>,
+    Query(query): Query<JwstImagesQuery>,
+) -> Result<Json<ApiResponse<Vec<JwstImage>>>, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    let filter = JwstImageFilter {
+        program: query.program.as_deref(),
+        instrument: query.instrument.as_deref(),
+        filter: query.filter.as_deref(),
+        suffix: query.suffix.as_deref(),
+    };
+
+    let mut service = service.lock().await;
+    let images = service.list_images(&filter, query.limit.unwrap_or(100)).await?;
+    Ok(Json(ApiResponse::success(images)))
+}
+
 /// GET /jwst/images/:program_id - Получить изображения JWST по программе
 pub async fn get_images(
     State(service): State<SharedJwstService>,
     Path(program_id): Path<String>,
-) -> Result<Json<ApiResponse<Value>>, ApiError> {
+) -> Result<Json<ApiResponse<Vec<JwstImage>>>, ApiError> {
     let mut service = service.lock().await;
     let images = service.get_images(&program_id).await?;
     Ok(Json(ApiResponse::success(images)))
-}
\ No newline at end of file
+}
+
+/// GET /jwst/new?since=&limit= - Новые наблюдения отслеживаемых программ
+pub async fn get_new_observations(
+    State(service): State<SharedJwstService>,
+    Query(query): Query<JwstNewQuery>,
+) -> Result<Json<ApiResponse<JwstNewObservations>>, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    let mut service = service.lock().await;
+    let observations = service
+        .get_new_observations(query.since, query.limit.unwrap_or(100))
+        .await?;
+    Ok(Json(ApiResponse::success(observations)))
+}
+
+/// GET /jwst/products/:file/header - Заголовки FITS продукта (Range-запросы к MAST)
+pub async fn get_product_header(
+    State(service): State<SharedJwstService>,
+    Path(file): Path<String>,
+) -> Result<Json<ApiResponse<FitsHeader>>, ApiError> {
+    let mut service = service.lock().await;
+    let header = service.inspect_product(&file).await?;
+    Ok(Json(ApiResponse::success(header)))
+}
+
+/// POST /jwst/products/:file/header - Заголовки загруженного FITS (тело - файл или его начало)
+pub async fn upload_product_header(
+    State(service): State<SharedJwstService>,
+    Path(file): Path<String>,
+    body: Bytes,
+) -> Result<Json<ApiResponse<FitsHeader>>, ApiError> {
+    let service = service.lock().await;
+    let header = service.inspect_upload(&file, &body)?;
+    Ok(Json(ApiResponse::success(header)))
+}
@@ -0,0 +1,61 @@
+use crate::{
+    domain::{
+        error::{ApiError, ApiResponse},
+        models::{MediaLookupQuery, MediaMirrorReport, MediaObject, MediaQuery},
+    },
+    services::MediaService,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub type SharedMediaService = Arc<Mutex<MediaService>>;
+
+/// Размер пачки для ручного запуска зеркалирования
+const MIRROR_BATCH: i64 = 20;
+
+/// GET /media/:hash?variant=original|thumb|webp - Отдать зеркалированное изображение
+pub async fn get_media(
+    State(service): State<SharedMediaService>,
+    Path(hash): Path<String>,
+    Query(query): Query<MediaQuery>,
+) -> Result<Response, ApiError> {
+    let mut service = service.lock().await;
+    let (data, content_type) = service.get_media(&hash, query.variant).await?;
+
+    // Содержимое адресуется хэшем и никогда не меняется
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::CACHE_CONTROL, "public, max-age=31536000, immutable".to_string()),
+            (header::ETAG, format!("\"{}\"", hash)),
+        ],
+        data,
+    )
+        .into_response())
+}
+
+/// GET /media/lookup?url= - Найти зеркало по исходному URL
+pub async fn lookup_media(
+    State(service): State<SharedMediaService>,
+    Query(query): Query<MediaLookupQuery>,
+) -> Result<Json<ApiResponse<MediaObject>>, ApiError> {
+    let mut service = service.lock().await;
+    let object = service.lookup(&query.url).await?;
+    Ok(Json(ApiResponse::success(object)))
+}
+
+/// POST /media/mirror - Обработать очередь зеркалирования
+pub async fn mirror_media(
+    State(service): State<SharedMediaService>,
+) -> Result<Json<ApiResponse<MediaMirrorReport>>, ApiError> {
+    // Скачивание идёт без блокировки сервиса: /media продолжает отвечать
+    let mirror = service.lock().await.mirror();
+    let report = mirror.mirror_pending(MIRROR_BATCH).await?;
+    Ok(Json(ApiResponse::success(report)))
+}
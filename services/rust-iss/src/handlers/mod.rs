@@ -1,13 +1,39 @@
+pub mod astro_handler;
+pub mod calendar_handler;
+pub mod eonet_handler;
+pub mod epic_handler;
 pub mod health;
 pub mod iss_handler;
 pub mod osdr_handler;
 pub mod nasa_handler;
 pub mod jwst_handler;
+pub mod media_handler;
 pub mod spacex_handler;
 
+pub use astro_handler::{get_astro_events, get_astro_positions, get_astro_sky, SharedAstronomyService};
+pub use calendar_handler::{get_iss_passes_calendar, get_launches_calendar};
+pub use eonet_handler::{get_eonet_events, SharedEonetService};
+pub use epic_handler::{
+    get_epic_dates, get_epic_image, get_epic_images, get_epic_nearest, SharedEpicService,
+};
 pub use health::health_check;
-pub use iss_handler::{get_current_position, fetch_position, get_history};
-pub use osdr_handler::{sync_datasets, list_datasets, SharedOsdrService};
-pub use nasa_handler::{get_apod, get_neo, get_donki_flr, get_donki_cme, SharedNasaService};
-pub use jwst_handler::{get_images, SharedJwstService};
-pub use spacex_handler::{get_next_launch, SharedSpaceXService};
\ No newline at end of file
+pub use iss_handler::{get_current_position, fetch_position, get_history, get_overflown_events, get_passes};
+pub use osdr_handler::{
+    sync_datasets, list_sync_runs, list_datasets, cite_dataset, cite_datasets_bulk,
+    get_related_datasets, list_watchlists, create_watchlist, delete_watchlist,
+    list_watchlist_notifications, SharedOsdrService,
+};
+pub use nasa_handler::{
+    backfill_apod, get_apod, get_apod_by_date, get_neo, get_neo_by_id, get_donki_flr,
+    get_donki_cme, get_donki_gst, get_donki_sep, get_donki_ips, get_donki_hss, get_donki_rbe,
+    get_donki_notifications, get_donki_chains, get_donki_related, get_space_weather_index,
+    SharedNasaService,
+};
+pub use jwst_handler::{
+    get_images, get_new_observations, get_product_header, list_images, upload_product_header,
+    SharedJwstService, FITS_UPLOAD_LIMIT,
+};
+pub use media_handler::{get_media, lookup_media, mirror_media, SharedMediaService};
+pub use spacex_handler::{
+    get_launch, get_launch_slips, get_next_launch, get_schedule_changes, list_launches, SharedSpaceXService,
+};
\ No newline at end of file
@@ -1,46 +1,298 @@
 use crate::{
-    domain::error::{ApiError, ApiResponse},
-    services::NasaService,
+    domain::{
+        error::{ApiError, ApiResponse, ErrorDetail},
+        models::{
+            ApodBackfillReport, ApodEntry, ApodRangeQuery, DonkiChain, DonkiDateQuery, DonkiEventType,
+            DonkiNotification, DonkiRangeQuery, GeomagneticStorm, HighSpeedStream, InterplanetaryShock, NeoApproach, NeoDetails, NeoFeedQuery,
+            NeoLookupQuery, RadiationBeltEnhancement, SolarEnergeticParticle, SpaceWeatherIndex,
+        },
+    },
+    services::{nasa_service::{apod_today, nasa_date_range, DONKI_DEFAULT_DAYS}, NasaService},
 };
-use axum::{extract::State, Json};
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::NaiveDate;
 use serde_json::Value;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use validator::Validate;
 
 pub type SharedNasaService = Arc<Mutex<NasaService>>;
 
-/// GET /nasa/apod - Astronomy Picture of the Day
+/// GET /nasa/apod - Astronomy Picture of the Day (?start=&end= - диапазон из архива)
 pub async fn get_apod(
     State(service): State<SharedNasaService>,
+    Query(query): Query<ApodRangeQuery>,
 ) -> Result<Json<ApiResponse<Value>>, ApiError> {
     let mut service = service.lock().await;
-    let apod = service.get_apod().await?;
-    Ok(Json(ApiResponse::success(apod)))
+
+    if query.start.is_none() && query.end.is_none() {
+        let apod = service.get_apod().await?;
+        return Ok(Json(ApiResponse::success(apod)));
+    }
+
+    let (start, end) = parse_range(&query)?;
+    let entries = service.get_apod_range(start, end).await?;
+    let entries = serde_json::to_value(entries)
+        .map_err(|e| ApiError::InternalError(e.to_string()))?;
+    Ok(Json(ApiResponse::success(entries)))
 }
 
-/// GET /nasa/neo - Near-Earth Objects
+/// GET /nasa/apod/:date - APOD за дату (YYYY-MM-DD)
+pub async fn get_apod_by_date(
+    State(service): State<SharedNasaService>,
+    Path(date): Path<String>,
+) -> Result<Json<ApiResponse<ApodEntry>>, ApiError> {
+    let date = parse_date("date", &date)?;
+    let mut service = service.lock().await;
+    let entry = service.get_apod_by_date(date).await?;
+    Ok(Json(ApiResponse::success(entry)))
+}
+
+/// POST /nasa/apod/backfill?start=&end= - Дозаполнить архив APOD
+pub async fn backfill_apod(
+    State(service): State<SharedNasaService>,
+    Query(query): Query<ApodRangeQuery>,
+) -> Result<Json<ApiResponse<ApodBackfillReport>>, ApiError> {
+    let (start, end) = parse_range(&query)?;
+    let mut service = service.lock().await;
+    let report = service.backfill_apod(start, end).await?;
+    Ok(Json(ApiResponse::success(report)))
+}
+
+/// GET /nasa/neo?start=&end=&hazardous=&max_miss_km=&sort= - Сближения Near-Earth Objects (по умолчанию 7 дней от сегодня)
 pub async fn get_neo(
     State(service): State<SharedNasaService>,
-) -> Result<Json<ApiResponse<Value>>, ApiError> {
+    Query(query): Query<NeoFeedQuery>,
+) -> Result<Json<ApiResponse<Vec<NeoApproach>>>, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    let start = match query.start.as_deref() {
+        Some(start) => parse_date("start", start)?,
+        None => chrono::Utc::now().date_naive(),
+    };
+    let end = match query.end.as_deref() {
+        Some(end) => parse_date("end", end)?,
+        None => start + chrono::Duration::days(6),
+    };
+
     let mut service = service.lock().await;
-    let neo = service.get_neo().await?;
+    let neo = service
+        .get_neo(start, end, query.hazardous, query.max_miss_km, query.sort)
+        .await?;
     Ok(Json(ApiResponse::success(neo)))
 }
 
-/// GET /nasa/donki/flr - DONKI Solar Flare events
+/// GET /nasa/neo/:id?start=&end=&step_days= - Объект NEO с орбитой и положениями (по умолчанию год с шагом 5 дней)
+pub async fn get_neo_by_id(
+    State(service): State<SharedNasaService>,
+    Path(neo_id): Path<String>,
+    Query(query): Query<NeoLookupQuery>,
+) -> Result<Json<ApiResponse<NeoDetails>>, ApiError> {
+    if neo_id.is_empty() || !neo_id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ApiError::ValidationError(vec![ErrorDetail {
+            field: "id".to_string(),
+            message: "NEO id must be numeric".to_string(),
+        }]));
+    }
+
+    let start = match query.start.as_deref() {
+        Some(start) => parse_date("start", start)?,
+        None => chrono::Utc::now().date_naive(),
+    };
+    let end = match query.end.as_deref() {
+        Some(end) => parse_date("end", end)?,
+        None => start + chrono::Duration::days(365),
+    };
+
+    let mut service = service.lock().await;
+    let details = service
+        .get_neo_details(&neo_id, start, end, query.step_days.unwrap_or(5))
+        .await?;
+    Ok(Json(ApiResponse::success(details)))
+}
+
+/// GET /nasa/donki/flr?start_date=&end_date= - DONKI Solar Flare events
 pub async fn get_donki_flr(
     State(service): State<SharedNasaService>,
+    Query(query): Query<DonkiDateQuery>,
 ) -> Result<Json<ApiResponse<Value>>, ApiError> {
+    let (start, end) = parse_donki_range(&query)?;
     let mut service = service.lock().await;
-    let flr = service.get_donki_flr().await?;
+    let flr = service.get_donki_flr(start, end).await?;
     Ok(Json(ApiResponse::success(flr)))
 }
 
-/// GET /nasa/donki/cme - DONKI Coronal Mass Ejection events
+/// GET /nasa/donki/cme?start_date=&end_date= - DONKI Coronal Mass Ejection events
 pub async fn get_donki_cme(
     State(service): State<SharedNasaService>,
+    Query(query): Query<DonkiDateQuery>,
 ) -> Result<Json<ApiResponse<Value>>, ApiError> {
+    let (start, end) = parse_donki_range(&query)?;
     let mut service = service.lock().await;
-    let cme = service.get_donki_cme().await?;
+    let cme = service.get_donki_cme(start, end).await?;
     Ok(Json(ApiResponse::success(cme)))
-}
\ No newline at end of file
+}
+
+/// GET /nasa/donki/gst?start_date=&end_date= - DONKI Geomagnetic Storms
+pub async fn get_donki_gst(
+    State(service): State<SharedNasaService>,
+    Query(query): Query<DonkiDateQuery>,
+) -> Result<Json<ApiResponse<Vec<GeomagneticStorm>>>, ApiError> {
+    let (start, end) = parse_donki_range(&query)?;
+    let mut service = service.lock().await;
+    let events = service.get_donki_events(DonkiEventType::Gst, start, end).await?;
+    Ok(Json(ApiResponse::success(events)))
+}
+
+/// GET /nasa/donki/sep?start_date=&end_date= - DONKI Solar Energetic Particle events
+pub async fn get_donki_sep(
+    State(service): State<SharedNasaService>,
+    Query(query): Query<DonkiDateQuery>,
+) -> Result<Json<ApiResponse<Vec<SolarEnergeticParticle>>>, ApiError> {
+    let (start, end) = parse_donki_range(&query)?;
+    let mut service = service.lock().await;
+    let events = service.get_donki_events(DonkiEventType::Sep, start, end).await?;
+    Ok(Json(ApiResponse::success(events)))
+}
+
+/// GET /nasa/donki/ips?start_date=&end_date= - DONKI Interplanetary Shocks
+pub async fn get_donki_ips(
+    State(service): State<SharedNasaService>,
+    Query(query): Query<DonkiDateQuery>,
+) -> Result<Json<ApiResponse<Vec<InterplanetaryShock>>>, ApiError> {
+    let (start, end) = parse_donki_range(&query)?;
+    let mut service = service.lock().await;
+    let events = service.get_donki_events(DonkiEventType::Ips, start, end).await?;
+    Ok(Json(ApiResponse::success(events)))
+}
+
+/// GET /nasa/donki/hss?start_date=&end_date= - DONKI High Speed Streams
+pub async fn get_donki_hss(
+    State(service): State<SharedNasaService>,
+    Query(query): Query<DonkiDateQuery>,
+) -> Result<Json<ApiResponse<Vec<HighSpeedStream>>>, ApiError> {
+    let (start, end) = parse_donki_range(&query)?;
+    let mut service = service.lock().await;
+    let events = service.get_donki_events(DonkiEventType::Hss, start, end).await?;
+    Ok(Json(ApiResponse::success(events)))
+}
+
+/// GET /nasa/donki/rbe?start_date=&end_date= - DONKI Radiation Belt Enhancements
+pub async fn get_donki_rbe(
+    State(service): State<SharedNasaService>,
+    Query(query): Query<DonkiDateQuery>,
+) -> Result<Json<ApiResponse<Vec<RadiationBeltEnhancement>>>, ApiError> {
+    let (start, end) = parse_donki_range(&query)?;
+    let mut service = service.lock().await;
+    let events = service.get_donki_events(DonkiEventType::Rbe, start, end).await?;
+    Ok(Json(ApiResponse::success(events)))
+}
+
+/// GET /nasa/donki/notifications?start_date=&end_date= - Уведомления DONKI (отчёты, предупреждения)
+pub async fn get_donki_notifications(
+    State(service): State<SharedNasaService>,
+    Query(query): Query<DonkiDateQuery>,
+) -> Result<Json<ApiResponse<Vec<DonkiNotification>>>, ApiError> {
+    let (start, end) = parse_donki_range(&query)?;
+    let mut service = service.lock().await;
+    let events = service.get_donki_events(DonkiEventType::Notification, start, end).await?;
+    Ok(Json(ApiResponse::success(events)))
+}
+
+/// GET /nasa/donki/chains?start=&end= - Цепочки связанных событий DONKI (по умолчанию 30 дней)
+pub async fn get_donki_chains(
+    State(service): State<SharedNasaService>,
+    Query(query): Query<DonkiRangeQuery>,
+) -> Result<Json<ApiResponse<Vec<DonkiChain>>>, ApiError> {
+    let end = match query.end.as_deref() {
+        Some(end) => parse_date("end", end)?,
+        None => chrono::Utc::now().date_naive(),
+    };
+    let start = match query.start.as_deref() {
+        Some(start) => parse_date("start", start)?,
+        None => end - chrono::Duration::days(30),
+    };
+
+    let mut service = service.lock().await;
+    let chains = service.get_donki_chains(start, end).await?;
+    Ok(Json(ApiResponse::success(chains)))
+}
+
+/// GET /nasa/donki/:activity_id/related - Цепочка, в которую входит событие DONKI
+pub async fn get_donki_related(
+    State(service): State<SharedNasaService>,
+    Path(activity_id): Path<String>,
+) -> Result<Json<ApiResponse<DonkiChain>>, ApiError> {
+    let mut service = service.lock().await;
+    let chain = service.get_donki_related(&activity_id).await?;
+    Ok(Json(ApiResponse::success(chain)))
+}
+
+/// GET /nasa/space-weather/index?start=&end= - Дневной индекс космической погоды (по умолчанию 30 дней)
+pub async fn get_space_weather_index(
+    State(service): State<SharedNasaService>,
+    Query(query): Query<DonkiRangeQuery>,
+) -> Result<Json<ApiResponse<SpaceWeatherIndex>>, ApiError> {
+    let end = match query.end.as_deref() {
+        Some(end) => parse_date("end", end)?,
+        None => chrono::Utc::now().date_naive(),
+    };
+    let start = match query.start.as_deref() {
+        Some(start) => parse_date("start", start)?,
+        None => end - chrono::Duration::days(30),
+    };
+
+    let mut service = service.lock().await;
+    let index = service.get_space_weather_index(start, end).await?;
+    Ok(Json(ApiResponse::success(index)))
+}
+
+fn parse_range(query: &ApodRangeQuery) -> Result<(NaiveDate, NaiveDate), ApiError> {
+    let start = parse_date("start", query.start.as_deref().unwrap_or_default())?;
+    let end = match query.end.as_deref() {
+        Some(end) => parse_date("end", end)?,
+        None => apod_today(),
+    };
+    Ok((start, end))
+}
+
+/// start_date/end_date для списков DONKI (по умолчанию последние 30 дней)
+fn parse_donki_range(query: &DonkiDateQuery) -> Result<(NaiveDate, NaiveDate), ApiError> {
+    let end = match query.end_date.as_deref() {
+        Some(end) => parse_date("end_date", end)?,
+        None => chrono::Utc::now().date_naive(),
+    };
+    let start = match query.start_date.as_deref() {
+        Some(start) => parse_date("start_date", start)?,
+        None => end - chrono::Duration::days(DONKI_DEFAULT_DAYS),
+    };
+    Ok((start, end))
+}
+
+/// Дата запроса; chrono принимает годы до +262142, поэтому границы проверяются сразу,
+/// до вычисления умолчаний вроде start + 6 дней
+fn parse_date(field: &str, value: &str) -> Result<NaiveDate, ApiError> {
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: field.to_string(),
+            message: "Expected date in YYYY-MM-DD format".to_string(),
+        }])
+    })?;
+
+    let (first, last) = nasa_date_range();
+    if date < first || date > last {
+        return Err(ApiError::ValidationError(vec![ErrorDetail {
+            field: field.to_string(),
+            message: format!("Date must be between {} and {}", first, last),
+        }]));
+    }
+    Ok(date)
+}
@@ -1,44 +1,205 @@
 use crate::{
     domain::{
-        error::{ApiError, ApiResponse},
-        models::OsdrDataset,
+        citation::CitationFormat,
+        error::{ApiError, ApiResponse, ErrorDetail},
+        models::{
+            CreateWatchlistRequest, OsdrDataset, OsdrListQuery, OsdrSyncRun, OsdrSyncSource,
+            OsdrWatchlist, RelatedOsdrDataset, WatchNotification,
+        },
     },
-    services::OsdrService,
+    services::{osdr_service::LIST_LIMIT, OsdrService},
 };
-use axum::{extract::State, Json};
-use serde::Serialize;
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use validator::Validate;
 
 pub type SharedOsdrService = Arc<Mutex<OsdrService>>;
 
 #[derive(Serialize)]
 pub struct SyncResponse {
-    pub synced_count: usize,
+    pub run_id: i64,
+    pub inserted: i32,
+    pub updated: i32,
+    pub unchanged: i32,
+    pub failed: i32,
+    pub withdrawn: i32,
     pub message: String,
 }
 
+#[derive(Deserialize)]
+pub struct SyncRunsQuery {
+    pub limit: Option<i32>,
+}
+
+#[derive(Deserialize)]
+pub struct NotificationsQuery {
+    pub limit: Option<i32>,
+}
+
+#[derive(Deserialize)]
+pub struct RelatedQuery {
+    pub limit: Option<usize>,
+}
+
+#[derive(Deserialize)]
+pub struct CiteQuery {
+    #[serde(default)]
+    pub format: CitationFormat,
+}
+
+#[derive(Deserialize)]
+pub struct BulkCiteRequest {
+    pub accessions: Vec<String>,
+    #[serde(default)]
+    pub format: CitationFormat,
+}
+
 /// GET /osdr/sync - Синхронизация датасетов из NASA OSDR
 pub async fn sync_datasets(
     State(service): State<SharedOsdrService>,
 ) -> Result<Json<ApiResponse<SyncResponse>>, ApiError> {
-    let mut service = service.lock().await;
-    let count = service.sync_datasets().await?;
+    let (run, dispatcher) = {
+        let mut service = service.lock().await;
+        let run = service.sync_datasets(OsdrSyncSource::Manual).await?;
+        (run, service.notification_dispatcher())
+    };
+
+    // Вебхуки и SMTP доставляются без блокировки сервиса
+    if let Err(e) = dispatcher.deliver_pending().await {
+        tracing::warn!("Failed to deliver OSDR watchlist notifications: {}", e);
+    }
 
     let response = SyncResponse {
-        synced_count: count,
-        message: format!("Successfully synced {} datasets", count),
+        run_id: run.id,
+        inserted: run.inserted,
+        updated: run.updated,
+        unchanged: run.unchanged,
+        failed: run.failed,
+        withdrawn: run.withdrawn,
+        message: format!(
+            "Synced OSDR datasets: {} inserted, {} updated, {} unchanged, {} failed, {} withdrawn",
+            run.inserted, run.updated, run.unchanged, run.failed, run.withdrawn
+        ),
     };
 
     Ok(Json(ApiResponse::success(response)))
 }
 
-/// GET /osdr/list - Получить список датасетов
+/// GET /osdr/sync/runs - Журнал последних синхронизаций
+pub async fn list_sync_runs(
+    State(service): State<SharedOsdrService>,
+    Query(query): Query<SyncRunsQuery>,
+) -> Result<Json<ApiResponse<Vec<OsdrSyncRun>>>, ApiError> {
+    let service = service.lock().await;
+    let limit = query.limit.unwrap_or(20).clamp(1, 200);
+    let runs = service.get_sync_runs(limit).await?;
+
+    Ok(Json(ApiResponse::success(runs)))
+}
+
+/// GET /osdr/list - Получить список датасетов (?include_withdrawn=true - вместе с отозванными)
 pub async fn list_datasets(
     State(service): State<SharedOsdrService>,
+    Query(query): Query<OsdrListQuery>,
 ) -> Result<Json<ApiResponse<Vec<OsdrDataset>>>, ApiError> {
     let mut service = service.lock().await;
-    let datasets = service.get_all_datasets(50).await?;
+    let include_withdrawn = query.include_withdrawn.unwrap_or(false);
+    let datasets = service.get_all_datasets(LIST_LIMIT, include_withdrawn).await?;
 
     Ok(Json(ApiResponse::success(datasets)))
+}
+
+/// GET /osdr/:dataset_id/cite?format=bibtex|ris|csl-json - Цитирование датасета
+pub async fn cite_dataset(
+    State(service): State<SharedOsdrService>,
+    Path(dataset_id): Path<String>,
+    Query(query): Query<CiteQuery>,
+) -> Result<Response, ApiError> {
+    let service = service.lock().await;
+    let body = service.get_citations(&[dataset_id], query.format).await?;
+
+    Ok(([(header::CONTENT_TYPE, query.format.content_type())], body).into_response())
+}
+
+/// POST /osdr/cite - Цитирование списка датасетов одним документом
+pub async fn cite_datasets_bulk(
+    State(service): State<SharedOsdrService>,
+    Json(request): Json<BulkCiteRequest>,
+) -> Result<Response, ApiError> {
+    let service = service.lock().await;
+    let body = service.get_citations(&request.accessions, request.format).await?;
+
+    Ok(([(header::CONTENT_TYPE, request.format.content_type())], body).into_response())
+}
+
+/// GET /osdr/:dataset_id/related - Похожие датасеты (TF-IDF по заголовкам и описаниям)
+pub async fn get_related_datasets(
+    State(service): State<SharedOsdrService>,
+    Path(dataset_id): Path<String>,
+    Query(query): Query<RelatedQuery>,
+) -> Result<Json<ApiResponse<Vec<RelatedOsdrDataset>>>, ApiError> {
+    // Модель строится без блокировки сервиса
+    let related = service.lock().await.related_indexer();
+    let related = related.related(&dataset_id, query.limit.unwrap_or(10)).await?;
+
+    Ok(Json(ApiResponse::success(related)))
+}
+
+/// GET /osdr/watchlists - Список watchlist
+pub async fn list_watchlists(
+    State(service): State<SharedOsdrService>,
+) -> Result<Json<ApiResponse<Vec<OsdrWatchlist>>>, ApiError> {
+    let service = service.lock().await;
+    let watchlists = service.get_watchlists().await?;
+
+    Ok(Json(ApiResponse::success(watchlists)))
+}
+
+/// POST /osdr/watchlists - Создать watchlist по ключевым словам и организмам
+pub async fn create_watchlist(
+    State(service): State<SharedOsdrService>,
+    Json(request): Json<CreateWatchlistRequest>,
+) -> Result<Json<ApiResponse<OsdrWatchlist>>, ApiError> {
+    request.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "body".to_string(),
+            message: format!("Invalid watchlist: {}", e),
+        }])
+    })?;
+
+    let service = service.lock().await;
+    let watchlist = service.create_watchlist(request).await?;
+
+    Ok(Json(ApiResponse::success(watchlist)))
+}
+
+/// DELETE /osdr/watchlists/:id - Удалить watchlist
+pub async fn delete_watchlist(
+    State(service): State<SharedOsdrService>,
+    Path(id): Path<i64>,
+) -> Result<Json<ApiResponse<()>>, ApiError> {
+    let service = service.lock().await;
+    service.delete_watchlist(id).await?;
+
+    Ok(Json(ApiResponse::success(())))
+}
+
+/// GET /osdr/watchlists/:id/notifications - История уведомлений watchlist
+pub async fn list_watchlist_notifications(
+    State(service): State<SharedOsdrService>,
+    Path(id): Path<i64>,
+    Query(query): Query<NotificationsQuery>,
+) -> Result<Json<ApiResponse<Vec<WatchNotification>>>, ApiError> {
+    let service = service.lock().await;
+    let limit = query.limit.unwrap_or(50).clamp(1, 500);
+    let notifications = service.get_watchlist_notifications(id, limit).await?;
+
+    Ok(Json(ApiResponse::success(notifications)))
 }
\ No newline at end of file
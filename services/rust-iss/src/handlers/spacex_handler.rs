@@ -1,11 +1,17 @@
 use crate::{
-    domain::error::{ApiError, ApiResponse},
+    domain::{
+        error::{ApiError, ApiResponse, ErrorDetail},
+        models::{LaunchChangesQuery, LaunchScheduleChange, SpaceXLaunch, SpaceXLaunchDetails, SpaceXLaunchesQuery},
+    },
+    repo::spacex_repo::SpaceXLaunchFilter,
     services::SpaceXService,
 };
-use axum::{extract::State, Json};
+use axum::{extract::{Path, Query, State}, Json};
+use chrono::{Duration, Utc};
 use serde_json::Value;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use validator::Validate;
 
 pub type SharedSpaceXService = Arc<Mutex<SpaceXService>>;
 
@@ -16,4 +22,79 @@ pub async fn get_next_launch(
     let mut service = service.lock().await;
     let launch = service.get_next_launch().await?;
     Ok(Json(ApiResponse::success(launch)))
-}
\ No newline at end of file
+}
+
+/// GET /spacex/launches?upcoming=&rocket=&success=&from=&to=&limit= - Архив запусков из БД
+pub async fn list_launches(
+    State(service): State<SharedSpaceXService>,
+    Query(query): Query<SpaceXLaunchesQuery>,
+) -> Result<Json<ApiResponse<Vec<SpaceXLaunch>>>, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    if let (Some(from), Some(to)) = (query.from, query.to) {
+        if from > to {
+            return Err(ApiError::ValidationError(vec![ErrorDetail {
+                field: "from".to_string(),
+                message: "from must be before to".to_string(),
+            }]));
+        }
+    }
+
+    let filter = SpaceXLaunchFilter {
+        upcoming: query.upcoming,
+        rocket: query.rocket.as_deref(),
+        success: query.success,
+        from: query.from,
+        to: query.to,
+    };
+
+    let mut service = service.lock().await;
+    let launches = service.get_launches(&filter, query.limit.unwrap_or(100)).await?;
+    Ok(Json(ApiResponse::success(launches)))
+}
+
+/// GET /spacex/launches/:id - Запуск с ракетой, площадкой и полезными нагрузками
+pub async fn get_launch(
+    State(service): State<SharedSpaceXService>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<SpaceXLaunchDetails>>, ApiError> {
+    let mut service = service.lock().await;
+    let launch = service.get_launch(&id).await?;
+    Ok(Json(ApiResponse::success(launch)))
+}
+
+/// GET /spacex/launches/:id/slips - История переносов и смены статуса запуска
+pub async fn get_launch_slips(
+    State(service): State<SharedSpaceXService>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<Vec<LaunchScheduleChange>>>, ApiError> {
+    let mut service = service.lock().await;
+    let slips = service.get_launch_slips(&id).await?;
+    Ok(Json(ApiResponse::success(slips)))
+}
+
+/// GET /spacex/changes?since=&kind=slip|status&limit= - Лента переносов и смены статуса запусков
+pub async fn get_schedule_changes(
+    State(service): State<SharedSpaceXService>,
+    Query(query): Query<LaunchChangesQuery>,
+) -> Result<Json<ApiResponse<Vec<LaunchScheduleChange>>>, ApiError> {
+    query.validate().map_err(|e| {
+        ApiError::ValidationError(vec![ErrorDetail {
+            field: "query".to_string(),
+            message: format!("Invalid query parameters: {}", e),
+        }])
+    })?;
+
+    let since = query.since.unwrap_or_else(|| Utc::now() - Duration::days(7));
+
+    let mut service = service.lock().await;
+    let changes = service
+        .get_schedule_changes(since, query.kind, query.limit.unwrap_or(100))
+        .await?;
+    Ok(Json(ApiResponse::success(changes)))
+}
@@ -3,6 +3,7 @@ mod config;
 mod domain;
 mod handlers;
 mod middleware;
+mod notifiers;
 mod repo;
 mod routes;
 mod scheduler;
@@ -10,16 +11,23 @@ mod services;
 mod utils;
 
 use crate::{
-    clients::{IssClient, NasaClient, OsdrClient, JwstClient, SpaceXClient},
+    clients::{AstronomyClient, EonetClient, IssClient, MediaClient, NasaClient, OsdrClient, JwstClient, SpaceXClient},
     config::Config,
     middleware::create_rate_limiter,
-    repo::{cache_repo::CacheRepo, iss_repo::IssRepo, osdr_repo::OsdrRepo},
+    notifiers::{Notifier, SmtpNotifier, WebhookNotifier},
+    repo::{
+        apod_repo::ApodRepo, cache_repo::CacheRepo, donki_repo::DonkiRepo, eonet_repo::EonetRepo, iss_repo::IssRepo, jwst_repo::JwstRepo,
+        media_repo::MediaRepo, neo_repo::NeoRepo, osdr_repo::OsdrRepo, spacex_repo::SpaceXRepo, watchlist_repo::WatchlistRepo,
+    },
     routes::{create_router, AppState},
     scheduler::Scheduler,
-    services::{IssService, NasaService, OsdrService, JwstService, SpaceXService},
+    services::{
+        AstronomyService, EonetService, EpicService, IssService, MediaService, NasaService, OsdrService, JwstService,
+        SpaceXService,
+    },
 };
 use sqlx::postgres::PgPoolOptions;
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 use tokio::sync::Mutex;
 use tracing::info;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
@@ -72,15 +80,45 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Создание клиентов
     let iss_client = IssClient::new(config.where_iss_url.clone())?;
     let osdr_client = OsdrClient::new(config.nasa_api_url.clone(), config.nasa_api_key.clone())?;
-    let nasa_client = NasaClient::new(config.nasa_api_key.clone())?;
-    let jwst_client = JwstClient::new("https://api.jwstapi.com".to_string(), "".to_string())?;
+    let nasa_client = NasaClient::new(config.nasa_api_key.clone(), config.nasa_quota_reserve_percent)?;
+    let jwst_client = JwstClient::new(
+        config.jwst_api_url.clone(),
+        config.jwst_api_key.clone(),
+        config.jwst_product_url.clone(),
+    )?;
     let spacex_client = SpaceXClient::new()?;
+    let media_client = MediaClient::new()?;
+    let eonet_client = EonetClient::new()?;
+    let astronomy_client = AstronomyClient::new(
+        config.astro_api_url.clone(),
+        config.astro_app_id.clone(),
+        config.astro_app_secret.clone(),
+    )?;
+    if !astronomy_client.is_configured() {
+        info!("AstronomyAPI credentials not set, /astro/events and /astro/positions are disabled");
+    }
 
     // Создание репозиториев
     let iss_repo = IssRepo::new(pg_pool.clone());
     let osdr_repo = OsdrRepo::new(pg_pool.clone());
+    let watchlist_repo = WatchlistRepo::new(pg_pool.clone());
+    let apod_repo = ApodRepo::new(pg_pool.clone());
     let cache_repo = CacheRepo::new(&config.redis_url)?;
 
+    // Каналы доставки уведомлений watchlist
+    let mut notifiers: Vec<Box<dyn Notifier>> = vec![Box::new(WebhookNotifier::new(config.webhook_allow_private_targets)?)];
+    if let Some(smtp_host) = &config.smtp_host {
+        let credentials = config.smtp_username.clone().zip(config.smtp_password.clone());
+        notifiers.push(Box::new(SmtpNotifier::new(
+            smtp_host,
+            config.smtp_port,
+            config.smtp_starttls,
+            credentials,
+            &config.smtp_from,
+        )?));
+        info!("SMTP notifier enabled ({}:{})", smtp_host, config.smtp_port);
+    }
+
     // Создание сервисов
     let iss_service = Arc::new(Mutex::new(IssService::new(
         iss_client,
@@ -91,24 +129,61 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let osdr_service = Arc::new(Mutex::new(OsdrService::new(
         osdr_client,
         osdr_repo,
+        watchlist_repo,
         cache_repo.clone(),
+        notifiers,
+        config.upstream_fallbacks,
+    )));
+
+    let epic_service = Arc::new(Mutex::new(EpicService::new(
+        nasa_client.clone(),
+        cache_repo.clone(),
+    )));
+
+    let eonet_service = Arc::new(Mutex::new(EonetService::new(
+        eonet_client,
+        EonetRepo::new(pg_pool.clone()),
+        IssRepo::new(pg_pool.clone()),
     )));
 
     let nasa_service = Arc::new(Mutex::new(NasaService::new(
         nasa_client,
+        apod_repo,
+        MediaRepo::new(pg_pool.clone()),
+        NeoRepo::new(pg_pool.clone()),
+        DonkiRepo::new(pg_pool.clone()),
         cache_repo.clone(),
     )));
 
     let jwst_service = Arc::new(Mutex::new(JwstService::new(
         jwst_client,
+        JwstRepo::new(pg_pool.clone()),
+        MediaRepo::new(pg_pool.clone()),
         cache_repo.clone(),
+        config.upstream_fallbacks,
+        config.jwst_tracked_programs.clone(),
     )));
 
     let spacex_service = Arc::new(Mutex::new(SpaceXService::new(
         spacex_client,
+        SpaceXRepo::new(pg_pool.clone()),
         cache_repo.clone(),
     )));
 
+    let astronomy_service = Arc::new(Mutex::new(AstronomyService::new(
+        astronomy_client,
+        cache_repo.clone(),
+    )));
+
+    let media_service = Arc::new(Mutex::new(MediaService::new(
+        media_client,
+        MediaRepo::new(pg_pool.clone()),
+        PathBuf::from(&config.media_dir),
+        config.media_quota_mb,
+        config.media_max_download_mb,
+        config.media_thumb_size,
+    )));
+
     // Создание rate limiter
     let rate_limiter = create_rate_limiter(config.rate_limit_per_minute);
 
@@ -119,7 +194,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         iss_service.clone(),
         osdr_service.clone(),
         nasa_service.clone(),
+        epic_service.clone(),
+        eonet_service.clone(),
+        jwst_service.clone(),
         spacex_service.clone(),
+        media_service.clone(),
     ));
     scheduler.start();
 
@@ -128,8 +207,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         iss_service,
         osdr_service,
         nasa_service,
+        epic_service,
+        eonet_service,
         jwst_service,
+        media_service,
         spacex_service,
+        astronomy_service,
         rate_limiter,
     };
 
@@ -179,6 +262,415 @@ async fn init_database(pool: &sqlx::PgPool) -> Result<(), Box<dyn std::error::Er
     .execute(pool)
     .await?;
 
+    // Tombstone для датасетов, пропавших из каталога OSDR
+    sqlx::query("ALTER TABLE osdr_items ADD COLUMN IF NOT EXISTS withdrawn_at TIMESTAMPTZ")
+        .execute(pool)
+        .await?;
+
+    // DOI датасета для экспорта цитирований
+    sqlx::query("ALTER TABLE osdr_items ADD COLUMN IF NOT EXISTS doi TEXT")
+        .execute(pool)
+        .await?;
+
+    // Журнал синхронизаций OSDR
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS osdr_sync_runs (
+            id BIGSERIAL PRIMARY KEY,
+            source TEXT NOT NULL,
+            started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            finished_at TIMESTAMPTZ,
+            inserted INTEGER NOT NULL DEFAULT 0,
+            updated INTEGER NOT NULL DEFAULT 0,
+            unchanged INTEGER NOT NULL DEFAULT 0,
+            failed INTEGER NOT NULL DEFAULT 0,
+            withdrawn INTEGER NOT NULL DEFAULT 0,
+            error TEXT
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Watchlist по ключевым словам и организмам
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS osdr_watchlists (
+            id BIGSERIAL PRIMARY KEY,
+            name TEXT NOT NULL,
+            keywords TEXT[] NOT NULL DEFAULT '{}',
+            organisms TEXT[] NOT NULL DEFAULT '{}',
+            channel TEXT NOT NULL,
+            target TEXT NOT NULL,
+            created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS osdr_watch_notifications (
+            id BIGSERIAL PRIMARY KEY,
+            watchlist_id BIGINT NOT NULL REFERENCES osdr_watchlists(id) ON DELETE CASCADE,
+            dataset_id TEXT NOT NULL,
+            title TEXT NOT NULL,
+            change TEXT NOT NULL,
+            matched_terms TEXT[] NOT NULL DEFAULT '{}',
+            created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            delivered_at TIMESTAMPTZ,
+            attempts INTEGER NOT NULL DEFAULT 0,
+            delivery_error TEXT
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS apod_entries (
+            id SERIAL PRIMARY KEY,
+            date DATE NOT NULL UNIQUE,
+            title TEXT NOT NULL,
+            explanation TEXT NOT NULL,
+            url TEXT NOT NULL,
+            hdurl TEXT,
+            media_type TEXT NOT NULL,
+            fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS neo_close_approaches (
+            id BIGSERIAL PRIMARY KEY,
+            neo_id TEXT NOT NULL,
+            name TEXT NOT NULL,
+            is_potentially_hazardous BOOLEAN NOT NULL,
+            is_sentry_object BOOLEAN NOT NULL DEFAULT FALSE,
+            absolute_magnitude_h DOUBLE PRECISION,
+            diameter_min_km DOUBLE PRECISION,
+            diameter_max_km DOUBLE PRECISION,
+            approach_date DATE NOT NULL,
+            approach_at TIMESTAMPTZ NOT NULL,
+            relative_velocity_kps DOUBLE PRECISION NOT NULL,
+            miss_distance_km DOUBLE PRECISION NOT NULL,
+            miss_distance_lunar DOUBLE PRECISION NOT NULL,
+            miss_distance_au DOUBLE PRECISION NOT NULL,
+            orbiting_body TEXT NOT NULL,
+            fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            UNIQUE (neo_id, approach_at, orbiting_body)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS neo_objects (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            payload JSONB NOT NULL,
+            fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Прошедшие недели NeoWs загружаются один раз (окна с понедельника по воскресенье)
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS neo_synced_windows (
+            window_start DATE PRIMARY KEY,
+            fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS donki_events (
+            activity_id TEXT PRIMARY KEY,
+            event_type TEXT NOT NULL,
+            start_time TIMESTAMPTZ NOT NULL,
+            linked_ids TEXT[] NOT NULL DEFAULT '{}',
+            payload JSONB NOT NULL,
+            fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS donki_synced_days (
+            event_type TEXT NOT NULL,
+            day DATE NOT NULL,
+            fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            PRIMARY KEY (event_type, day)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS eonet_events (
+            id TEXT PRIMARY KEY,
+            title TEXT NOT NULL,
+            description TEXT,
+            category TEXT NOT NULL,
+            link TEXT,
+            closed_at TIMESTAMPTZ,
+            sources TEXT[] NOT NULL DEFAULT '{}',
+            fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS eonet_geometries (
+            event_id TEXT NOT NULL REFERENCES eonet_events(id) ON DELETE CASCADE,
+            observed_at TIMESTAMPTZ NOT NULL,
+            latitude DOUBLE PRECISION NOT NULL,
+            longitude DOUBLE PRECISION NOT NULL,
+            magnitude_value DOUBLE PRECISION,
+            magnitude_unit TEXT,
+            PRIMARY KEY (event_id, observed_at)
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS jwst_images (
+            id BIGSERIAL,
+            observation_id TEXT PRIMARY KEY,
+            program TEXT NOT NULL,
+            title TEXT NOT NULL,
+            description TEXT,
+            image_url TEXT NOT NULL,
+            thumbnail_url TEXT,
+            file_type TEXT NOT NULL,
+            suffix TEXT,
+            instrument TEXT,
+            filters TEXT[] NOT NULL DEFAULT '{}',
+            observation_date TIMESTAMP,
+            updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Когда наблюдение впервые попало в каталог (для /jwst/new)
+    sqlx::query("ALTER TABLE jwst_images ADD COLUMN IF NOT EXISTS first_seen_at TIMESTAMP NOT NULL DEFAULT NOW()")
+        .execute(pool)
+        .await?;
+
+    // Ключевые поля FITS-заголовка продукта (инструмент, фильтр, экспозиция, цель, WCS)
+    sqlx::query("ALTER TABLE jwst_images ADD COLUMN IF NOT EXISTS fits JSONB")
+        .execute(pool)
+        .await?;
+
+    // Плановые обновления программ JWST: начало последнего - граница /jwst/new для всех экземпляров
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS jwst_refresh_runs (
+            started_at TIMESTAMPTZ PRIMARY KEY,
+            finished_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            new_images BIGINT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS spacex_rockets (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            rocket_type TEXT,
+            active BOOLEAN NOT NULL DEFAULT FALSE,
+            stages INTEGER,
+            cost_per_launch BIGINT,
+            success_rate_pct INTEGER,
+            first_flight DATE,
+            country TEXT,
+            company TEXT,
+            description TEXT,
+            updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS spacex_launchpads (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            full_name TEXT,
+            locality TEXT,
+            region TEXT,
+            latitude DOUBLE PRECISION,
+            longitude DOUBLE PRECISION,
+            status TEXT,
+            timezone TEXT,
+            launch_attempts INTEGER NOT NULL DEFAULT 0,
+            launch_successes INTEGER NOT NULL DEFAULT 0,
+            updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS spacex_launches (
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            flight_number INTEGER NOT NULL,
+            date_utc TIMESTAMPTZ NOT NULL,
+            date_precision TEXT NOT NULL,
+            upcoming BOOLEAN NOT NULL,
+            success BOOLEAN,
+            tbd BOOLEAN NOT NULL DEFAULT FALSE,
+            net BOOLEAN NOT NULL DEFAULT FALSE,
+            rocket_id TEXT,
+            launchpad_id TEXT,
+            payload_ids TEXT[] NOT NULL DEFAULT '{}',
+            details TEXT,
+            patch_url TEXT,
+            webcast_url TEXT,
+            failure_reasons TEXT[] NOT NULL DEFAULT '{}',
+            updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS spacex_payloads (
+            id TEXT PRIMARY KEY,
+            name TEXT,
+            payload_type TEXT,
+            launch_id TEXT,
+            customers TEXT[] NOT NULL DEFAULT '{}',
+            mass_kg DOUBLE PRECISION,
+            orbit TEXT,
+            regime TEXT,
+            reused BOOLEAN NOT NULL DEFAULT FALSE,
+            updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Переносы и смена статуса запусков между прогонами синхронизации
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS launch_schedule_history (
+            id BIGSERIAL PRIMARY KEY,
+            launch_id TEXT NOT NULL,
+            launch_name TEXT NOT NULL,
+            kind TEXT NOT NULL,
+            previous_date_utc TIMESTAMPTZ NOT NULL,
+            date_utc TIMESTAMPTZ NOT NULL,
+            slip_seconds BIGINT NOT NULL,
+            previous_precision TEXT NOT NULL,
+            date_precision TEXT NOT NULL,
+            previous_tbd BOOLEAN NOT NULL,
+            tbd BOOLEAN NOT NULL,
+            previous_net BOOLEAN NOT NULL,
+            net BOOLEAN NOT NULL,
+            previous_status TEXT NOT NULL,
+            status TEXT NOT NULL,
+            detected_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS media_objects (
+            hash TEXT PRIMARY KEY,
+            content_type TEXT NOT NULL,
+            width INTEGER NOT NULL,
+            height INTEGER NOT NULL,
+            bytes BIGINT NOT NULL,
+            created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            last_accessed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS media_sources (
+            url TEXT PRIMARY KEY,
+            origin TEXT NOT NULL,
+            hash TEXT REFERENCES media_objects(hash) ON DELETE SET NULL,
+            requested_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+            mirrored_at TIMESTAMPTZ,
+            attempts INTEGER NOT NULL DEFAULT 0,
+            last_error TEXT,
+            evicted_at TIMESTAMPTZ
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Вытесненные по квоте URL остаются в таблице, чтобы не скачиваться заново
+    sqlx::query("ALTER TABLE media_sources ADD COLUMN IF NOT EXISTS evicted_at TIMESTAMPTZ")
+        .execute(pool)
+        .await?;
+
+    sqlx::query(
+        r#"
+        DO $$
+        BEGIN
+            IF EXISTS (
+                SELECT 1 FROM pg_constraint
+                WHERE conname = 'media_sources_hash_fkey' AND confdeltype = 'c'
+            ) THEN
+                ALTER TABLE media_sources DROP CONSTRAINT media_sources_hash_fkey;
+                ALTER TABLE media_sources ADD CONSTRAINT media_sources_hash_fkey
+                    FOREIGN KEY (hash) REFERENCES media_objects(hash) ON DELETE SET NULL;
+            END IF;
+        END $$
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
     // Индексы
     sqlx::query("CREATE INDEX IF NOT EXISTS idx_iss_timestamp ON iss_fetch_log(timestamp DESC)")
         .execute(pool)
@@ -192,6 +684,74 @@ async fn init_database(pool: &sqlx::PgPool) -> Result<(), Box<dyn std::error::Er
         .execute(pool)
         .await?;
 
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_osdr_withdrawn_at ON osdr_items(withdrawn_at) WHERE withdrawn_at IS NOT NULL")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_osdr_sync_runs_started_at ON osdr_sync_runs(started_at DESC)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_osdr_watch_notifications_watchlist ON osdr_watch_notifications(watchlist_id, created_at DESC)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_osdr_watch_notifications_pending ON osdr_watch_notifications(created_at) WHERE delivered_at IS NULL")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_neo_close_approaches_date ON neo_close_approaches(approach_date)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_donki_events_type_time ON donki_events(event_type, start_time)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_donki_events_linked_ids ON donki_events USING GIN (linked_ids)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_eonet_events_category ON eonet_events(category, closed_at)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_jwst_images_program ON jwst_images(program, updated_at DESC)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_jwst_images_first_seen ON jwst_images(first_seen_at DESC)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_spacex_launches_date ON spacex_launches(date_utc DESC)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_spacex_launches_rocket ON spacex_launches(rocket_id, date_utc DESC)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_spacex_payloads_launch ON spacex_payloads(launch_id)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_launch_schedule_history_launch ON launch_schedule_history(launch_id, detected_at)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_launch_schedule_history_detected ON launch_schedule_history(detected_at DESC)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_media_objects_lru ON media_objects(last_accessed_at)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_media_sources_pending ON media_sources(requested_at) WHERE hash IS NULL")
+        .execute(pool)
+        .await?;
+
     info!("Database initialized successfully");
     Ok(())
 }
@@ -1,7 +1,9 @@
 pub mod metrics;
+pub mod provenance;
 pub mod rate_limit;
 pub mod request_id;
 
 pub use metrics::metrics_middleware;
+pub use provenance::provenance_middleware;
 pub use rate_limit::{create_rate_limiter, rate_limit_middleware, SharedRateLimiter};
 pub use request_id::request_id_middleware;
\ No newline at end of file
@@ -0,0 +1,7 @@
+use crate::domain::provenance;
+use axum::{extract::Request, middleware::Next, response::Response};
+
+/// Собирает происхождение данных запроса для поля meta в ApiResponse
+pub async fn provenance_middleware(request: Request, next: Next) -> Response {
+    provenance::scope(next.run(request)).await
+}
@@ -0,0 +1,61 @@
+pub mod smtp;
+pub mod webhook;
+
+pub use smtp::SmtpNotifier;
+pub use webhook::WebhookNotifier;
+
+use crate::domain::models::NotifyChannel;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Уведомление о новом или изменённом датасете, совпавшем с watchlist
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationMessage {
+    pub watchlist_id: i64,
+    pub watchlist_name: String,
+    pub dataset_id: String,
+    pub title: String,
+    pub change: String,
+    pub matched_terms: Vec<String>,
+    pub url: String,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl NotificationMessage {
+    pub fn subject(&self) -> String {
+        format!(
+            "[OSDR watchlist] {}: {} {}",
+            self.watchlist_name, self.dataset_id, self.change
+        )
+    }
+
+    pub fn text_body(&self) -> String {
+        format!(
+            "Watchlist \"{}\" matched an OSDR dataset.\n\n\
+             Dataset: {}\nTitle: {}\nChange: {}\nMatched: {}\nLink: {}\nDetected at: {}\n",
+            self.watchlist_name,
+            self.dataset_id,
+            self.title,
+            self.change,
+            self.matched_terms.join(", "),
+            self.url,
+            self.detected_at.to_rfc3339(),
+        )
+    }
+}
+
+/// Канал доставки уведомлений watchlist
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    fn channel(&self) -> NotifyChannel;
+
+    /// Проверить цель при создании watchlist, до первой доставки
+    fn validate_target(&self, target: &str) -> Result<(), String>;
+
+    /// Доставить уведомление; `target` - URL вебхука или email-адрес из watchlist
+    async fn deliver(&self, target: &str, message: &NotificationMessage) -> Result<(), String>;
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,81 @@
+use super::{NotificationMessage, Notifier};
+use crate::domain::{error::ApiError, models::NotifyChannel};
+use async_trait::async_trait;
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+
+/// Доставка уведомлений по SMTP (в dev-окружении - в локальный mail catcher)
+pub struct SmtpNotifier {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpNotifier {
+    pub fn new(
+        host: &str,
+        port: u16,
+        starttls: bool,
+        credentials: Option<(String, String)>,
+        from: &str,
+    ) -> Result<Self, ApiError> {
+        let mut builder = if starttls {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+                .map_err(|e| ApiError::InternalError(format!("Invalid SMTP relay: {}", e)))?
+        } else {
+            // Без TLS: подходит для mail catcher (Mailpit, MailHog) в локальной сети
+            AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(host)
+        };
+        builder = builder.port(port);
+
+        if let Some((username, password)) = credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        let from = from
+            .parse::<Mailbox>()
+            .map_err(|e| ApiError::InternalError(format!("Invalid SMTP_FROM address: {}", e)))?;
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl Notifier for SmtpNotifier {
+    fn channel(&self) -> NotifyChannel {
+        NotifyChannel::Email
+    }
+
+    fn validate_target(&self, target: &str) -> Result<(), String> {
+        target
+            .parse::<Mailbox>()
+            .map(|_| ())
+            .map_err(|e| format!("Invalid recipient: {}", e))
+    }
+
+    async fn deliver(&self, target: &str, message: &NotificationMessage) -> Result<(), String> {
+        let to = target
+            .parse::<Mailbox>()
+            .map_err(|e| format!("Invalid recipient: {}", e))?;
+
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(message.subject())
+            .header(ContentType::TEXT_PLAIN)
+            .body(message.text_body())
+            .map_err(|e| format!("Failed to build email: {}", e))?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| format!("SMTP send failed: {}", e))?;
+
+        Ok(())
+    }
+}
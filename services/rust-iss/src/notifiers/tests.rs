@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    fn message() -> NotificationMessage {
+        NotificationMessage {
+            watchlist_id: 7,
+            watchlist_name: "Rodent research".to_string(),
+            dataset_id: "GLDS-379".to_string(),
+            title: "Rodent Research-1: bone loss".to_string(),
+            change: "inserted".to_string(),
+            matched_terms: vec!["rodent".to_string()],
+            url: "https://osdr.nasa.gov/bio/repo/data/studies/OSD-379".to_string(),
+            detected_at: Utc::now(),
+        }
+    }
+
+    /// Минимальный SMTP mail catcher: принимает одно письмо и возвращает протокол сессии
+    async fn spawn_mail_catcher() -> (u16, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let mut reader = BufReader::new(reader);
+            let mut transcript = String::new();
+            let mut in_data = false;
+
+            writer.write_all(b"220 localhost ESMTP\r\n").await.unwrap();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).await.unwrap() == 0 {
+                    break;
+                }
+                transcript.push_str(&line);
+
+                if in_data {
+                    if line == ".\r\n" {
+                        in_data = false;
+                        writer.write_all(b"250 OK\r\n").await.unwrap();
+                    }
+                    continue;
+                }
+
+                let command = line.to_uppercase();
+                if command.starts_with("QUIT") {
+                    writer.write_all(b"221 Bye\r\n").await.unwrap();
+                    break;
+                } else if command.starts_with("DATA") {
+                    in_data = true;
+                    writer.write_all(b"354 End data with <CR><LF>.<CR><LF>\r\n").await.unwrap();
+                } else {
+                    writer.write_all(b"250 OK\r\n").await.unwrap();
+                }
+            }
+            transcript
+        });
+
+        (port, handle)
+    }
+
+    #[test]
+    fn test_message_subject_and_body() {
+        let message = message();
+        assert_eq!(message.subject(), "[OSDR watchlist] Rodent research: GLDS-379 inserted");
+        assert!(message.text_body().contains("Matched: rodent"));
+    }
+
+    #[tokio::test]
+    async fn test_webhook_notifier_posts_json() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .match_header("content-type", "application/json")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"dataset_id": "GLDS-379", "change": "inserted"}"#.to_string(),
+            ))
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let notifier = WebhookNotifier::new(true).unwrap();
+        let result = notifier
+            .deliver(&format!("{}/hook", server.url()), &message())
+            .await;
+
+        assert!(result.is_ok());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_webhook_notifier_reports_http_errors() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("POST", "/hook").with_status(500).create_async().await;
+
+        let notifier = WebhookNotifier::new(true).unwrap();
+        let result = notifier
+            .deliver(&format!("{}/hook", server.url()), &message())
+            .await;
+
+        assert_eq!(result.unwrap_err(), "HTTP 500 Internal Server Error");
+    }
+
+    #[tokio::test]
+    async fn test_webhook_notifier_does_not_follow_redirects() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/hook")
+            .with_status(302)
+            .with_header("location", "http://169.254.169.254/latest/meta-data/")
+            .create_async()
+            .await;
+
+        let notifier = WebhookNotifier::new(true).unwrap();
+        let result = notifier
+            .deliver(&format!("{}/hook", server.url()), &message())
+            .await;
+
+        assert_eq!(result.unwrap_err(), "HTTP 302 Found");
+    }
+
+    #[tokio::test]
+    async fn test_webhook_notifier_rejects_internal_targets() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server.mock("POST", "/hook").expect(0).create_async().await;
+
+        let notifier = WebhookNotifier::new(false).unwrap();
+        for target in [
+            "http://127.0.0.1:8080/hook",
+            "http://redis:6379/",
+            "http://169.254.169.254/latest/meta-data/",
+            "http://10.0.0.5/hook",
+            "http://[::1]/hook",
+            "http://[::ffff:192.168.1.1]/hook",
+            "http://metadata.google.internal/",
+            "ftp://example.com/hook",
+        ] {
+            assert!(notifier.validate_target(target).is_err(), "{}", target);
+        }
+        assert!(notifier.validate_target("https://hooks.example.com/osdr").is_ok());
+
+        let result = notifier
+            .deliver(&format!("{}/hook", server.url()), &message())
+            .await;
+        assert_eq!(result.unwrap_err(), "Webhook URL points to an internal host");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_smtp_notifier_delivers_to_mail_catcher() {
+        let (port, catcher) = spawn_mail_catcher().await;
+
+        let notifier = SmtpNotifier::new("127.0.0.1", port, false, None, "osdr@localhost").unwrap();
+        let result = notifier.deliver("bio@example.com", &message()).await;
+        assert!(result.is_ok(), "{:?}", result);
+
+        let transcript = catcher.await.unwrap();
+        assert!(transcript.contains("MAIL FROM:<osdr@localhost>"));
+        assert!(transcript.contains("RCPT TO:<bio@example.com>"));
+        assert!(transcript.contains("Subject: [OSDR watchlist] Rodent research: GLDS-379 inserted"));
+    }
+}
@@ -0,0 +1,164 @@
+use super::{NotificationMessage, Notifier};
+use crate::domain::{error::ApiError, models::NotifyChannel};
+use async_trait::async_trait;
+use reqwest::{redirect, Client, ClientBuilder, Url};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::Duration;
+
+/// Доставка уведомлений POST-запросом с JSON телом.
+/// URL задаёт пользователь, поэтому внутренние адреса (сервисы compose, метаданные облака) запрещены
+pub struct WebhookNotifier {
+    allow_private_targets: bool,
+}
+
+impl WebhookNotifier {
+    /// `allow_private_targets` - разрешить адреса локальной сети (только для dev-окружения)
+    pub fn new(allow_private_targets: bool) -> Result<Self, ApiError> {
+        // Проверяем настройки клиента при старте, а не при первой доставке
+        client_builder()
+            .build()
+            .map_err(|e| ApiError::InternalError(format!("Failed to create HTTP client: {}", e)))?;
+
+        Ok(Self { allow_private_targets })
+    }
+
+    /// Клиент с адресом, проверенным после DNS: повторное разрешение имени не подменит цель
+    async fn pinned_client(&self, url: &Url) -> Result<Client, String> {
+        let port = url.port_or_known_default().ok_or("Webhook URL has no port")?;
+        let (host, literal_ip) = target_host(url)?;
+        let addrs: Vec<SocketAddr> = match literal_ip {
+            Some(ip) => vec![SocketAddr::new(ip, port)],
+            None => tokio::net::lookup_host((host, port))
+                .await
+                .map_err(|e| format!("DNS lookup failed for {}: {}", host, e))?
+                .collect(),
+        };
+
+        if !self.allow_private_targets {
+            if let Some(addr) = addrs.iter().find(|a| is_internal_ip(a.ip())) {
+                return Err(format!("Webhook target resolves to internal address {}", addr.ip()));
+            }
+        }
+
+        let addr = addrs.first().ok_or("Webhook host has no addresses")?;
+        let mut builder = client_builder();
+        if literal_ip.is_none() {
+            builder = builder.resolve(host, *addr);
+        }
+        builder
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    fn channel(&self) -> NotifyChannel {
+        NotifyChannel::Webhook
+    }
+
+    fn validate_target(&self, target: &str) -> Result<(), String> {
+        parse_target(target, self.allow_private_targets).map(|_| ())
+    }
+
+    async fn deliver(&self, target: &str, message: &NotificationMessage) -> Result<(), String> {
+        let url = parse_target(target, self.allow_private_targets)?;
+        let client = self.pinned_client(&url).await?;
+
+        let response = client
+            .post(url)
+            .json(message)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()));
+        }
+
+        Ok(())
+    }
+}
+
+fn client_builder() -> ClientBuilder {
+    Client::builder()
+        .timeout(Duration::from_secs(10))
+        .user_agent("CassiopeiaBot/1.0 (Space Data Collector)")
+        // Редирект мог бы увести запрос на внутренний адрес в обход проверки
+        .redirect(redirect::Policy::none())
+}
+
+/// Разобрать URL вебхука и отклонить внутренние цели, видимые без DNS (литералы IP, локальные имена)
+fn parse_target(target: &str, allow_private_targets: bool) -> Result<Url, String> {
+    let url = Url::parse(target).map_err(|e| format!("Invalid URL: {}", e))?;
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err("Webhook URL must use http or https".to_string());
+    }
+
+    let internal = match target_host(&url)? {
+        (_, Some(ip)) => is_internal_ip(ip),
+        (host, None) => is_internal_hostname(host),
+    };
+    if internal && !allow_private_targets {
+        return Err("Webhook URL points to an internal host".to_string());
+    }
+
+    Ok(url)
+}
+
+/// Хост URL и IP, если хост задан литералом (IPv6 - в квадратных скобках)
+fn target_host(url: &Url) -> Result<(&str, Option<IpAddr>), String> {
+    let host = url.host_str().ok_or("Webhook URL has no host")?;
+    let ip = host
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse::<IpAddr>()
+        .ok();
+    Ok((host, ip))
+}
+
+/// Имена без точки (redis, postgres - сервисы compose) и зарезервированные для локальных сетей зоны
+fn is_internal_hostname(host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    !host.contains('.')
+        || [".localhost", ".local", ".internal", ".lan", ".home.arpa"]
+            .iter()
+            .any(|zone| host.ends_with(zone))
+}
+
+/// Loopback, частные, link-local, CGNAT и прочие не маршрутизируемые в интернете адреса
+fn is_internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_internal_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_internal_ipv4(mapped),
+            None => is_internal_ipv6(ip),
+        },
+    }
+}
+
+fn is_internal_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        || a == 0
+        || a >= 240
+        || (a == 100 && (64..128).contains(&b))
+        || (a == 198 && (b == 18 || b == 19))
+        || (a == 192 && b == 0 && c == 0)
+}
+
+fn is_internal_ipv6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80
+        || (first == 0x2001 && ip.segments()[1] == 0x0db8)
+}
@@ -0,0 +1,96 @@
+use crate::domain::{
+    error::ApiError,
+    models::{ApodApiEntry, ApodEntry},
+};
+use chrono::NaiveDate;
+use sqlx::{PgPool, Row};
+
+pub struct ApodRepo {
+    pool: PgPool,
+}
+
+impl ApodRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Сохранить запись APOD (UPSERT по дате)
+    pub async fn upsert(&self, entry: &ApodApiEntry) -> Result<ApodEntry, ApiError> {
+        let stored = sqlx::query_as::<_, ApodEntry>(
+            r#"
+            INSERT INTO apod_entries (date, title, explanation, url, hdurl, media_type, fetched_at)
+            VALUES ($1, $2, $3, $4, $5, $6, NOW())
+            ON CONFLICT (date) DO UPDATE SET
+                title = EXCLUDED.title,
+                explanation = EXCLUDED.explanation,
+                url = EXCLUDED.url,
+                hdurl = EXCLUDED.hdurl,
+                media_type = EXCLUDED.media_type,
+                fetched_at = EXCLUDED.fetched_at
+            RETURNING id, date, title, explanation, url, hdurl, media_type, fetched_at
+            "#
+        )
+        .bind(entry.date)
+        .bind(&entry.title)
+        .bind(&entry.explanation)
+        .bind(&entry.url)
+        .bind(&entry.hdurl)
+        .bind(&entry.media_type)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(stored)
+    }
+
+    /// Получить запись APOD за дату
+    pub async fn get_by_date(&self, date: NaiveDate) -> Result<Option<ApodEntry>, ApiError> {
+        let entry = sqlx::query_as::<_, ApodEntry>(
+            r#"
+            SELECT id, date, title, explanation, url, hdurl, media_type, fetched_at
+            FROM apod_entries
+            WHERE date = $1
+            "#
+        )
+        .bind(date)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    /// Получить записи APOD за диапазон дат (включительно)
+    pub async fn get_range(&self, start: NaiveDate, end: NaiveDate) -> Result<Vec<ApodEntry>, ApiError> {
+        let entries = sqlx::query_as::<_, ApodEntry>(
+            r#"
+            SELECT id, date, title, explanation, url, hdurl, media_type, fetched_at
+            FROM apod_entries
+            WHERE date BETWEEN $1 AND $2
+            ORDER BY date
+            "#
+        )
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    /// Даты диапазона, для которых в архиве нет записи
+    pub async fn get_missing_dates(&self, start: NaiveDate, end: NaiveDate) -> Result<Vec<NaiveDate>, ApiError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT d::date AS date
+            FROM generate_series($1::date, $2::date, INTERVAL '1 day') AS d
+            WHERE NOT EXISTS (SELECT 1 FROM apod_entries a WHERE a.date = d::date)
+            ORDER BY d
+            "#
+        )
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.get("date")).collect())
+    }
+}
@@ -1,3 +1,5 @@
+use crate::domain::{models::CachedData, provenance};
+use chrono::Utc;
 use redis::aio::Connection;
 use redis::{Client, RedisError};
 use serde::{Deserialize, Serialize};
@@ -17,7 +19,7 @@ impl CacheRepo {
         self.client.get_async_connection().await
     }
 
-    /// Получить значение из кэша
+    /// Получить значение из кэша (возраст записи попадает в meta ответа)
     pub async fn get<T: for<'de> Deserialize<'de>>(
         &self,
         key: &str,
@@ -28,7 +30,12 @@ impl CacheRepo {
         match value {
             Some(json) => {
                 crate::utils::metrics::record_cache_hit(key);
-                Ok(serde_json::from_str(&json).ok())
+                // Записи старого формата (без cached_at) считаем промахом
+                let cached = serde_json::from_str::<CachedData<T>>(&json).ok();
+                if let Some(cached) = &cached {
+                    provenance::record_cache_hit(cached.cached_at);
+                }
+                Ok(cached.map(|c| c.data))
             },
             None => {
                 crate::utils::metrics::record_cache_miss(key);
@@ -45,7 +52,11 @@ impl CacheRepo {
         ttl_seconds: usize,
     ) -> Result<(), RedisError> {
         let mut conn = self.get_connection().await?;
-        let json = serde_json::to_string(value).unwrap();
+        let json = serde_json::to_string(&CachedData {
+            data: value,
+            cached_at: Utc::now(),
+        })
+        .unwrap();
 
         redis::cmd("SETEX")
             .arg(key)
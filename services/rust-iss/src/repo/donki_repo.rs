@@ -0,0 +1,163 @@
+use crate::domain::{
+    error::ApiError,
+    models::{DonkiEvent, DonkiEventType},
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{PgPool, Row};
+
+pub struct DonkiRepo {
+    pool: PgPool,
+}
+
+impl DonkiRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Сохранить события (UPSERT по activity_id)
+    pub async fn upsert_events(&self, events: &[DonkiEvent]) -> Result<usize, ApiError> {
+        for event in events {
+            sqlx::query(
+                r#"
+                INSERT INTO donki_events (activity_id, event_type, start_time, linked_ids, payload, fetched_at)
+                VALUES ($1, $2, $3, $4, $5, NOW())
+                ON CONFLICT (activity_id) DO UPDATE SET
+                    event_type = EXCLUDED.event_type,
+                    start_time = EXCLUDED.start_time,
+                    linked_ids = EXCLUDED.linked_ids,
+                    payload = EXCLUDED.payload,
+                    fetched_at = EXCLUDED.fetched_at
+                "#
+            )
+            .bind(&event.activity_id)
+            .bind(&event.event_type)
+            .bind(event.start_time)
+            .bind(&event.linked_ids)
+            .bind(&event.payload)
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(events.len())
+    }
+
+    /// События типа за период [start, end)
+    pub async fn get_events(
+        &self,
+        event_type: DonkiEventType,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<DonkiEvent>, ApiError> {
+        let events = sqlx::query_as::<_, DonkiEvent>(
+            r#"
+            SELECT activity_id, event_type, start_time, linked_ids, payload, fetched_at
+            FROM donki_events
+            WHERE event_type = $1 AND start_time >= $2 AND start_time < $3
+            ORDER BY start_time
+            "#
+        )
+        .bind(event_type.as_str())
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(events)
+    }
+
+    /// События с linkedEvents (все типы, кроме уведомлений) за период [start, end)
+    pub async fn get_linkable(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<DonkiEvent>, ApiError> {
+        let events = sqlx::query_as::<_, DonkiEvent>(
+            r#"
+            SELECT activity_id, event_type, start_time, linked_ids, payload, fetched_at
+            FROM donki_events
+            WHERE event_type <> 'NOTIFICATION' AND start_time >= $1 AND start_time < $2
+            ORDER BY start_time
+            "#
+        )
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(events)
+    }
+
+    /// События по списку activity_id
+    pub async fn get_by_ids(&self, ids: &[String]) -> Result<Vec<DonkiEvent>, ApiError> {
+        let events = sqlx::query_as::<_, DonkiEvent>(
+            r#"
+            SELECT activity_id, event_type, start_time, linked_ids, payload, fetched_at
+            FROM donki_events
+            WHERE activity_id = ANY($1)
+            "#
+        )
+        .bind(ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(events)
+    }
+
+    /// События, которые ссылаются на любой из указанных activity_id
+    pub async fn get_linking_to(&self, ids: &[String]) -> Result<Vec<DonkiEvent>, ApiError> {
+        let events = sqlx::query_as::<_, DonkiEvent>(
+            r#"
+            SELECT activity_id, event_type, start_time, linked_ids, payload, fetched_at
+            FROM donki_events
+            WHERE linked_ids && $1
+            "#
+        )
+        .bind(ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(events)
+    }
+
+    /// Отметить дни диапазона как загруженные из DONKI
+    pub async fn mark_synced(&self, event_type: DonkiEventType, start: NaiveDate, end: NaiveDate) -> Result<(), ApiError> {
+        sqlx::query(
+            r#"
+            INSERT INTO donki_synced_days (event_type, day, fetched_at)
+            SELECT $1, d::date, NOW()
+            FROM generate_series($2::date, $3::date, INTERVAL '1 day') AS d
+            ON CONFLICT (event_type, day) DO UPDATE SET fetched_at = EXCLUDED.fetched_at
+            "#
+        )
+        .bind(event_type.as_str())
+        .bind(start)
+        .bind(end)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Все дни диапазона загружены не раньше чем через settle_days после самого дня
+    pub async fn is_settled(
+        &self,
+        event_type: DonkiEventType,
+        start: NaiveDate,
+        end: NaiveDate,
+        settle_days: i32,
+    ) -> Result<bool, ApiError> {
+        let row = sqlx::query(
+            r#"
+            SELECT COUNT(*) = ($3::date - $2::date + 1) AS settled
+            FROM donki_synced_days
+            WHERE event_type = $1
+              AND day BETWEEN $2 AND $3
+              AND fetched_at >= (day + $4::int)::timestamptz
+            "#
+        )
+        .bind(event_type.as_str())
+        .bind(start)
+        .bind(end)
+        .bind(settle_days)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.get("settled"))
+    }
+}
@@ -0,0 +1,166 @@
+use crate::domain::{
+    error::ApiError,
+    models::{EonetEvent, EonetGeometry, EonetStatus},
+};
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgRow, PgPool, Row};
+use std::collections::HashMap;
+
+pub struct EonetRepo {
+    pool: PgPool,
+}
+
+impl EonetRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Сохранить события и их наблюдения (UPSERT по id и времени наблюдения)
+    pub async fn upsert_events(&self, events: &[EonetEvent]) -> Result<usize, ApiError> {
+        for event in events {
+            sqlx::query(
+                r#"
+                INSERT INTO eonet_events (id, title, description, category, link, closed_at, sources, fetched_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, NOW())
+                ON CONFLICT (id) DO UPDATE SET
+                    title = EXCLUDED.title,
+                    description = EXCLUDED.description,
+                    category = EXCLUDED.category,
+                    link = EXCLUDED.link,
+                    closed_at = EXCLUDED.closed_at,
+                    sources = EXCLUDED.sources,
+                    fetched_at = EXCLUDED.fetched_at
+                "#
+            )
+            .bind(&event.id)
+            .bind(&event.title)
+            .bind(&event.description)
+            .bind(&event.category)
+            .bind(&event.link)
+            .bind(event.closed_at)
+            .bind(&event.sources)
+            .execute(&self.pool)
+            .await?;
+
+            for geometry in &event.geometry {
+                sqlx::query(
+                    r#"
+                    INSERT INTO eonet_geometries
+                        (event_id, observed_at, latitude, longitude, magnitude_value, magnitude_unit)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    ON CONFLICT (event_id, observed_at) DO UPDATE SET
+                        latitude = EXCLUDED.latitude,
+                        longitude = EXCLUDED.longitude,
+                        magnitude_value = EXCLUDED.magnitude_value,
+                        magnitude_unit = EXCLUDED.magnitude_unit
+                    "#
+                )
+                .bind(&event.id)
+                .bind(geometry.observed_at)
+                .bind(geometry.latitude)
+                .bind(geometry.longitude)
+                .bind(geometry.magnitude_value)
+                .bind(&geometry.magnitude_unit)
+                .execute(&self.pool)
+                .await?;
+            }
+        }
+
+        Ok(events.len())
+    }
+
+    /// События с фильтром по категории и статусу, последние наблюдения первыми
+    pub async fn get_events(
+        &self,
+        category: Option<&str>,
+        status: EonetStatus,
+        limit: i64,
+    ) -> Result<Vec<EonetEvent>, ApiError> {
+        let status = match status {
+            EonetStatus::Open => "open",
+            EonetStatus::Closed => "closed",
+            EonetStatus::All => "all",
+        };
+
+        let rows = sqlx::query(
+            r#"
+            SELECT e.id, e.title, e.description, e.category, e.link, e.closed_at, e.sources
+            FROM eonet_events e
+            WHERE ($1::text IS NULL OR e.category = $1)
+              AND ($2 = 'all' OR ($2 = 'open') = (e.closed_at IS NULL))
+            ORDER BY (SELECT MAX(g.observed_at) FROM eonet_geometries g WHERE g.event_id = e.id) DESC NULLS LAST
+            LIMIT $3
+            "#
+        )
+        .bind(category)
+        .bind(status)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        self.with_geometry(rows).await
+    }
+
+    /// События, активные хотя бы частично в интервале [start, end]
+    pub async fn get_active_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<EonetEvent>, ApiError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT e.id, e.title, e.description, e.category, e.link, e.closed_at, e.sources
+            FROM eonet_events e
+            WHERE (e.closed_at IS NULL OR e.closed_at >= $1)
+              AND EXISTS (SELECT 1 FROM eonet_geometries g WHERE g.event_id = e.id AND g.observed_at <= $2)
+            "#
+        )
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await?;
+
+        self.with_geometry(rows).await
+    }
+
+    /// Собрать события из строк eonet_events и догрузить их наблюдения
+    async fn with_geometry(&self, rows: Vec<PgRow>) -> Result<Vec<EonetEvent>, ApiError> {
+        let ids: Vec<String> = rows.iter().map(|r| r.get("id")).collect();
+
+        let geometry_rows = sqlx::query(
+            r#"
+            SELECT event_id, observed_at, latitude, longitude, magnitude_value, magnitude_unit
+            FROM eonet_geometries
+            WHERE event_id = ANY($1)
+            ORDER BY observed_at
+            "#
+        )
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut geometry: HashMap<String, Vec<EonetGeometry>> = HashMap::new();
+        for r in geometry_rows {
+            geometry.entry(r.get("event_id")).or_default().push(EonetGeometry {
+                observed_at: r.get("observed_at"),
+                latitude: r.get("latitude"),
+                longitude: r.get("longitude"),
+                magnitude_value: r.get("magnitude_value"),
+                magnitude_unit: r.get("magnitude_unit"),
+            });
+        }
+
+        Ok(rows
+            .into_iter()
+            .map(|r| {
+                let id: String = r.get("id");
+                EonetEvent {
+                    geometry: geometry.remove(&id).unwrap_or_default(),
+                    id,
+                    title: r.get("title"),
+                    description: r.get("description"),
+                    category: r.get("category"),
+                    link: r.get("link"),
+                    closed_at: r.get("closed_at"),
+                    sources: r.get("sources"),
+                }
+            })
+            .collect())
+    }
+}
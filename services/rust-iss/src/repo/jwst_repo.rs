@@ -0,0 +1,174 @@
+use crate::domain::{
+    error::ApiError,
+    models::{FitsSummary, JwstImage},
+};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::PgPool;
+
+pub struct JwstRepo {
+    pool: PgPool,
+}
+
+/// Фильтры каталога JWST (регистр инструмента и фильтра не важен)
+#[derive(Debug, Default)]
+pub struct JwstImageFilter<'a> {
+    pub program: Option<&'a str>,
+    pub instrument: Option<&'a str>,
+    pub filter: Option<&'a str>,
+    pub suffix: Option<&'a str>,
+}
+
+impl JwstRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Сохранить файлы каталога (UPSERT по observation_id)
+    pub async fn upsert_images(&self, images: &[JwstImage]) -> Result<usize, ApiError> {
+        for image in images {
+            sqlx::query(
+                r#"
+                INSERT INTO jwst_images
+                    (observation_id, program, title, description, image_url, thumbnail_url, file_type,
+                     suffix, instrument, filters, observation_date, updated_at, first_seen_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, NOW(), $12)
+                ON CONFLICT (observation_id) DO UPDATE SET
+                    program = EXCLUDED.program,
+                    title = EXCLUDED.title,
+                    description = EXCLUDED.description,
+                    image_url = EXCLUDED.image_url,
+                    thumbnail_url = EXCLUDED.thumbnail_url,
+                    file_type = EXCLUDED.file_type,
+                    suffix = EXCLUDED.suffix,
+                    instrument = EXCLUDED.instrument,
+                    filters = EXCLUDED.filters,
+                    observation_date = COALESCE(EXCLUDED.observation_date, jwst_images.observation_date),
+                    updated_at = EXCLUDED.updated_at
+                "#
+            )
+            .bind(&image.observation_id)
+            .bind(&image.program)
+            .bind(&image.title)
+            .bind(&image.description)
+            .bind(&image.image_url)
+            .bind(&image.thumbnail_url)
+            .bind(&image.file_type)
+            .bind(&image.suffix)
+            .bind(&image.instrument)
+            .bind(&image.filters)
+            .bind(image.observation_date)
+            .bind(image.first_seen_at)
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(images.len())
+    }
+
+    /// Файлы каталога по фильтрам, свежие первыми
+    pub async fn get_images(&self, filter: &JwstImageFilter<'_>, limit: i64) -> Result<Vec<JwstImage>, ApiError> {
+        let images = sqlx::query_as::<_, JwstImage>(
+            r#"
+            SELECT id, observation_id, program, title, description, image_url, thumbnail_url, file_type,
+                   suffix, instrument, filters, observation_date, updated_at, first_seen_at, fits
+            FROM jwst_images
+            WHERE ($1::text IS NULL OR program = $1)
+              AND ($2::text IS NULL OR instrument = upper($2))
+              AND ($3::text IS NULL OR upper($3) = ANY(filters))
+              AND ($4::text IS NULL OR suffix = lower(trim(leading '_' from $4)))
+            ORDER BY updated_at DESC, observation_id
+            LIMIT $5
+            "#
+        )
+        .bind(filter.program)
+        .bind(filter.instrument)
+        .bind(filter.filter)
+        .bind(filter.suffix)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(images)
+    }
+
+    /// Сколько файлов программ впервые попало в каталог после since
+    pub async fn count_new_images(&self, programs: &[String], since: NaiveDateTime) -> Result<i64, ApiError> {
+        let count: (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM jwst_images WHERE program = ANY($1) AND first_seen_at >= $2"
+        )
+        .bind(programs)
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count.0)
+    }
+
+    /// Записать завершённое плановое обновление отслеживаемых программ
+    pub async fn record_refresh_run(&self, started_at: DateTime<Utc>, new_images: i64) -> Result<(), ApiError> {
+        sqlx::query(
+            r#"
+            INSERT INTO jwst_refresh_runs (started_at, finished_at, new_images)
+            VALUES ($1, NOW(), $2)
+            ON CONFLICT (started_at) DO NOTHING
+            "#
+        )
+        .bind(started_at)
+        .bind(new_images)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Начало последнего завершённого планового обновления
+    pub async fn get_last_refresh_at(&self) -> Result<Option<DateTime<Utc>>, ApiError> {
+        let row: (Option<DateTime<Utc>>,) = sqlx::query_as("SELECT MAX(started_at) FROM jwst_refresh_runs")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(row.0)
+    }
+
+    /// Файлы программ, впервые попавшие в каталог после since
+    pub async fn get_new_images(
+        &self,
+        programs: &[String],
+        since: NaiveDateTime,
+        limit: i64,
+    ) -> Result<Vec<JwstImage>, ApiError> {
+        let images = sqlx::query_as::<_, JwstImage>(
+            r#"
+            SELECT id, observation_id, program, title, description, image_url, thumbnail_url, file_type,
+                   suffix, instrument, filters, observation_date, updated_at, first_seen_at, fits
+            FROM jwst_images
+            WHERE program = ANY($1) AND first_seen_at >= $2
+            ORDER BY first_seen_at DESC, observation_id
+            LIMIT $3
+            "#
+        )
+        .bind(programs)
+        .bind(since)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(images)
+    }
+
+    /// Сохранить ключевые поля FITS у файлов каталога с тем же именем (без расширения)
+    pub async fn set_fits_summary(&self, file_stem: &str, summary: &FitsSummary) -> Result<u64, ApiError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE jwst_images SET fits = $2
+            WHERE regexp_replace(title, '\.[^.]*$', '') = $1
+            "#
+        )
+        .bind(file_stem)
+        .bind(sqlx::types::Json(summary))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
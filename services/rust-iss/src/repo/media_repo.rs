@@ -0,0 +1,226 @@
+use crate::domain::{error::ApiError, models::MediaObject};
+use sqlx::{PgPool, Row};
+
+#[derive(Clone)]
+pub struct MediaRepo {
+    pool: PgPool,
+}
+
+impl MediaRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Поставить URL в очередь на зеркалирование (повторный вызов ничего не меняет)
+    pub async fn enqueue(&self, url: &str, origin: &str) -> Result<(), ApiError> {
+        sqlx::query(
+            r#"
+            INSERT INTO media_sources (url, origin)
+            VALUES ($1, $2)
+            ON CONFLICT (url) DO NOTHING
+            "#
+        )
+        .bind(url)
+        .bind(origin)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// URL, ещё не скачанные, не вытесненные по квоте и не исчерпавшие попытки
+    pub async fn get_pending(&self, limit: i64, max_attempts: i32) -> Result<Vec<String>, ApiError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT url
+            FROM media_sources
+            WHERE hash IS NULL AND evicted_at IS NULL AND attempts < $2
+            ORDER BY requested_at
+            LIMIT $1
+            "#
+        )
+        .bind(limit)
+        .bind(max_attempts)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.get("url")).collect())
+    }
+
+    /// Зафиксировать неудачную попытку скачивания
+    pub async fn mark_failed(&self, url: &str, error: &str) -> Result<(), ApiError> {
+        sqlx::query(
+            r#"
+            UPDATE media_sources
+            SET attempts = attempts + 1, last_error = $2
+            WHERE url = $1
+            "#
+        )
+        .bind(url)
+        .bind(error)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Привязать URL к сохранённому объекту
+    pub async fn link_source(&self, url: &str, hash: &str) -> Result<(), ApiError> {
+        sqlx::query(
+            r#"
+            UPDATE media_sources
+            SET hash = $2, mirrored_at = NOW(), attempts = attempts + 1, last_error = NULL
+            WHERE url = $1
+            "#
+        )
+        .bind(url)
+        .bind(hash)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Сохранить метаданные объекта
+    pub async fn save_object(&self, object: &MediaObject) -> Result<(), ApiError> {
+        sqlx::query(
+            r#"
+            INSERT INTO media_objects (hash, content_type, width, height, bytes, created_at, last_accessed_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (hash) DO NOTHING
+            "#
+        )
+        .bind(&object.hash)
+        .bind(&object.content_type)
+        .bind(object.width)
+        .bind(object.height)
+        .bind(object.bytes)
+        .bind(object.created_at)
+        .bind(object.last_accessed_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Получить объект по хэшу
+    pub async fn get_object(&self, hash: &str) -> Result<Option<MediaObject>, ApiError> {
+        let object = sqlx::query_as::<_, MediaObject>(
+            r#"
+            SELECT hash, content_type, width, height, bytes, created_at, last_accessed_at
+            FROM media_objects
+            WHERE hash = $1
+            "#
+        )
+        .bind(hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(object)
+    }
+
+    /// Получить объект по исходному URL
+    pub async fn get_by_url(&self, url: &str) -> Result<Option<MediaObject>, ApiError> {
+        let object = sqlx::query_as::<_, MediaObject>(
+            r#"
+            SELECT o.hash, o.content_type, o.width, o.height, o.bytes, o.created_at, o.last_accessed_at
+            FROM media_sources s
+            JOIN media_objects o ON o.hash = s.hash
+            WHERE s.url = $1
+            "#
+        )
+        .bind(url)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(object)
+    }
+
+    /// Отметить обращение к объекту (для LRU)
+    pub async fn touch(&self, hash: &str) -> Result<(), ApiError> {
+        sqlx::query("UPDATE media_objects SET last_accessed_at = NOW() WHERE hash = $1")
+            .bind(hash)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Суммарный объём хранилища в байтах
+    pub async fn total_bytes(&self) -> Result<i64, ApiError> {
+        let row = sqlx::query("SELECT COALESCE(SUM(bytes), 0)::BIGINT AS total FROM media_objects")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(row.get("total"))
+    }
+
+    /// Давно не использованные объекты (hash, bytes), от самого старого
+    pub async fn get_least_recently_used(&self, limit: i64) -> Result<Vec<(String, i64)>, ApiError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT hash, bytes
+            FROM media_objects
+            ORDER BY last_accessed_at ASC
+            LIMIT $1
+            "#
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| (r.get("hash"), r.get("bytes"))).collect())
+    }
+
+    /// Хэши всех сохранённых объектов
+    pub async fn get_hashes(&self) -> Result<Vec<String>, ApiError> {
+        let rows = sqlx::query("SELECT hash FROM media_objects")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(rows.into_iter().map(|r| r.get("hash")).collect())
+    }
+
+    /// Забыть объект без файлов на диске: его URL возвращаются в очередь с нуля попыток
+    pub async fn forget_object(&self, hash: &str) -> Result<(), ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE media_sources
+            SET hash = NULL, mirrored_at = NULL, attempts = 0, last_error = NULL
+            WHERE hash = $1
+            "#
+        )
+        .bind(hash)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("DELETE FROM media_objects WHERE hash = $1")
+            .bind(hash)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Вытеснить объект по квоте: URL помечаются evicted_at и больше не попадают в очередь
+    pub async fn evict_object(&self, hash: &str) -> Result<(), ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("UPDATE media_sources SET evicted_at = NOW() WHERE hash = $1")
+            .bind(hash)
+            .execute(&mut *tx)
+            .await?;
+
+        // hash в media_sources обнуляется внешним ключом (ON DELETE SET NULL)
+        sqlx::query("DELETE FROM media_objects WHERE hash = $1")
+            .bind(hash)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
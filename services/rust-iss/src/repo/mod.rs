@@ -1,7 +1,16 @@
+pub mod apod_repo;
+pub mod donki_repo;
+pub mod eonet_repo;
 pub mod iss_repo;
+pub mod jwst_repo;
+pub mod media_repo;
+pub mod neo_repo;
 pub mod osdr_repo;
 pub mod cache_repo;
+pub mod spacex_repo;
+pub mod watchlist_repo;
 
 pub use iss_repo::IssRepo;
 pub use osdr_repo::OsdrRepo;
-pub use cache_repo::CacheRepo;
\ No newline at end of file
+pub use cache_repo::CacheRepo;
+pub use watchlist_repo::WatchlistRepo;
\ No newline at end of file
@@ -0,0 +1,165 @@
+use crate::domain::{
+    error::ApiError,
+    models::{NeoApproach, NeoObject, NeoSort},
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::Value;
+use sqlx::{PgPool, Row};
+
+pub struct NeoRepo {
+    pool: PgPool,
+}
+
+impl NeoRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Сохранить сближения объекта (UPSERT по объекту, моменту и телу)
+    pub async fn upsert_approaches(&self, neo: &NeoObject) -> Result<usize, ApiError> {
+        for approach in &neo.close_approaches {
+            sqlx::query(
+                r#"
+                INSERT INTO neo_close_approaches (
+                    neo_id, name, is_potentially_hazardous, is_sentry_object, absolute_magnitude_h,
+                    diameter_min_km, diameter_max_km, approach_date, approach_at,
+                    relative_velocity_kps, miss_distance_km, miss_distance_lunar, miss_distance_au,
+                    orbiting_body, fetched_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, NOW())
+                ON CONFLICT (neo_id, approach_at, orbiting_body) DO UPDATE SET
+                    name = EXCLUDED.name,
+                    is_potentially_hazardous = EXCLUDED.is_potentially_hazardous,
+                    is_sentry_object = EXCLUDED.is_sentry_object,
+                    absolute_magnitude_h = EXCLUDED.absolute_magnitude_h,
+                    diameter_min_km = EXCLUDED.diameter_min_km,
+                    diameter_max_km = EXCLUDED.diameter_max_km,
+                    approach_date = EXCLUDED.approach_date,
+                    relative_velocity_kps = EXCLUDED.relative_velocity_kps,
+                    miss_distance_km = EXCLUDED.miss_distance_km,
+                    miss_distance_lunar = EXCLUDED.miss_distance_lunar,
+                    miss_distance_au = EXCLUDED.miss_distance_au,
+                    fetched_at = EXCLUDED.fetched_at
+                "#
+            )
+            .bind(&neo.id)
+            .bind(&neo.name)
+            .bind(neo.is_potentially_hazardous)
+            .bind(neo.is_sentry_object)
+            .bind(neo.absolute_magnitude_h)
+            .bind(neo.diameter_min_km)
+            .bind(neo.diameter_max_km)
+            .bind(approach.approach_date)
+            .bind(approach.approach_at)
+            .bind(approach.relative_velocity_kps)
+            .bind(approach.miss_distance_km)
+            .bind(approach.miss_distance_lunar)
+            .bind(approach.miss_distance_au)
+            .bind(&approach.orbiting_body)
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(neo.close_approaches.len())
+    }
+
+    /// Сближения за диапазон дат (включительно) с фильтрами
+    pub async fn get_approaches(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        hazardous: Option<bool>,
+        max_miss_km: Option<f64>,
+        sort: NeoSort,
+    ) -> Result<Vec<NeoApproach>, ApiError> {
+        let order_by = match sort {
+            NeoSort::Date => "approach_at ASC",
+            NeoSort::MissDistance => "miss_distance_km ASC",
+            NeoSort::Velocity => "relative_velocity_kps DESC",
+            NeoSort::Size => "diameter_max_km DESC NULLS LAST",
+        };
+
+        let query = format!(
+            r#"
+            SELECT neo_id, name, is_potentially_hazardous, absolute_magnitude_h,
+                   diameter_min_km, diameter_max_km, approach_date, approach_at,
+                   relative_velocity_kps, miss_distance_km, miss_distance_lunar, miss_distance_au,
+                   orbiting_body
+            FROM neo_close_approaches
+            WHERE approach_date BETWEEN $1 AND $2
+              AND ($3::BOOLEAN IS NULL OR is_potentially_hazardous = $3)
+              AND ($4::DOUBLE PRECISION IS NULL OR miss_distance_km <= $4)
+            ORDER BY {}, neo_id
+            "#,
+            order_by
+        );
+
+        let approaches = sqlx::query_as::<_, NeoApproach>(&query)
+            .bind(start)
+            .bind(end)
+            .bind(hazardous)
+            .bind(max_miss_km)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(approaches)
+    }
+
+    /// Отметить окно NeoWs, загруженное целиком после его окончания
+    pub async fn mark_window_synced(&self, window_start: NaiveDate) -> Result<(), ApiError> {
+        sqlx::query(
+            r#"
+            INSERT INTO neo_synced_windows (window_start, fetched_at)
+            VALUES ($1, NOW())
+            ON CONFLICT (window_start) DO UPDATE SET fetched_at = EXCLUDED.fetched_at
+            "#
+        )
+        .bind(window_start)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Загружено ли прошедшее окно NeoWs
+    pub async fn is_window_synced(&self, window_start: NaiveDate) -> Result<bool, ApiError> {
+        let row = sqlx::query("SELECT EXISTS (SELECT 1 FROM neo_synced_windows WHERE window_start = $1) AS synced")
+            .bind(window_start)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(row.get("synced"))
+    }
+
+    /// Сохранить ответ NeoWs lookup для объекта
+    pub async fn upsert_object(&self, neo_id: &str, name: &str, payload: &Value) -> Result<DateTime<Utc>, ApiError> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO neo_objects (id, name, payload, fetched_at)
+            VALUES ($1, $2, $3, NOW())
+            ON CONFLICT (id) DO UPDATE SET
+                name = EXCLUDED.name,
+                payload = EXCLUDED.payload,
+                fetched_at = EXCLUDED.fetched_at
+            RETURNING fetched_at
+            "#
+        )
+        .bind(neo_id)
+        .bind(name)
+        .bind(payload)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.get("fetched_at"))
+    }
+
+    /// Получить сохранённый ответ lookup и время загрузки
+    pub async fn get_object(&self, neo_id: &str) -> Result<Option<(Value, DateTime<Utc>)>, ApiError> {
+        let row = sqlx::query("SELECT payload, fetched_at FROM neo_objects WHERE id = $1")
+            .bind(neo_id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(row.map(|r| (r.get("payload"), r.get("fetched_at"))))
+    }
+}
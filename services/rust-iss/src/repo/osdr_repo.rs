@@ -1,7 +1,19 @@
-use crate::domain::{error::ApiError, models::OsdrDataset};
+use crate::domain::{
+    error::ApiError,
+    models::{OsdrDataset, OsdrSyncRun, OsdrSyncSource, OsdrSyncStats},
+};
 use chrono::{DateTime, Utc};
 use sqlx::{PgPool, Row};
 
+/// Результат UPSERT одного датасета
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    Inserted,
+    Updated,
+    Unchanged,
+}
+
+#[derive(Clone)]
 pub struct OsdrRepo {
     pool: PgPool,
 }
@@ -15,13 +27,15 @@ impl OsdrRepo {
     pub async fn save(&self, dataset: &OsdrDataset) -> Result<(), ApiError> {
         sqlx::query(
             r#"
-            INSERT INTO osdr_items (dataset_id, title, description, release_date, updated_at)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO osdr_items (dataset_id, title, description, release_date, updated_at, doi)
+            VALUES ($1, $2, $3, $4, $5, $6)
             ON CONFLICT (dataset_id) DO UPDATE SET
                 title = EXCLUDED.title,
                 description = EXCLUDED.description,
                 release_date = EXCLUDED.release_date,
-                updated_at = EXCLUDED.updated_at
+                updated_at = EXCLUDED.updated_at,
+                doi = EXCLUDED.doi,
+                withdrawn_at = NULL
             "#
         )
         .bind(&dataset.dataset_id)
@@ -29,23 +43,64 @@ impl OsdrRepo {
         .bind(&dataset.description)
         .bind(dataset.release_date)
         .bind(dataset.updated_at)
+        .bind(&dataset.doi)
         .execute(&self.pool)
         .await?;
 
         Ok(())
     }
 
-    /// Получить все датасеты OSDR
-    pub async fn get_all(&self, limit: i32) -> Result<Vec<OsdrDataset>, ApiError> {
+    /// UPSERT датасета с определением, была ли запись вставлена, изменена или осталась прежней
+    pub async fn upsert_tracked(&self, dataset: &OsdrDataset) -> Result<UpsertOutcome, ApiError> {
+        // Строка обновляется только при реальном изменении полей или возврате
+        // ранее отозванного датасета;
+        // xmax = 0 означает, что строка была вставлена, а не обновлена
+        let row = sqlx::query(
+            r#"
+            INSERT INTO osdr_items (dataset_id, title, description, release_date, updated_at, doi)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (dataset_id) DO UPDATE SET
+                title = EXCLUDED.title,
+                description = EXCLUDED.description,
+                release_date = EXCLUDED.release_date,
+                updated_at = EXCLUDED.updated_at,
+                doi = EXCLUDED.doi,
+                withdrawn_at = NULL
+            WHERE (osdr_items.title, osdr_items.description, osdr_items.release_date, osdr_items.doi)
+                IS DISTINCT FROM (EXCLUDED.title, EXCLUDED.description, EXCLUDED.release_date, EXCLUDED.doi)
+                OR osdr_items.withdrawn_at IS NOT NULL
+            RETURNING (xmax = 0) AS inserted
+            "#
+        )
+        .bind(&dataset.dataset_id)
+        .bind(&dataset.title)
+        .bind(&dataset.description)
+        .bind(dataset.release_date)
+        .bind(dataset.updated_at)
+        .bind(&dataset.doi)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(match row {
+            Some(r) if r.get::<bool, _>("inserted") => UpsertOutcome::Inserted,
+            Some(_) => UpsertOutcome::Updated,
+            None => UpsertOutcome::Unchanged,
+        })
+    }
+
+    /// Получить все датасеты OSDR (tombstone-записи только по запросу)
+    pub async fn get_all(&self, limit: i32, include_withdrawn: bool) -> Result<Vec<OsdrDataset>, ApiError> {
         let rows = sqlx::query(
             r#"
-            SELECT id, dataset_id, title, description, release_date, updated_at
+            SELECT id, dataset_id, title, description, release_date, updated_at, doi, withdrawn_at
             FROM osdr_items
+            WHERE $2 OR withdrawn_at IS NULL
             ORDER BY updated_at DESC
             LIMIT $1
             "#
         )
         .bind(limit as i64)
+        .bind(include_withdrawn)
         .fetch_all(&self.pool)
         .await?;
 
@@ -58,17 +113,37 @@ impl OsdrRepo {
                 description: r.get("description"),
                 release_date: r.get("release_date"),
                 updated_at: r.get("updated_at"),
+                doi: r.get("doi"),
+                withdrawn_at: r.get("withdrawn_at"),
             })
             .collect();
 
         Ok(datasets)
     }
 
+    /// Тексты (accession, заголовок, описание) всех неотозванных датасетов
+    pub async fn get_active_texts(&self) -> Result<Vec<(String, String, Option<String>)>, ApiError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT dataset_id, title, description
+            FROM osdr_items
+            WHERE withdrawn_at IS NULL
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| (r.get("dataset_id"), r.get("title"), r.get("description")))
+            .collect())
+    }
+
     /// Получить датасет по ID
     pub async fn get_by_id(&self, dataset_id: &str) -> Result<Option<OsdrDataset>, ApiError> {
         let row = sqlx::query(
             r#"
-            SELECT id, dataset_id, title, description, release_date, updated_at
+            SELECT id, dataset_id, title, description, release_date, updated_at, doi, withdrawn_at
             FROM osdr_items
             WHERE dataset_id = $1
             "#
@@ -85,16 +160,48 @@ impl OsdrRepo {
                 description: r.get("description"),
                 release_date: r.get("release_date"),
                 updated_at: r.get("updated_at"),
+                doi: r.get("doi"),
+                withdrawn_at: r.get("withdrawn_at"),
             })),
             None => Ok(None),
         }
     }
 
+    /// Получить несколько датасетов по списку accession
+    pub async fn get_by_ids(&self, dataset_ids: &[String]) -> Result<Vec<OsdrDataset>, ApiError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, dataset_id, title, description, release_date, updated_at, doi, withdrawn_at
+            FROM osdr_items
+            WHERE dataset_id = ANY($1::text[])
+            "#
+        )
+        .bind(dataset_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let datasets = rows
+            .into_iter()
+            .map(|r| OsdrDataset {
+                id: Some(r.get("id")),
+                dataset_id: r.get("dataset_id"),
+                title: r.get("title"),
+                description: r.get("description"),
+                release_date: r.get("release_date"),
+                updated_at: r.get("updated_at"),
+                doi: r.get("doi"),
+                withdrawn_at: r.get("withdrawn_at"),
+            })
+            .collect();
+
+        Ok(datasets)
+    }
+
     /// Поиск по названию (полнотекстовый поиск)
     pub async fn search_by_title(&self, query: &str, limit: i32) -> Result<Vec<OsdrDataset>, ApiError> {
         let rows = sqlx::query(
             r#"
-            SELECT id, dataset_id, title, description, release_date, updated_at
+            SELECT id, dataset_id, title, description, release_date, updated_at, doi, withdrawn_at
             FROM osdr_items
             WHERE title_search @@ plainto_tsquery('english', $1)
             ORDER BY ts_rank(title_search, plainto_tsquery('english', $1)) DESC
@@ -115,6 +222,8 @@ impl OsdrRepo {
                 description: r.get("description"),
                 release_date: r.get("release_date"),
                 updated_at: r.get("updated_at"),
+                doi: r.get("doi"),
+                withdrawn_at: r.get("withdrawn_at"),
             })
             .collect();
 
@@ -142,16 +251,19 @@ impl OsdrRepo {
             datasets.iter().map(|d| d.release_date).collect();
         let updated_ats: Vec<DateTime<Utc>> = 
             datasets.iter().map(|d| d.updated_at).collect();
+        let dois: Vec<Option<String>> = datasets.iter().map(|d| d.doi.clone()).collect();
 
         let result = sqlx::query(
             r#"
-            INSERT INTO osdr_items (dataset_id, title, description, release_date, updated_at)
-            SELECT * FROM UNNEST($1::text[], $2::text[], $3::text[], $4::date[], $5::timestamptz[])
+            INSERT INTO osdr_items (dataset_id, title, description, release_date, updated_at, doi)
+            SELECT * FROM UNNEST($1::text[], $2::text[], $3::text[], $4::date[], $5::timestamptz[], $6::text[])
             ON CONFLICT (dataset_id) DO UPDATE SET
                 title = EXCLUDED.title,
                 description = EXCLUDED.description,
                 release_date = EXCLUDED.release_date,
-                updated_at = EXCLUDED.updated_at
+                updated_at = EXCLUDED.updated_at,
+                doi = EXCLUDED.doi,
+                withdrawn_at = NULL
             "#
         )
         .bind(&dataset_ids)
@@ -159,6 +271,7 @@ impl OsdrRepo {
         .bind(&descriptions)
         .bind(&release_dates)
         .bind(&updated_ats)
+        .bind(&dois)
         .execute(&self.pool)
         .await?;
 
@@ -174,10 +287,27 @@ impl OsdrRepo {
         Ok(row.get("count"))
     }
 
-    /// Delete datasets older than specified days (cleanup)
-    pub async fn delete_older_than(&self, days: i64) -> Result<u64, ApiError> {
+    /// Пометить как отозванные датасеты, отсутствующие в полном каталоге upstream
+    pub async fn mark_withdrawn_except(&self, present_ids: &[String]) -> Result<u64, ApiError> {
         let result = sqlx::query(
-            "DELETE FROM osdr_items WHERE updated_at < NOW() - INTERVAL '1 day' * $1"
+            r#"
+            UPDATE osdr_items
+            SET withdrawn_at = NOW()
+            WHERE withdrawn_at IS NULL
+              AND NOT (dataset_id = ANY($1::text[]))
+            "#
+        )
+        .bind(present_ids)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Удалить tombstone-записи, отозванные более `days` дней назад (retention)
+    pub async fn purge_withdrawn_older_than(&self, days: i64) -> Result<u64, ApiError> {
+        let result = sqlx::query(
+            "DELETE FROM osdr_items WHERE withdrawn_at < NOW() - INTERVAL '1 day' * $1"
         )
         .bind(days)
         .execute(&self.pool)
@@ -185,4 +315,76 @@ impl OsdrRepo {
 
         Ok(result.rows_affected())
     }
+
+    /// Зарегистрировать начало синхронизации в журнале osdr_sync_runs
+    pub async fn start_sync_run(&self, source: OsdrSyncSource) -> Result<i64, ApiError> {
+        let row = sqlx::query(
+            "INSERT INTO osdr_sync_runs (source, started_at) VALUES ($1, NOW()) RETURNING id"
+        )
+        .bind(source.as_str())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.get("id"))
+    }
+
+    /// Завершить запись журнала синхронизации со счётчиками и ошибкой (если была)
+    pub async fn finish_sync_run(
+        &self,
+        run_id: i64,
+        stats: &OsdrSyncStats,
+        error: Option<&str>,
+    ) -> Result<OsdrSyncRun, ApiError> {
+        let run = sqlx::query_as::<_, OsdrSyncRun>(
+            r#"
+            UPDATE osdr_sync_runs
+            SET finished_at = NOW(),
+                inserted = $2,
+                updated = $3,
+                unchanged = $4,
+                failed = $5,
+                withdrawn = $6,
+                error = $7
+            WHERE id = $1
+            RETURNING id, source, started_at, finished_at, inserted, updated, unchanged, failed, withdrawn, error
+            "#
+        )
+        .bind(run_id)
+        .bind(stats.inserted)
+        .bind(stats.updated)
+        .bind(stats.unchanged)
+        .bind(stats.failed)
+        .bind(stats.withdrawn)
+        .bind(error)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(run)
+    }
+
+    /// Id последней завершённой синхронизации - версия каталога для производных in-process данных
+    pub async fn get_latest_finished_run_id(&self) -> Result<Option<i64>, ApiError> {
+        let row = sqlx::query("SELECT MAX(id) AS id FROM osdr_sync_runs WHERE finished_at IS NOT NULL AND error IS NULL")
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(row.get("id"))
+    }
+
+    /// Получить последние записи журнала синхронизации
+    pub async fn get_sync_runs(&self, limit: i32) -> Result<Vec<OsdrSyncRun>, ApiError> {
+        let runs = sqlx::query_as::<_, OsdrSyncRun>(
+            r#"
+            SELECT id, source, started_at, finished_at, inserted, updated, unchanged, failed, withdrawn, error
+            FROM osdr_sync_runs
+            ORDER BY started_at DESC
+            LIMIT $1
+            "#
+        )
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(runs)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,452 @@
+use crate::domain::{
+    error::ApiError,
+    models::{LaunchScheduleChange, SpaceXLaunch, SpaceXLaunchpad, SpaceXPayload, SpaceXRocket},
+};
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+
+pub struct SpaceXRepo {
+    pool: PgPool,
+}
+
+/// Фильтры архива запусков; rocket - ID или название ракеты (регистр не важен)
+#[derive(Debug, Default)]
+pub struct SpaceXLaunchFilter<'a> {
+    pub upcoming: Option<bool>,
+    pub rocket: Option<&'a str>,
+    pub success: Option<bool>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+const LAUNCH_COLUMNS: &str = r#"
+    l.id, l.name, l.flight_number, l.date_utc, l.date_precision, l.upcoming, l.success, l.tbd, l.net,
+    l.rocket_id, r.name AS rocket_name, l.launchpad_id, p.name AS launchpad_name, l.payload_ids,
+    l.details, l.patch_url, l.webcast_url, l.failure_reasons, l.updated_at
+"#;
+
+const CHANGE_COLUMNS: &str = r#"
+    launch_id, launch_name, kind, previous_date_utc, date_utc, slip_seconds, previous_precision, date_precision,
+    previous_tbd, tbd, previous_net, net, previous_status, status, detected_at
+"#;
+
+impl SpaceXRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Сохранить ракеты (UPSERT по id)
+    pub async fn upsert_rockets(&self, rockets: &[SpaceXRocket]) -> Result<usize, ApiError> {
+        for rocket in rockets {
+            sqlx::query(
+                r#"
+                INSERT INTO spacex_rockets
+                    (id, name, rocket_type, active, stages, cost_per_launch, success_rate_pct, first_flight,
+                     country, company, description, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, NOW())
+                ON CONFLICT (id) DO UPDATE SET
+                    name = EXCLUDED.name,
+                    rocket_type = EXCLUDED.rocket_type,
+                    active = EXCLUDED.active,
+                    stages = EXCLUDED.stages,
+                    cost_per_launch = EXCLUDED.cost_per_launch,
+                    success_rate_pct = EXCLUDED.success_rate_pct,
+                    first_flight = EXCLUDED.first_flight,
+                    country = EXCLUDED.country,
+                    company = EXCLUDED.company,
+                    description = EXCLUDED.description,
+                    updated_at = EXCLUDED.updated_at
+                "#
+            )
+            .bind(&rocket.id)
+            .bind(&rocket.name)
+            .bind(&rocket.rocket_type)
+            .bind(rocket.active)
+            .bind(rocket.stages)
+            .bind(rocket.cost_per_launch)
+            .bind(rocket.success_rate_pct)
+            .bind(rocket.first_flight)
+            .bind(&rocket.country)
+            .bind(&rocket.company)
+            .bind(&rocket.description)
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(rockets.len())
+    }
+
+    /// Сохранить стартовые площадки (UPSERT по id)
+    pub async fn upsert_launchpads(&self, pads: &[SpaceXLaunchpad]) -> Result<usize, ApiError> {
+        for pad in pads {
+            sqlx::query(
+                r#"
+                INSERT INTO spacex_launchpads
+                    (id, name, full_name, locality, region, latitude, longitude, status, timezone,
+                     launch_attempts, launch_successes, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, NOW())
+                ON CONFLICT (id) DO UPDATE SET
+                    name = EXCLUDED.name,
+                    full_name = EXCLUDED.full_name,
+                    locality = EXCLUDED.locality,
+                    region = EXCLUDED.region,
+                    latitude = EXCLUDED.latitude,
+                    longitude = EXCLUDED.longitude,
+                    status = EXCLUDED.status,
+                    timezone = EXCLUDED.timezone,
+                    launch_attempts = EXCLUDED.launch_attempts,
+                    launch_successes = EXCLUDED.launch_successes,
+                    updated_at = EXCLUDED.updated_at
+                "#
+            )
+            .bind(&pad.id)
+            .bind(&pad.name)
+            .bind(&pad.full_name)
+            .bind(&pad.locality)
+            .bind(&pad.region)
+            .bind(pad.latitude)
+            .bind(pad.longitude)
+            .bind(&pad.status)
+            .bind(&pad.timezone)
+            .bind(pad.launch_attempts)
+            .bind(pad.launch_successes)
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(pads.len())
+    }
+
+    /// Сохранить запуски и записать изменения их расписания одной транзакцией.
+    /// `detect` получает снимок отслеживаемых запусков до перезаписи и возвращает изменения
+    pub async fn upsert_launches_tracking_changes<F>(
+        &self,
+        launches: &[SpaceXLaunch],
+        detect: F,
+    ) -> Result<(usize, usize), ApiError>
+    where
+        F: FnOnce(Vec<SpaceXLaunch>) -> Vec<LaunchScheduleChange>,
+    {
+        let mut tx = self.pool.begin().await?;
+
+        let tracked = Self::get_tracked_launches(&mut tx).await?;
+        let changes = detect(tracked);
+        let upserted = Self::upsert_launches(&mut tx, launches).await?;
+        let recorded = Self::insert_schedule_changes(&mut tx, &changes).await?;
+
+        tx.commit().await?;
+        Ok((upserted, recorded))
+    }
+
+    /// Сохранить запуски (UPSERT по id)
+    async fn upsert_launches(conn: &mut PgConnection, launches: &[SpaceXLaunch]) -> Result<usize, ApiError> {
+        for launch in launches {
+            sqlx::query(
+                r#"
+                INSERT INTO spacex_launches
+                    (id, name, flight_number, date_utc, date_precision, upcoming, success, tbd, net,
+                     rocket_id, launchpad_id, payload_ids, details, patch_url, webcast_url, failure_reasons,
+                     updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, NOW())
+                ON CONFLICT (id) DO UPDATE SET
+                    name = EXCLUDED.name,
+                    flight_number = EXCLUDED.flight_number,
+                    date_utc = EXCLUDED.date_utc,
+                    date_precision = EXCLUDED.date_precision,
+                    upcoming = EXCLUDED.upcoming,
+                    success = EXCLUDED.success,
+                    tbd = EXCLUDED.tbd,
+                    net = EXCLUDED.net,
+                    rocket_id = EXCLUDED.rocket_id,
+                    launchpad_id = EXCLUDED.launchpad_id,
+                    payload_ids = EXCLUDED.payload_ids,
+                    details = EXCLUDED.details,
+                    patch_url = EXCLUDED.patch_url,
+                    webcast_url = EXCLUDED.webcast_url,
+                    failure_reasons = EXCLUDED.failure_reasons,
+                    updated_at = EXCLUDED.updated_at
+                "#
+            )
+            .bind(&launch.id)
+            .bind(&launch.name)
+            .bind(launch.flight_number)
+            .bind(launch.date_utc)
+            .bind(&launch.date_precision)
+            .bind(launch.upcoming)
+            .bind(launch.success)
+            .bind(launch.tbd)
+            .bind(launch.net)
+            .bind(&launch.rocket_id)
+            .bind(&launch.launchpad_id)
+            .bind(&launch.payload_ids)
+            .bind(&launch.details)
+            .bind(&launch.patch_url)
+            .bind(&launch.webcast_url)
+            .bind(&launch.failure_reasons)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(launches.len())
+    }
+
+    /// Сохранить полезные нагрузки (UPSERT по id)
+    pub async fn upsert_payloads(&self, payloads: &[SpaceXPayload]) -> Result<usize, ApiError> {
+        for payload in payloads {
+            sqlx::query(
+                r#"
+                INSERT INTO spacex_payloads
+                    (id, name, payload_type, launch_id, customers, mass_kg, orbit, regime, reused, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, NOW())
+                ON CONFLICT (id) DO UPDATE SET
+                    name = EXCLUDED.name,
+                    payload_type = EXCLUDED.payload_type,
+                    launch_id = EXCLUDED.launch_id,
+                    customers = EXCLUDED.customers,
+                    mass_kg = EXCLUDED.mass_kg,
+                    orbit = EXCLUDED.orbit,
+                    regime = EXCLUDED.regime,
+                    reused = EXCLUDED.reused,
+                    updated_at = EXCLUDED.updated_at
+                "#
+            )
+            .bind(&payload.id)
+            .bind(&payload.name)
+            .bind(&payload.payload_type)
+            .bind(&payload.launch_id)
+            .bind(&payload.customers)
+            .bind(payload.mass_kg)
+            .bind(&payload.orbit)
+            .bind(&payload.regime)
+            .bind(payload.reused)
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(payloads.len())
+    }
+
+    /// Запуски по фильтрам: предстоящие - ближайшие первыми, остальные - свежие первыми
+    pub async fn get_launches(&self, filter: &SpaceXLaunchFilter<'_>, limit: i64) -> Result<Vec<SpaceXLaunch>, ApiError> {
+        let query = format!(
+            r#"
+            SELECT {}
+            FROM spacex_launches l
+            LEFT JOIN spacex_rockets r ON r.id = l.rocket_id
+            LEFT JOIN spacex_launchpads p ON p.id = l.launchpad_id
+            WHERE ($1::boolean IS NULL OR l.upcoming = $1)
+              AND ($2::text IS NULL OR l.rocket_id = $2 OR lower(r.name) = lower($2))
+              AND ($3::boolean IS NULL OR l.success = $3)
+              AND ($4::timestamptz IS NULL OR l.date_utc >= $4)
+              AND ($5::timestamptz IS NULL OR l.date_utc <= $5)
+            ORDER BY CASE WHEN $1 IS TRUE THEN l.date_utc END ASC,
+                     l.date_utc DESC
+            LIMIT $6
+            "#,
+            LAUNCH_COLUMNS
+        );
+
+        let launches = sqlx::query_as::<_, SpaceXLaunch>(&query)
+            .bind(filter.upcoming)
+            .bind(filter.rocket)
+            .bind(filter.success)
+            .bind(filter.from)
+            .bind(filter.to)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(launches)
+    }
+
+    pub async fn get_launch(&self, id: &str) -> Result<Option<SpaceXLaunch>, ApiError> {
+        let query = format!(
+            r#"
+            SELECT {}
+            FROM spacex_launches l
+            LEFT JOIN spacex_rockets r ON r.id = l.rocket_id
+            LEFT JOIN spacex_launchpads p ON p.id = l.launchpad_id
+            WHERE l.id = $1
+            "#,
+            LAUNCH_COLUMNS
+        );
+
+        let launch = sqlx::query_as::<_, SpaceXLaunch>(&query)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        Ok(launch)
+    }
+
+    pub async fn get_rocket(&self, id: &str) -> Result<Option<SpaceXRocket>, ApiError> {
+        let rocket = sqlx::query_as::<_, SpaceXRocket>(
+            r#"
+            SELECT id, name, rocket_type, active, stages, cost_per_launch, success_rate_pct, first_flight,
+                   country, company, description
+            FROM spacex_rockets
+            WHERE id = $1
+            "#
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rocket)
+    }
+
+    pub async fn get_launchpad(&self, id: &str) -> Result<Option<SpaceXLaunchpad>, ApiError> {
+        let pad = sqlx::query_as::<_, SpaceXLaunchpad>(
+            r#"
+            SELECT id, name, full_name, locality, region, latitude, longitude, status, timezone,
+                   launch_attempts, launch_successes
+            FROM spacex_launchpads
+            WHERE id = $1
+            "#
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(pad)
+    }
+
+    /// Полезные нагрузки запуска
+    pub async fn get_payloads(&self, launch_id: &str) -> Result<Vec<SpaceXPayload>, ApiError> {
+        let payloads = sqlx::query_as::<_, SpaceXPayload>(
+            r#"
+            SELECT id, name, payload_type, launch_id, customers, mass_kg, orbit, regime, reused
+            FROM spacex_payloads
+            WHERE launch_id = $1
+            ORDER BY id
+            "#
+        )
+        .bind(launch_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(payloads)
+    }
+
+    /// Запуски, за расписанием которых следим: предстоящие и без опубликованного итога
+    async fn get_tracked_launches(conn: &mut PgConnection) -> Result<Vec<SpaceXLaunch>, ApiError> {
+        let query = format!(
+            r#"
+            SELECT {}
+            FROM spacex_launches l
+            LEFT JOIN spacex_rockets r ON r.id = l.rocket_id
+            LEFT JOIN spacex_launchpads p ON p.id = l.launchpad_id
+            WHERE l.upcoming OR l.success IS NULL
+            FOR UPDATE OF l
+            "#,
+            LAUNCH_COLUMNS
+        );
+
+        let launches = sqlx::query_as::<_, SpaceXLaunch>(&query)
+            .fetch_all(&mut *conn)
+            .await?;
+
+        Ok(launches)
+    }
+
+    /// Добавить записи в историю расписания
+    async fn insert_schedule_changes(conn: &mut PgConnection, changes: &[LaunchScheduleChange]) -> Result<usize, ApiError> {
+        for change in changes {
+            sqlx::query(&format!(
+                r#"
+                INSERT INTO launch_schedule_history ({})
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+                "#,
+                CHANGE_COLUMNS
+            ))
+            .bind(&change.launch_id)
+            .bind(&change.launch_name)
+            .bind(&change.kind)
+            .bind(change.previous_date_utc)
+            .bind(change.date_utc)
+            .bind(change.slip_seconds)
+            .bind(&change.previous_precision)
+            .bind(&change.date_precision)
+            .bind(change.previous_tbd)
+            .bind(change.tbd)
+            .bind(change.previous_net)
+            .bind(change.net)
+            .bind(&change.previous_status)
+            .bind(&change.status)
+            .bind(change.detected_at)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(changes.len())
+    }
+
+    /// История расписания запуска, от старых записей к новым
+    pub async fn get_launch_history(&self, launch_id: &str) -> Result<Vec<LaunchScheduleChange>, ApiError> {
+        let query = format!(
+            r#"
+            SELECT {}
+            FROM launch_schedule_history
+            WHERE launch_id = $1
+            ORDER BY detected_at, id
+            "#,
+            CHANGE_COLUMNS
+        );
+
+        let changes = sqlx::query_as::<_, LaunchScheduleChange>(&query)
+            .bind(launch_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(changes)
+    }
+
+    /// Лента изменений расписания по всем запускам, свежие первыми
+    pub async fn get_schedule_changes(
+        &self,
+        since: DateTime<Utc>,
+        kind: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<LaunchScheduleChange>, ApiError> {
+        let query = format!(
+            r#"
+            SELECT {}
+            FROM launch_schedule_history
+            WHERE detected_at >= $1
+              AND ($2::text IS NULL OR kind = $2)
+            ORDER BY detected_at DESC, id DESC
+            LIMIT $3
+            "#,
+            CHANGE_COLUMNS
+        );
+
+        let changes = sqlx::query_as::<_, LaunchScheduleChange>(&query)
+            .bind(since)
+            .bind(kind)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(changes)
+    }
+
+    /// Число записей истории и время последней по каждому запуску (SEQUENCE и LAST-MODIFIED календаря)
+    pub async fn get_schedule_revisions(
+        &self,
+        launch_ids: &[String],
+    ) -> Result<Vec<(String, i64, DateTime<Utc>)>, ApiError> {
+        let revisions = sqlx::query_as::<_, (String, i64, DateTime<Utc>)>(
+            r#"
+            SELECT launch_id, COUNT(*), MAX(detected_at)
+            FROM launch_schedule_history
+            WHERE launch_id = ANY($1)
+            GROUP BY launch_id
+            "#
+        )
+        .bind(launch_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(revisions)
+    }
+}
@@ -0,0 +1,181 @@
+use crate::domain::{
+    error::ApiError,
+    models::{CreateWatchlistRequest, NotifyChannel, OsdrWatchlist, WatchNotification},
+};
+use sqlx::{postgres::PgRow, PgPool, Row};
+
+#[derive(Clone)]
+pub struct WatchlistRepo {
+    pool: PgPool,
+}
+
+impl WatchlistRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Создать watchlist
+    pub async fn create(&self, request: &CreateWatchlistRequest) -> Result<OsdrWatchlist, ApiError> {
+        let row = sqlx::query(
+            r#"
+            INSERT INTO osdr_watchlists (name, keywords, organisms, channel, target)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, name, keywords, organisms, channel, target, created_at
+            "#
+        )
+        .bind(&request.name)
+        .bind(&request.keywords)
+        .bind(&request.organisms)
+        .bind(request.channel.as_str())
+        .bind(&request.target)
+        .fetch_one(&self.pool)
+        .await?;
+
+        map_watchlist(&row)
+            .ok_or_else(|| ApiError::InternalError("Unknown watchlist channel".to_string()))
+    }
+
+    /// Получить все watchlist
+    pub async fn get_all(&self) -> Result<Vec<OsdrWatchlist>, ApiError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, name, keywords, organisms, channel, target, created_at
+            FROM osdr_watchlists
+            ORDER BY id
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().filter_map(map_watchlist).collect())
+    }
+
+    /// Получить watchlist по ID
+    pub async fn get_by_id(&self, id: i64) -> Result<Option<OsdrWatchlist>, ApiError> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, name, keywords, organisms, channel, target, created_at
+            FROM osdr_watchlists
+            WHERE id = $1
+            "#
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.as_ref().and_then(map_watchlist))
+    }
+
+    /// Удалить watchlist (уведомления удаляются каскадно)
+    pub async fn delete(&self, id: i64) -> Result<bool, ApiError> {
+        let result = sqlx::query("DELETE FROM osdr_watchlists WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Записать уведомление о совпадении датасета с watchlist
+    pub async fn record_notification(
+        &self,
+        watchlist_id: i64,
+        dataset_id: &str,
+        title: &str,
+        change: &str,
+        matched_terms: &[String],
+    ) -> Result<WatchNotification, ApiError> {
+        let notification = sqlx::query_as::<_, WatchNotification>(
+            r#"
+            INSERT INTO osdr_watch_notifications (watchlist_id, dataset_id, title, change, matched_terms)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, watchlist_id, dataset_id, title, change, matched_terms,
+                      created_at, delivered_at, attempts, delivery_error
+            "#
+        )
+        .bind(watchlist_id)
+        .bind(dataset_id)
+        .bind(title)
+        .bind(change)
+        .bind(matched_terms)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(notification)
+    }
+
+    /// Недоставленные уведомления, у которых ещё остались попытки
+    pub async fn get_pending(&self, max_attempts: i32) -> Result<Vec<WatchNotification>, ApiError> {
+        let notifications = sqlx::query_as::<_, WatchNotification>(
+            r#"
+            SELECT id, watchlist_id, dataset_id, title, change, matched_terms,
+                   created_at, delivered_at, attempts, delivery_error
+            FROM osdr_watch_notifications
+            WHERE delivered_at IS NULL AND attempts < $1
+            ORDER BY created_at
+            "#
+        )
+        .bind(max_attempts)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(notifications)
+    }
+
+    /// Отметить результат попытки доставки
+    pub async fn mark_attempt(&self, id: i64, error: Option<&str>) -> Result<(), ApiError> {
+        sqlx::query(
+            r#"
+            UPDATE osdr_watch_notifications
+            SET attempts = attempts + 1,
+                delivered_at = CASE WHEN $2::text IS NULL THEN NOW() ELSE NULL END,
+                delivery_error = $2
+            WHERE id = $1
+            "#
+        )
+        .bind(id)
+        .bind(error)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// История уведомлений watchlist
+    pub async fn get_notifications(
+        &self,
+        watchlist_id: i64,
+        limit: i32,
+    ) -> Result<Vec<WatchNotification>, ApiError> {
+        let notifications = sqlx::query_as::<_, WatchNotification>(
+            r#"
+            SELECT id, watchlist_id, dataset_id, title, change, matched_terms,
+                   created_at, delivered_at, attempts, delivery_error
+            FROM osdr_watch_notifications
+            WHERE watchlist_id = $1
+            ORDER BY created_at DESC
+            LIMIT $2
+            "#
+        )
+        .bind(watchlist_id)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(notifications)
+    }
+}
+
+fn map_watchlist(r: &PgRow) -> Option<OsdrWatchlist> {
+    let channel: String = r.get("channel");
+
+    Some(OsdrWatchlist {
+        id: r.get("id"),
+        name: r.get("name"),
+        keywords: r.get("keywords"),
+        organisms: r.get("organisms"),
+        channel: NotifyChannel::parse(&channel)?,
+        target: r.get("target"),
+        created_at: r.get("created_at"),
+    })
+}
@@ -1,17 +1,33 @@
 use crate::{
     handlers::{
         health_check, 
-        get_current_position, fetch_position, get_history,
-        sync_datasets, list_datasets, SharedOsdrService,
-        get_apod, get_neo, get_donki_flr, get_donki_cme, SharedNasaService,
-        get_images, SharedJwstService,
-        get_next_launch, SharedSpaceXService,
+        get_epic_dates, get_epic_image, get_epic_images, get_epic_nearest, SharedEpicService,
+        get_current_position, fetch_position, get_history, get_overflown_events, get_passes,
+        get_iss_passes_calendar, get_launches_calendar,
+        get_astro_events, get_astro_positions, get_astro_sky, SharedAstronomyService,
+        get_eonet_events, SharedEonetService,
+        sync_datasets, list_sync_runs, list_datasets, cite_dataset, cite_datasets_bulk,
+        get_related_datasets, list_watchlists, create_watchlist, delete_watchlist,
+        list_watchlist_notifications, SharedOsdrService,
+        get_apod, get_apod_by_date, backfill_apod, get_neo, get_neo_by_id,
+        get_donki_flr, get_donki_cme, get_donki_gst, get_donki_sep, get_donki_ips, get_donki_hss,
+        get_donki_rbe, get_donki_notifications, get_donki_chains, get_donki_related,
+        get_space_weather_index,
+        SharedNasaService,
+        get_images, get_new_observations, get_product_header, list_images, upload_product_header,
+        SharedJwstService, FITS_UPLOAD_LIMIT,
+        get_media, lookup_media, mirror_media, SharedMediaService,
+        get_launch, get_launch_slips, get_next_launch, get_schedule_changes, list_launches, SharedSpaceXService,
+    },
+    middleware::{
+        metrics_middleware, provenance_middleware, rate_limit_middleware, request_id_middleware,
+        SharedRateLimiter,
     },
-    middleware::{metrics_middleware, rate_limit_middleware, request_id_middleware, SharedRateLimiter},
 };
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
-    routing::get,
+    routing::{delete, get, post},
     Router,
 };
 use tower_http::{
@@ -29,8 +45,12 @@ pub struct AppState {
     pub iss_service: SharedIssService,
     pub osdr_service: SharedOsdrService,
     pub nasa_service: SharedNasaService,
+    pub epic_service: SharedEpicService,
+    pub eonet_service: SharedEonetService,
     pub jwst_service: SharedJwstService,
+    pub media_service: SharedMediaService,
     pub spacex_service: SharedSpaceXService,
+    pub astronomy_service: SharedAstronomyService,
     pub rate_limiter: SharedRateLimiter,
 }
 
@@ -40,8 +60,12 @@ impl Clone for AppState {
             iss_service: self.iss_service.clone(),
             osdr_service: self.osdr_service.clone(),
             nasa_service: self.nasa_service.clone(),
+            epic_service: self.epic_service.clone(),
+            eonet_service: self.eonet_service.clone(),
             jwst_service: self.jwst_service.clone(),
+            media_service: self.media_service.clone(),
             spacex_service: self.spacex_service.clone(),
+            astronomy_service: self.astronomy_service.clone(),
             rate_limiter: self.rate_limiter.clone(),
         }
     }
@@ -53,32 +77,98 @@ pub fn create_router(state: AppState) -> Router {
         .route("/current", get(get_current_position))
         .route("/fetch", get(fetch_position))
         .route("/history", get(get_history))
+        .route("/overflown-events", get(get_overflown_events))
+        .route("/passes", get(get_passes))
         .with_state(state.clone());
 
     // OSDR routes
     let osdr_routes = Router::new()
         .route("/sync", get(sync_datasets))
+        .route("/sync/runs", get(list_sync_runs))
         .route("/list", get(list_datasets))
+        .route("/cite", post(cite_datasets_bulk))
+        .route("/:dataset_id/cite", get(cite_dataset))
+        .route("/:dataset_id/related", get(get_related_datasets))
+        .route("/watchlists", get(list_watchlists).post(create_watchlist))
+        .route("/watchlists/:id", delete(delete_watchlist))
+        .route("/watchlists/:id/notifications", get(list_watchlist_notifications))
         .with_state(state.osdr_service.clone());
 
     // NASA routes
     let nasa_routes = Router::new()
         .route("/apod", get(get_apod))
+        .route("/apod/backfill", post(backfill_apod))
+        .route("/apod/:date", get(get_apod_by_date))
         .route("/neo", get(get_neo))
+        .route("/neo/:id", get(get_neo_by_id))
         .route("/donki/flr", get(get_donki_flr))
         .route("/donki/cme", get(get_donki_cme))
+        .route("/donki/gst", get(get_donki_gst))
+        .route("/donki/sep", get(get_donki_sep))
+        .route("/donki/ips", get(get_donki_ips))
+        .route("/donki/hss", get(get_donki_hss))
+        .route("/donki/rbe", get(get_donki_rbe))
+        .route("/donki/notifications", get(get_donki_notifications))
+        .route("/donki/chains", get(get_donki_chains))
+        .route("/donki/:activity_id/related", get(get_donki_related))
+        .route("/space-weather/index", get(get_space_weather_index))
         .with_state(state.nasa_service.clone());
 
+    // EPIC routes
+    let epic_routes = Router::new()
+        .route("/:collection/images", get(get_epic_images))
+        .route("/:collection/images/:identifier", get(get_epic_image))
+        .route("/:collection/dates", get(get_epic_dates))
+        .route("/:collection/nearest", get(get_epic_nearest))
+        .with_state(state.epic_service.clone());
+
+    // EONET routes
+    let eonet_routes = Router::new()
+        .route("/events", get(get_eonet_events))
+        .with_state(state.eonet_service.clone());
+
     // JWST routes
     let jwst_routes = Router::new()
+        .route("/images", get(list_images))
         .route("/images/:program_id", get(get_images))
+        .route("/new", get(get_new_observations))
+        .route(
+            "/products/:file/header",
+            get(get_product_header)
+                .post(upload_product_header)
+                .layer(DefaultBodyLimit::max(FITS_UPLOAD_LIMIT)),
+        )
         .with_state(state.jwst_service.clone());
 
+    // Media routes
+    let media_routes = Router::new()
+        .route("/lookup", get(lookup_media))
+        .route("/mirror", post(mirror_media))
+        .route("/:hash", get(get_media))
+        .with_state(state.media_service.clone());
+
     // SpaceX routes
     let spacex_routes = Router::new()
         .route("/next", get(get_next_launch))
+        .route("/launches", get(list_launches))
+        .route("/launches/:id", get(get_launch))
+        .route("/launches/:id/slips", get(get_launch_slips))
+        .route("/changes", get(get_schedule_changes))
         .with_state(state.spacex_service.clone());
 
+    // Astronomy routes
+    let astro_routes = Router::new()
+        .route("/events", get(get_astro_events))
+        .route("/positions", get(get_astro_positions))
+        .route("/sky", get(get_astro_sky))
+        .with_state(state.astronomy_service.clone());
+
+    // Calendar subscriptions (iCalendar)
+    let calendar_routes = Router::new()
+        .route("/launches.ics", get(get_launches_calendar))
+        .route("/iss-passes.ics", get(get_iss_passes_calendar))
+        .with_state(state.clone());
+
     // Main router
     Router::new()
         .route("/health", get(health_check))
@@ -86,9 +176,15 @@ pub fn create_router(state: AppState) -> Router {
         .nest("/iss", iss_routes)
         .nest("/osdr", osdr_routes)
         .nest("/nasa", nasa_routes)
+        .nest("/epic", epic_routes)
+        .nest("/eonet", eonet_routes)
         .nest("/jwst", jwst_routes)
+        .nest("/media", media_routes)
         .nest("/spacex", spacex_routes)
+        .nest("/astro", astro_routes)
+        .nest("/calendar", calendar_routes)
         // Middleware
+        .layer(middleware::from_fn(provenance_middleware))
         .layer(TraceLayer::new_for_http())
         .layer(CorsLayer::permissive())
         .layer(middleware::from_fn(metrics_middleware))
@@ -1,12 +1,38 @@
 use crate::{
     config::Config,
-    services::{IssService, OsdrService, NasaService, SpaceXService},
+    domain::models::OsdrSyncSource,
+    services::{
+        EonetService, EpicService, IssService, JwstService, MediaService, OsdrService, NasaService,
+        SpaceXService,
+    },
     utils::metrics,
 };
 use std::{sync::Arc, time::{Duration, Instant}};
 use tokio::sync::Mutex;
 use tracing::{error, info, warn};
-use sqlx::PgPool;
+use sqlx::{pool::PoolConnection, PgPool, Postgres};
+
+/// Advisory lock задания, взятый на отдельном соединении пула: session-level блокировку
+/// можно снять только из той же сессии, поэтому соединение держится до release
+struct JobLock {
+    conn: PoolConnection<Postgres>,
+    lock_id: i64,
+}
+
+impl JobLock {
+    async fn release(mut self) -> Result<(), sqlx::Error> {
+        let result = sqlx::query("SELECT pg_advisory_unlock($1)")
+            .bind(self.lock_id)
+            .execute(&mut *self.conn)
+            .await;
+
+        if result.is_err() {
+            // Закрытие сессии снимает её блокировки - в пул соединение с блокировкой не вернётся
+            let _ = self.conn.close().await;
+        }
+        result.map(|_| ())
+    }
+}
 
 pub struct Scheduler {
     config: Config,
@@ -14,17 +40,26 @@ pub struct Scheduler {
     iss_service: Arc<Mutex<IssService>>,
     osdr_service: Arc<Mutex<OsdrService>>,
     nasa_service: Arc<Mutex<NasaService>>,
+    epic_service: Arc<Mutex<EpicService>>,
+    eonet_service: Arc<Mutex<EonetService>>,
+    jwst_service: Arc<Mutex<JwstService>>,
     spacex_service: Arc<Mutex<SpaceXService>>,
+    media_service: Arc<Mutex<MediaService>>,
 }
 
 impl Scheduler {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         config: Config,
         pool: PgPool,
         iss_service: Arc<Mutex<IssService>>,
         osdr_service: Arc<Mutex<OsdrService>>,
         nasa_service: Arc<Mutex<NasaService>>,
+        epic_service: Arc<Mutex<EpicService>>,
+        eonet_service: Arc<Mutex<EonetService>>,
+        jwst_service: Arc<Mutex<JwstService>>,
         spacex_service: Arc<Mutex<SpaceXService>>,
+        media_service: Arc<Mutex<MediaService>>,
     ) -> Self {
         Self {
             config,
@@ -32,7 +67,11 @@ impl Scheduler {
             iss_service,
             osdr_service,
             nasa_service,
+            epic_service,
+            eonet_service,
+            jwst_service,
             spacex_service,
+            media_service,
         }
     }
 
@@ -60,6 +99,18 @@ impl Scheduler {
         Ok(())
     }
 
+    /// Взять advisory lock задания на соединении, которое будет держаться до JobLock::release.
+    /// None - блокировку держит другой экземпляр
+    async fn try_acquire_job_lock(&self, lock_id: i64) -> Result<Option<JobLock>, sqlx::Error> {
+        let mut conn = self.pool.acquire().await?;
+        let (acquired,): (bool,) = sqlx::query_as("SELECT pg_try_advisory_lock($1)")
+            .bind(lock_id)
+            .fetch_one(&mut *conn)
+            .await?;
+
+        Ok(acquired.then_some(JobLock { conn, lock_id }))
+    }
+
     pub fn start(self: Arc<Self>) {
         // ISS fetcher with Advisory Lock (ID: 1001)
         {
@@ -135,13 +186,21 @@ impl Scheduler {
                             info!("OSDR scheduler: lock acquired, starting sync");
                             
                             let start = Instant::now();
-                            let mut service = scheduler.osdr_service.lock().await;
+                            let (result, dispatcher) = {
+                                let mut service = scheduler.osdr_service.lock().await;
+                                let result = service.sync_datasets(OsdrSyncSource::Scheduler).await;
+                                (result, service.notification_dispatcher())
+                            };
                             
-                            match service.sync_datasets().await {
-                                Ok(count) => {
+                            match result {
+                                Ok(run) => {
                                     let duration = start.elapsed().as_secs_f64();
+                                    let count = (run.inserted + run.updated + run.unchanged) as usize;
                                     metrics::record_osdr_sync(true, duration, count);
-                                    info!("OSDR synced {} datasets in {:.2}s", count, duration);
+                                    info!(
+                                        "OSDR synced {} datasets in {:.2}s ({} inserted, {} updated, {} failed)",
+                                        count, duration, run.inserted, run.updated, run.failed
+                                    );
                                 }
                                 Err(e) => {
                                     let duration = start.elapsed().as_secs_f64();
@@ -150,6 +209,11 @@ impl Scheduler {
                                 }
                             }
                             
+                            // Доставка уведомлений - без блокировки сервиса, но под advisory lock
+                            if let Err(e) = dispatcher.deliver_pending().await {
+                                warn!("Failed to deliver OSDR watchlist notifications: {:?}", e);
+                            }
+                            
                             // Release lock
                             if let Err(e) = scheduler.release_lock(LOCK_ID).await {
                                 error!("Failed to release OSDR advisory lock: {:?}", e);
@@ -167,6 +231,86 @@ impl Scheduler {
             });
         }
 
+        // OSDR tombstone retention with Advisory Lock (ID: 1003) - once a day
+        {
+            let scheduler = self.clone();
+            tokio::spawn(async move {
+                let retention_days = scheduler.config.osdr_tombstone_retention_days;
+                info!("Starting OSDR retention scheduler (every 86400s, retention {} days)", retention_days);
+                let mut interval = tokio::time::interval(Duration::from_secs(86400));
+                const LOCK_ID: i64 = 1003; // Unique lock ID for OSDR retention
+
+                loop {
+                    interval.tick().await;
+
+                    match scheduler.try_acquire_job_lock(LOCK_ID).await {
+                        Ok(Some(lock)) => {
+                            metrics::record_advisory_lock_acquired(LOCK_ID);
+
+                            let mut service = scheduler.osdr_service.lock().await;
+                            match service.purge_withdrawn(retention_days).await {
+                                Ok(purged) => info!("OSDR retention purged {} withdrawn datasets", purged),
+                                Err(e) => error!("Failed to purge withdrawn OSDR datasets: {:?}", e),
+                            }
+
+                            if let Err(e) = lock.release().await {
+                                error!("Failed to release OSDR retention advisory lock: {:?}", e);
+                            }
+                        }
+                        Ok(None) => {
+                            metrics::record_advisory_lock_failed(LOCK_ID);
+                            warn!("OSDR retention: another instance is running, skipping this tick");
+                        }
+                        Err(e) => {
+                            error!("Failed to acquire OSDR retention advisory lock: {:?}", e);
+                        }
+                    }
+                }
+            });
+        }
+
+        // Media mirror with Advisory Lock (ID: 1004)
+        {
+            let scheduler = self.clone();
+            tokio::spawn(async move {
+                let every = scheduler.config.media_mirror_every_seconds;
+                info!("Starting media mirror scheduler (every {}s)", every);
+                let mut interval = tokio::time::interval(Duration::from_secs(every));
+                const LOCK_ID: i64 = 1004; // Unique lock ID for media mirror
+                const BATCH: i64 = 20;
+
+                loop {
+                    interval.tick().await;
+
+                    match scheduler.try_acquire_job_lock(LOCK_ID).await {
+                        Ok(Some(lock)) => {
+                            metrics::record_advisory_lock_acquired(LOCK_ID);
+
+                            let mirror = scheduler.media_service.lock().await.mirror();
+                            match mirror.mirror_pending(BATCH).await {
+                                Ok(report) => info!(
+                                    "Media mirror: {} mirrored, {} deduplicated, {} failed, {} evicted, {} repaired",
+                                    report.mirrored, report.deduplicated, report.failed, report.evicted, report.repaired
+                                ),
+                                Err(e) => error!("Failed to mirror media: {:?}", e),
+                            }
+
+                            if let Err(e) = lock.release().await {
+                                error!("Failed to release media mirror advisory lock: {:?}", e);
+                            }
+                        }
+                        Ok(None) => {
+                            metrics::record_advisory_lock_failed(LOCK_ID);
+                            warn!("Media mirror: another instance is running, skipping this tick");
+                        }
+                        Err(e) => {
+                            error!("Failed to acquire media mirror advisory lock: {:?}", e);
+                        }
+                    }
+                }
+            });
+        }
+
         // APOD fetcher
         {
             let scheduler = self.clone();
@@ -178,7 +322,7 @@ impl Scheduler {
                     interval.tick().await;
                     
                     let mut service = scheduler.nasa_service.lock().await;
-                    if let Err(e) = service.get_apod().await {
+                    if let Err(e) = service.refresh_apod().await {
                         error!("Failed to fetch APOD: {:?}", e); 
                     } else {
                         info!("APOD fetched successfully");
@@ -198,10 +342,9 @@ impl Scheduler {
                     interval.tick().await;
                     
                     let mut service = scheduler.nasa_service.lock().await;
-                    if let Err(e) = service.get_neo().await {
-                        error!("Failed to fetch NEO: {:?}", e); 
-                    } else {
-                        info!("NEO fetched successfully");
+                    match service.refresh_neo().await {
+                        Ok(stored) => info!("NEO fetched successfully ({} close approaches)", stored),
+                        Err(e) => error!("Failed to fetch NEO: {:?}", e),
                     }
                 }
             });
@@ -218,9 +361,73 @@ impl Scheduler {
                     interval.tick().await;
                     
                     let mut service = scheduler.nasa_service.lock().await;
-                    let _ = service.get_donki_flr().await;
-                    let _ = service.get_donki_cme().await;
-                    info!("DONKI events fetched");
+                    match service.refresh_donki().await {
+                        Ok(count) => info!("DONKI events fetched ({} events)", count),
+                        Err(e) => error!("Failed to fetch DONKI events: {:?}", e),
+                    }
+                }
+            });
+        }
+
+        // EPIC fetcher
+        {
+            let scheduler = self.clone();
+            tokio::spawn(async move {
+                info!("Starting EPIC scheduler (every {}s)", scheduler.config.epic_every_seconds);
+                let mut interval = tokio::time::interval(Duration::from_secs(scheduler.config.epic_every_seconds));
+                
+                loop {
+                    interval.tick().await;
+                    
+                    let mut service = scheduler.epic_service.lock().await;
+                    match service.refresh_latest().await {
+                        Ok(count) => info!("EPIC latest day fetched ({} images)", count),
+                        Err(e) => error!("Failed to fetch EPIC images: {:?}", e),
+                    }
+                }
+            });
+        }
+
+        // EONET fetcher
+        {
+            let scheduler = self.clone();
+            tokio::spawn(async move {
+                info!("Starting EONET scheduler (every {}s)", scheduler.config.eonet_every_seconds);
+                let mut interval = tokio::time::interval(Duration::from_secs(scheduler.config.eonet_every_seconds));
+                
+                loop {
+                    interval.tick().await;
+                    
+                    let mut service = scheduler.eonet_service.lock().await;
+                    match service.refresh().await {
+                        Ok(count) => info!("EONET events fetched ({} events)", count),
+                        Err(e) => error!("Failed to fetch EONET events: {:?}", e),
+                    }
+                }
+            });
+        }
+
+        // JWST tracked programs prefetch
+        {
+            let scheduler = self.clone();
+            tokio::spawn(async move {
+                let every = scheduler.config.jwst_every_seconds;
+                info!(
+                    "Starting JWST scheduler (every {}s, programs: {})",
+                    every,
+                    scheduler.config.jwst_tracked_programs.join(",")
+                );
+                let mut interval = tokio::time::interval(Duration::from_secs(every));
+                
+                loop {
+                    interval.tick().await;
+                    
+                    // Отметка свежести переживает один пропущенный прогон
+                    let mut service = scheduler.jwst_service.lock().await;
+                    match service.refresh_tracked((every * 2) as usize).await {
+                        Ok(count) => info!("JWST tracked programs refreshed ({} new observations)", count),
+                        Err(e) => error!("Failed to refresh JWST programs: {:?}", e),
+                    }
                 }
             });
         }
@@ -231,15 +438,43 @@ impl Scheduler {
             tokio::spawn(async move {
                 info!("Starting SpaceX scheduler (every {}s)", scheduler.config.spacex_every_seconds);
                 let mut interval = tokio::time::interval(Duration::from_secs(scheduler.config.spacex_every_seconds));
-                
+                const LOCK_ID: i64 = 1005; // Unique lock ID for SpaceX sync
+
                 loop {
                     interval.tick().await;
-                    
-                    let mut service = scheduler.spacex_service.lock().await;
-                    if let Err(e) = service.get_next_launch().await {
-                        error!("Failed to fetch SpaceX launch: {:?}", e); 
-                    } else {
-                        info!("SpaceX next launch fetched");
+
+                    // История расписания пишется по разнице с прошлым прогоном - прогон должен быть один
+                    match scheduler.try_acquire_job_lock(LOCK_ID).await {
+                        Ok(Some(lock)) => {
+                            metrics::record_advisory_lock_acquired(LOCK_ID);
+
+                            let mut service = scheduler.spacex_service.lock().await;
+                            match service.sync().await {
+                                Ok(report) => info!(
+                                    "SpaceX archive synced ({} launches, {} rockets, {} launchpads, {} payloads, {} skipped, {} schedule changes)",
+                                    report.launches, report.rockets, report.launchpads, report.payloads, report.skipped,
+                                    report.schedule_changes
+                                ),
+                                Err(e) => error!("Failed to sync SpaceX archive: {:?}", e),
+                            }
+
+                            if let Err(e) = service.get_next_launch().await {
+                                error!("Failed to fetch SpaceX launch: {:?}", e);
+                            } else {
+                                info!("SpaceX next launch fetched");
+                            }
+
+                            if let Err(e) = lock.release().await {
+                                error!("Failed to release SpaceX advisory lock: {:?}", e);
+                            }
+                        }
+                        Ok(None) => {
+                            metrics::record_advisory_lock_failed(LOCK_ID);
+                            warn!("SpaceX sync: another instance is running, skipping this tick");
+                        }
+                        Err(e) => {
+                            error!("Failed to acquire SpaceX advisory lock: {:?}", e);
+                        }
                     }
                 }
             });
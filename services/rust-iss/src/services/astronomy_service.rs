@@ -0,0 +1,131 @@
+use crate::{
+    clients::AstronomyClient,
+    domain::{
+        error::{ApiError, ErrorDetail},
+        models::{AstroBody, AstroObserver, SkyReport},
+        sky,
+    },
+    repo::cache_repo::CacheRepo,
+};
+use chrono::NaiveDate;
+use serde_json::Value;
+
+/// События (затмения) меняются редко - кэш 6 часов
+const EVENTS_TTL_SECONDS: usize = 6 * 3600;
+
+/// Положения тел - кэш 1 час
+const POSITIONS_TTL_SECONDS: usize = 3600;
+
+/// Максимальная длина диапазона дат (дней, включительно)
+const MAX_EVENTS_SPAN_DAYS: i64 = 366;
+const MAX_POSITIONS_SPAN_DAYS: i64 = 31;
+
+/// Границы дат: упрощённые формулы эфемерид (utils::ephemeris) точны примерно в 1950-2050 гг.,
+/// тот же диапазон держим и для AstronomyAPI
+const ASTRO_FIRST_DATE: (i32, u32, u32) = (1950, 1, 1);
+const ASTRO_LAST_DATE: (i32, u32, u32) = (2050, 12, 31);
+
+pub struct AstronomyService {
+    astronomy_client: AstronomyClient,
+    cache_repo: CacheRepo,
+}
+
+impl AstronomyService {
+    pub fn new(astronomy_client: AstronomyClient, cache_repo: CacheRepo) -> Self {
+        Self {
+            astronomy_client,
+            cache_repo,
+        }
+    }
+
+    /// События тела для наблюдателя (ответ AstronomyAPI в формате rows)
+    pub async fn get_events(&mut self, body: AstroBody, observer: &AstroObserver) -> Result<Value, ApiError> {
+        validate_span(observer, MAX_EVENTS_SPAN_DAYS)?;
+
+        let cache_key = format!("astro:events:{}:{}", body.as_str(), observer_key(observer));
+        if let Some(cached) = self.cache_repo.get::<Value>(&cache_key).await? {
+            return Ok(cached);
+        }
+
+        let mut params = observer_params(observer);
+        params.push(("output", "rows".to_string()));
+        let events = self.astronomy_client.fetch_events(body.as_str(), &params).await?;
+
+        self.cache_repo.set(&cache_key, &events, EVENTS_TTL_SECONDS).await?;
+        Ok(events)
+    }
+
+    /// Положения Солнца, Луны и планет на каждую дату диапазона
+    pub async fn get_positions(&mut self, observer: &AstroObserver) -> Result<Value, ApiError> {
+        validate_span(observer, MAX_POSITIONS_SPAN_DAYS)?;
+
+        let cache_key = format!("astro:positions:{}", observer_key(observer));
+        if let Some(cached) = self.cache_repo.get::<Value>(&cache_key).await? {
+            return Ok(cached);
+        }
+
+        let positions = self
+            .astronomy_client
+            .fetch_positions(&observer_params(observer))
+            .await?;
+
+        self.cache_repo.set(&cache_key, &positions, POSITIONS_TTL_SECONDS).await?;
+        Ok(positions)
+    }
+
+    /// Восход, заход, сумерки и фаза Луны - считаются локально, без AstronomyAPI
+    pub fn get_sky(&self, lat: f64, lon: f64, date: NaiveDate) -> Result<SkyReport, ApiError> {
+        validate_astro_date("date", date)?;
+        Ok(sky::sky_report(lat, lon, date))
+    }
+}
+
+/// Параметры наблюдателя; координаты округлены до ~100 м, чтобы близкие запросы делили кэш
+fn observer_params(observer: &AstroObserver) -> Vec<(&'static str, String)> {
+    vec![
+        ("latitude", format!("{:.3}", observer.lat)),
+        ("longitude", format!("{:.3}", observer.lon)),
+        ("elevation", format!("{:.0}", observer.elevation)),
+        ("from_date", observer.from.format("%Y-%m-%d").to_string()),
+        ("to_date", observer.to.format("%Y-%m-%d").to_string()),
+        ("time", observer.time.format("%H:%M:%S").to_string()),
+    ]
+}
+
+fn observer_key(observer: &AstroObserver) -> String {
+    observer_params(observer)
+        .into_iter()
+        .map(|(_, value)| value)
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+/// Проверить, что дата лежит в границах, где расчёты имеют смысл
+pub fn validate_astro_date(field: &str, date: NaiveDate) -> Result<(), ApiError> {
+    let (first, last) = (ymd(ASTRO_FIRST_DATE), ymd(ASTRO_LAST_DATE));
+    if date < first || date > last {
+        return Err(ApiError::ValidationError(vec![ErrorDetail {
+            field: field.to_string(),
+            message: format!("Date must be between {} and {}", first, last),
+        }]));
+    }
+    Ok(())
+}
+
+fn ymd((y, m, d): (i32, u32, u32)) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).expect("valid date constant")
+}
+
+fn validate_span(observer: &AstroObserver, max_days: i64) -> Result<(), ApiError> {
+    if observer.from > observer.to || (observer.to - observer.from).num_days() >= max_days {
+        return Err(ApiError::ValidationError(vec![ErrorDetail {
+            field: "to".to_string(),
+            message: format!("Range must be ordered and not exceed {} days", max_days),
+        }]));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "astronomy_service_tests.rs"]
+mod astronomy_service_tests;
@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use chrono::{Duration, NaiveTime};
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn observer(lat: f64, lon: f64, from: &str, to: &str) -> AstroObserver {
+        AstroObserver {
+            lat,
+            lon,
+            elevation: 35.0,
+            from: date(from),
+            to: date(to),
+            time: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_validate_span() {
+        assert!(validate_span(&observer(55.75, 37.62, "2024-01-01", "2024-01-01"), 1).is_ok());
+        // Граница включительная: 31 день при лимите 31
+        assert!(validate_span(&observer(55.75, 37.62, "2024-01-01", "2024-01-31"), 31).is_ok());
+        assert!(validate_span(&observer(55.75, 37.62, "2024-01-01", "2024-02-01"), 31).is_err());
+        assert!(validate_span(&observer(55.75, 37.62, "2024-01-02", "2024-01-01"), 31).is_err());
+    }
+
+    #[test]
+    fn test_validate_astro_date_bounds() {
+        assert!(validate_astro_date("date", date("1950-01-01")).is_ok());
+        assert!(validate_astro_date("date", date("2050-12-31")).is_ok());
+        assert!(validate_astro_date("date", date("1949-12-31")).is_err());
+        assert!(validate_astro_date("date", date("2051-01-01")).is_err());
+
+        match validate_astro_date("from", NaiveDate::MAX - Duration::days(1)) {
+            Err(ApiError::ValidationError(details)) => assert_eq!(details[0].field, "from"),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_observer_params() {
+        let params = observer_params(&observer(55.755_83, 37.617_3, "2024-03-01", "2024-03-03"));
+
+        assert_eq!(
+            params,
+            vec![
+                ("latitude", "55.756".to_string()),
+                ("longitude", "37.617".to_string()),
+                ("elevation", "35".to_string()),
+                ("from_date", "2024-03-01".to_string()),
+                ("to_date", "2024-03-03".to_string()),
+                ("time", "21:00:00".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_observer_key_shares_cache_for_nearby_coordinates() {
+        let key = observer_key(&observer(55.7558, 37.6173, "2024-03-01", "2024-03-03"));
+
+        assert_eq!(key, "55.756:37.617:35:2024-03-01:2024-03-03:21:00:00");
+        // Разница в десятки метров - тот же ключ кэша
+        assert_eq!(key, observer_key(&observer(55.7561, 37.6168, "2024-03-01", "2024-03-03")));
+        assert_ne!(key, observer_key(&observer(55.7572, 37.6173, "2024-03-01", "2024-03-03")));
+        assert_ne!(key, observer_key(&observer(55.7558, 37.6173, "2024-03-01", "2024-03-04")));
+    }
+}
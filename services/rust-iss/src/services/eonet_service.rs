@@ -0,0 +1,79 @@
+use crate::{
+    clients::EonetClient,
+    domain::{
+        eonet::{find_overflights, parse_events},
+        error::{ApiError, ErrorDetail},
+        models::{EonetEvent, EonetStatus, EventOverflight},
+    },
+    repo::{eonet_repo::EonetRepo, iss_repo::IssRepo},
+};
+use chrono::{DateTime, Duration, Utc};
+
+/// За сколько дней запрашивать события при обновлении
+const EONET_LOOKBACK_DAYS: u32 = 30;
+
+/// Максимальный интервал трассы МКС для поиска пролётов (дней)
+const OVERFLIGHT_MAX_SPAN_DAYS: i64 = 7;
+
+pub struct EonetService {
+    eonet_client: EonetClient,
+    eonet_repo: EonetRepo,
+    iss_repo: IssRepo,
+}
+
+impl EonetService {
+    pub fn new(eonet_client: EonetClient, eonet_repo: EonetRepo, iss_repo: IssRepo) -> Self {
+        Self {
+            eonet_client,
+            eonet_repo,
+            iss_repo,
+        }
+    }
+
+    /// Загрузить события EONET за последние 30 дней (для планировщика); возвращает число сохранённых
+    pub async fn refresh(&mut self) -> Result<usize, ApiError> {
+        let response = self.eonet_client.fetch_events(EONET_LOOKBACK_DAYS).await?;
+        let (events, skipped) = parse_events(response).map_err(ApiError::UpstreamError)?;
+        if skipped > 0 {
+            tracing::warn!("EONET: skipped {} events without category or geometry", skipped);
+        }
+
+        self.eonet_repo.upsert_events(&events).await
+    }
+
+    /// Сохранённые события
+    pub async fn get_events(
+        &mut self,
+        category: Option<&str>,
+        status: EonetStatus,
+        limit: i64,
+    ) -> Result<Vec<EonetEvent>, ApiError> {
+        self.eonet_repo.get_events(category, status, limit).await
+    }
+
+    /// События, над которыми МКС пролетела ближе radius_km за интервал (по iss_fetch_log)
+    pub async fn get_overflown_events(
+        &mut self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        radius_km: f64,
+    ) -> Result<Vec<EventOverflight>, ApiError> {
+        if start > end || end - start > Duration::days(OVERFLIGHT_MAX_SPAN_DAYS) {
+            return Err(ApiError::ValidationError(vec![ErrorDetail {
+                field: "end".to_string(),
+                message: format!("Range must be ordered and not exceed {} days", OVERFLIGHT_MAX_SPAN_DAYS),
+            }]));
+        }
+
+        let track = self
+            .iss_repo
+            .get_by_timerange(start.naive_utc(), end.naive_utc())
+            .await?;
+        if track.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let events = self.eonet_repo.get_active_between(start, end).await?;
+        Ok(find_overflights(&events, &track, radius_km))
+    }
+}
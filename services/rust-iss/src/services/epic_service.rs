@@ -0,0 +1,194 @@
+use crate::{
+    clients::{
+        nasa_budget::{quota_exhausted, RequestPriority},
+        NasaClient,
+    },
+    domain::{
+        epic::{identifier_date, nearest, parse_available_dates, parse_images},
+        error::{ApiError, ErrorDetail},
+        models::{EpicCollection, EpicImage},
+        provenance,
+    },
+    repo::cache_repo::CacheRepo,
+};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+
+/// Первый день снимков EPIC
+const EPIC_FIRST_DATE: (i32, u32, u32) = (2015, 6, 13);
+
+/// Снимки последнего дня (секунд)
+const LATEST_TTL_SECONDS: usize = 3 * 3600;
+
+/// Прошедшие дни больше не меняются (секунд)
+const PAST_DAY_TTL_SECONDS: usize = 30 * 24 * 3600;
+
+/// Последние дни ещё дополняются снимками (секунд)
+const RECENT_DAY_TTL_SECONDS: usize = 3600;
+
+const DATES_TTL_SECONDS: usize = 6 * 3600;
+
+/// Устаревшая копия последнего дня для выдачи при исчерпанном лимите (секунд)
+const STALE_TTL_SECONDS: usize = 7 * 24 * 3600;
+
+pub struct EpicService {
+    nasa_client: NasaClient,
+    cache_repo: CacheRepo,
+}
+
+impl EpicService {
+    pub fn new(nasa_client: NasaClient, cache_repo: CacheRepo) -> Self {
+        Self {
+            nasa_client,
+            cache_repo,
+        }
+    }
+
+    /// Снимки последнего доступного дня (кэш 3 часа; при исчерпанном лимите - устаревшая копия)
+    pub async fn get_latest(&mut self, collection: EpicCollection) -> Result<Vec<EpicImage>, ApiError> {
+        let cache_key = format!("epic:{}:latest", collection.as_str());
+        if let Some(cached) = self.cache_repo.get::<Vec<EpicImage>>(&cache_key).await? {
+            tracing::info!("EPIC {} latest from cache", collection.as_str());
+            return Ok(cached);
+        }
+
+        if !self.nasa_client.has_budget(RequestPriority::OnDemand) {
+            tracing::warn!("NASA API budget low, serving stale EPIC {}", collection.as_str());
+            provenance::record_stale(format!("NASA API quota is low, serving the last EPIC {} day", collection.as_str()));
+            return self
+                .cache_repo
+                .get::<Vec<EpicImage>>(&format!("{}:stale", cache_key))
+                .await?
+                .ok_or_else(quota_exhausted);
+        }
+
+        self.load_latest(collection).await
+    }
+
+    /// Обновить последний день всех коллекций (для планировщика); возвращает число снимков
+    pub async fn refresh_latest(&mut self) -> Result<usize, ApiError> {
+        let mut count = 0;
+        for collection in EpicCollection::ALL {
+            if !self.nasa_client.has_budget(RequestPriority::Scheduled) {
+                return Err(quota_exhausted());
+            }
+            count += self.load_latest(collection).await?.len();
+        }
+        Ok(count)
+    }
+
+    async fn load_latest(&mut self, collection: EpicCollection) -> Result<Vec<EpicImage>, ApiError> {
+        let response = self.nasa_client.fetch_epic_latest(collection).await?;
+        let images = parse_images(collection, response).map_err(ApiError::UpstreamError)?;
+
+        let cache_key = format!("epic:{}:latest", collection.as_str());
+        self.cache_repo.set(&cache_key, &images, LATEST_TTL_SECONDS).await?;
+        self.cache_repo
+            .set(&format!("{}:stale", cache_key), &images, STALE_TTL_SECONDS)
+            .await?;
+
+        // Тот же день доступен и по дате
+        if let Some(first) = images.first() {
+            let date = first.taken_at.date_naive();
+            self.cache_repo
+                .set(&date_cache_key(collection, date), &images, day_ttl(date))
+                .await?;
+        }
+
+        tracing::info!("EPIC {} latest: {} images", collection.as_str(), images.len());
+        Ok(images)
+    }
+
+    /// Снимки за дату
+    pub async fn get_by_date(&mut self, collection: EpicCollection, date: NaiveDate) -> Result<Vec<EpicImage>, ApiError> {
+        validate_date(date)?;
+
+        let cache_key = date_cache_key(collection, date);
+        if let Some(cached) = self.cache_repo.get::<Vec<EpicImage>>(&cache_key).await? {
+            return Ok(cached);
+        }
+
+        if !self.nasa_client.has_budget(RequestPriority::OnDemand) {
+            return Err(quota_exhausted());
+        }
+
+        let response = self
+            .nasa_client
+            .fetch_epic_date(collection, &date.format("%Y-%m-%d").to_string())
+            .await?;
+        let images = parse_images(collection, response).map_err(ApiError::UpstreamError)?;
+        self.cache_repo.set(&cache_key, &images, day_ttl(date)).await?;
+
+        Ok(images)
+    }
+
+    /// Даты, за которые есть снимки (кэш 6 часов)
+    pub async fn get_available_dates(&mut self, collection: EpicCollection) -> Result<Vec<NaiveDate>, ApiError> {
+        let cache_key = format!("epic:{}:dates", collection.as_str());
+        if let Some(cached) = self.cache_repo.get::<Vec<NaiveDate>>(&cache_key).await? {
+            return Ok(cached);
+        }
+
+        if !self.nasa_client.has_budget(RequestPriority::OnDemand) {
+            return Err(quota_exhausted());
+        }
+
+        let response = self.nasa_client.fetch_epic_available(collection).await?;
+        let dates = parse_available_dates(response).map_err(ApiError::UpstreamError)?;
+        self.cache_repo.set(&cache_key, &dates, DATES_TTL_SECONDS).await?;
+
+        Ok(dates)
+    }
+
+    /// Метаданные снимка по идентификатору (YYYYMMDDhhmmss)
+    pub async fn get_image(&mut self, collection: EpicCollection, identifier: &str) -> Result<EpicImage, ApiError> {
+        let date = identifier_date(identifier).ok_or_else(|| {
+            ApiError::ValidationError(vec![ErrorDetail {
+                field: "identifier".to_string(),
+                message: "Expected EPIC identifier in YYYYMMDDhhmmss format".to_string(),
+            }])
+        })?;
+
+        self.get_by_date(collection, date)
+            .await?
+            .into_iter()
+            .find(|image| image.identifier == identifier)
+            .ok_or_else(|| ApiError::NotFound(format!("EPIC image {} not found", identifier)))
+    }
+
+    /// Снимок, ближайший по времени к моменту at (например, к времени положения МКС)
+    pub async fn get_nearest(&mut self, collection: EpicCollection, at: DateTime<Utc>) -> Result<EpicImage, ApiError> {
+        let mut images = self.get_by_date(collection, at.date_naive()).await?;
+        if images.is_empty() {
+            // EPIC публикует снимки с задержкой около суток
+            images = self.get_latest(collection).await?;
+        }
+
+        nearest(&images, at)
+            .cloned()
+            .ok_or_else(|| ApiError::NotFound(format!("No EPIC images near {}", at)))
+    }
+}
+
+fn date_cache_key(collection: EpicCollection, date: NaiveDate) -> String {
+    format!("epic:{}:date:{}", collection.as_str(), date)
+}
+
+fn day_ttl(date: NaiveDate) -> usize {
+    if date < Utc::now().date_naive() - Duration::days(2) {
+        PAST_DAY_TTL_SECONDS
+    } else {
+        RECENT_DAY_TTL_SECONDS
+    }
+}
+
+fn validate_date(date: NaiveDate) -> Result<(), ApiError> {
+    let (y, m, d) = EPIC_FIRST_DATE;
+    let first = NaiveDate::from_ymd_opt(y, m, d).unwrap_or(NaiveDate::MIN);
+    if date < first || date > Utc::now().date_naive() {
+        return Err(ApiError::ValidationError(vec![ErrorDetail {
+            field: "date".to_string(),
+            message: format!("Date must be between {} and today", first),
+        }]));
+    }
+    Ok(())
+}
@@ -1,9 +1,17 @@
 use crate::{
     clients::IssClient,
-    domain::{error::ApiError, models::IssPosition},
+    domain::{
+        error::ApiError,
+        iss_pass::find_passes,
+        models::{IssPassPrediction, IssPosition, Tle},
+    },
     repo::{cache_repo::CacheRepo, iss_repo::IssRepo},
+    utils::orbit::parse_tle,
 };
-use chrono::{DateTime, TimeZone, Utc};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+/// Сколько хранить TLE в кэше (секунд); элементы МКС обновляются несколько раз в сутки
+const TLE_TTL_SECONDS: usize = 6 * 3600;
 
 pub struct IssService {
     iss_client: IssClient,
@@ -123,6 +131,42 @@ impl IssService {
     ) -> Result<Vec<IssPosition>, ApiError> {
         self.iss_repo.get_history(start, end, limit).await
     }
+
+    /// Актуальные элементы орбиты МКС (кэш 6 часов)
+    pub async fn get_tle(&mut self) -> Result<Tle, ApiError> {
+        if let Some(cached) = self.cache_repo.get::<Tle>("iss:tle").await? {
+            return Ok(cached);
+        }
+
+        let api_tle = self.iss_client.fetch_tle().await?;
+        let name = api_tle.header.as_deref().unwrap_or("ISS (ZARYA)");
+        let tle = parse_tle(name, &api_tle.line1, &api_tle.line2)
+            .map_err(|e| ApiError::UpstreamError(format!("Invalid ISS TLE: {}", e)))?;
+
+        self.cache_repo.set("iss:tle", &tle, TLE_TTL_SECONDS).await?;
+        Ok(tle)
+    }
+
+    /// Прогноз пролётов МКС над наблюдателем на ближайшие days суток
+    pub async fn predict_passes(
+        &mut self,
+        lat: f64,
+        lon: f64,
+        days: i64,
+        min_elevation_deg: f64,
+    ) -> Result<IssPassPrediction, ApiError> {
+        let tle = self.get_tle().await?;
+        let start = Utc::now();
+        let passes = find_passes(&tle, lat, lon, start, start + Duration::days(days), min_elevation_deg);
+
+        Ok(IssPassPrediction {
+            latitude: lat,
+            longitude: lon,
+            min_elevation_deg,
+            tle_epoch: tle.epoch,
+            passes,
+        })
+    }
 }
 
 #[cfg(test)]
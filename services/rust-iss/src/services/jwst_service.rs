@@ -1,35 +1,305 @@
 use crate::{
-    clients::JwstClient,
-    domain::error::ApiError,
-    repo::cache_repo::CacheRepo,
+    clients::{fixtures, JwstClient},
+    domain::{
+        error::{ApiError, ErrorDetail},
+        fits::{self, is_product_name, next_hdu_offset, parse_hdu, summarize},
+        jwst::parse_images,
+        models::{FitsHdu, FitsHeader, JwstImage, JwstNewObservations},
+        provenance,
+    },
+    repo::{
+        cache_repo::CacheRepo,
+        jwst_repo::{JwstImageFilter, JwstRepo},
+        media_repo::MediaRepo,
+    },
+    services::media_service::is_image_url,
 };
+use chrono::{DateTime, Duration, Utc};
 use serde_json::Value;
 
+/// Сколько файлов программы отдавать в /jwst/images/:program_id
+const PROGRAM_IMAGES_LIMIT: i64 = 500;
+
+/// Окно /jwst/new по умолчанию, пока планировщик ещё не отработал
+const NEW_DEFAULT_HOURS: i64 = 24;
+
+/// Сколько байт запрашивать на заголовок одного HDU (32 блока - около 1150 карточек)
+const FITS_RANGE_BYTES: usize = 32 * fits::BLOCK_SIZE;
+
+/// Сколько HDU читать из удалённого продукта (один Range-запрос на HDU)
+const FITS_REMOTE_HDUS: usize = 16;
+
 pub struct JwstService {
     jwst_client: JwstClient,
+    jwst_repo: JwstRepo,
+    media_repo: MediaRepo,
     cache_repo: CacheRepo,
+    fallbacks: bool,
+    tracked_programs: Vec<String>,
 }
 
 impl JwstService {
-    pub fn new(jwst_client: JwstClient, cache_repo: CacheRepo) -> Self {
+    pub fn new(
+        jwst_client: JwstClient,
+        jwst_repo: JwstRepo,
+        media_repo: MediaRepo,
+        cache_repo: CacheRepo,
+        fallbacks: bool,
+        tracked_programs: Vec<String>,
+    ) -> Self {
         Self {
             jwst_client,
+            jwst_repo,
+            media_repo,
             cache_repo,
+            fallbacks,
+            tracked_programs,
+        }
+    }
+
+    /// Изображения программы из каталога; раз в 30 минут каталог программы обновляется из jwstapi.com.
+    /// Если API недоступен - сохранённый каталог, а при пустом каталоге - демо-данные (если разрешены)
+    pub async fn get_images(&mut self, program_id: &str) -> Result<Vec<JwstImage>, ApiError> {
+        let cache_key = program_cache_key(program_id);
+        let filter = JwstImageFilter {
+            program: Some(program_id),
+            ..Default::default()
+        };
+
+        if self.cache_repo.get::<usize>(&cache_key).await?.is_some() {
+            tracing::info!("JWST catalog for program {} is fresh", program_id);
+            return self.jwst_repo.get_images(&filter, PROGRAM_IMAGES_LIMIT).await;
+        }
+
+        match self.load_program(program_id).await {
+            Ok(stored) => {
+                self.cache_repo.set(&cache_key, &stored, 1800).await?; // 30 минут
+                self.jwst_repo.get_images(&filter, PROGRAM_IMAGES_LIMIT).await
+            }
+            Err(e) => {
+                let stored = self.jwst_repo.get_images(&filter, PROGRAM_IMAGES_LIMIT).await?;
+                if !stored.is_empty() {
+                    tracing::warn!("JWST program {} refresh failed, serving stored catalog: {}", program_id, e);
+                    provenance::record_stale(format!("JWST API unavailable, serving stored catalog: {}", e));
+                    return Ok(stored);
+                }
+                if !self.fallbacks {
+                    return Err(e);
+                }
+
+                // Демо-данные только в ответ: ни в БД, ни в кэш, ни в очередь зеркалирования
+                tracing::warn!("JWST program {} unavailable, serving demo data: {}", program_id, e);
+                provenance::record_fallback(format!("JWST API unavailable, serving demo data: {}", e));
+                parse_images(fixtures::jwst_images(), program_id).map_err(ApiError::InternalError)
+            }
         }
     }
 
-    /// Получить изображения JWST по программе (кэш 30 минут)
-    pub async fn get_images(&mut self, program_id: &str) -> Result<Value, ApiError> {
-        let cache_key = format!("jwst:images:{}", program_id);
+    /// Обновить отслеживаемые программы (для планировщика); возвращает число новых наблюдений.
+    /// Отметка свежести живёт marker_ttl секунд, чтобы галерея до следующего прогона читала только БД
+    pub async fn refresh_tracked(&mut self, marker_ttl: usize) -> Result<i64, ApiError> {
+        let started_at = Utc::now();
+        let mut failed = 0;
 
-        if let Some(cached) = self.cache_repo.get::<Value>(&cache_key).await? {
-            tracing::info!("JWST images from cache for program {}", program_id);
+        for program_id in self.tracked_programs.clone() {
+            match self.load_program(&program_id).await {
+                Ok(stored) => {
+                    self.cache_repo
+                        .set(&program_cache_key(&program_id), &stored, marker_ttl)
+                        .await?;
+                }
+                Err(e) => {
+                    tracing::warn!("JWST program {} refresh failed: {}", program_id, e);
+                    failed += 1;
+                }
+            }
+        }
+
+        if failed > 0 && failed == self.tracked_programs.len() {
+            return Err(ApiError::UpstreamError(format!(
+                "All {} tracked JWST programs failed to refresh",
+                failed
+            )));
+        }
+
+        let new_images = self
+            .jwst_repo
+            .count_new_images(&self.tracked_programs, started_at.naive_utc())
+            .await?;
+        // Граница /jwst/new хранится в БД: одинакова для всех экземпляров и переживает рестарт
+        self.jwst_repo.record_refresh_run(started_at, new_images).await?;
+        Ok(new_images)
+    }
+
+    /// Наблюдения отслеживаемых программ, впервые попавшие в каталог после since
+    /// (по умолчанию - с начала последнего планового обновления)
+    pub async fn get_new_observations(
+        &mut self,
+        since: Option<DateTime<Utc>>,
+        limit: i64,
+    ) -> Result<JwstNewObservations, ApiError> {
+        let last_refresh_at = self.jwst_repo.get_last_refresh_at().await?;
+        let since = since
+            .or(last_refresh_at)
+            .unwrap_or_else(|| Utc::now() - Duration::hours(NEW_DEFAULT_HOURS));
+
+        let images = self
+            .jwst_repo
+            .get_new_images(&self.tracked_programs, since.naive_utc(), limit)
+            .await?;
+
+        Ok(JwstNewObservations {
+            since,
+            programs: self.tracked_programs.clone(),
+            last_refresh_at,
+            images,
+        })
+    }
+
+    /// Заголовки FITS продукта из MAST: по Range-запросу на HDU, данные изображений не скачиваются.
+    /// Ключевые поля сохраняются у файлов каталога с тем же именем
+    pub async fn inspect_product(&mut self, file: &str) -> Result<FitsHeader, ApiError> {
+        validate_product_name(file)?;
+
+        let cache_key = format!("jwst:fits:{}", file);
+        if let Some(cached) = self.cache_repo.get::<FitsHeader>(&cache_key).await? {
             return Ok(cached);
         }
 
-        let images = self.jwst_client.fetch_images(program_id).await?;
-        self.cache_repo.set(&cache_key, &images, 1800).await?; // 30 минут
+        let url = self.jwst_client.product_url(file);
+        let (hdus, truncated) = read_remote_hdus(&self.jwst_client, &url, file).await?;
+
+        if hdus.is_empty() {
+            return Err(ApiError::UpstreamError(format!("{}: FITS header is incomplete", file)));
+        }
+
+        let summary = summarize(&hdus);
+        let stem = file.trim_end_matches(".fits");
+        let updated = self.jwst_repo.set_fits_summary(stem, &summary).await?;
+        tracing::info!("FITS {}: {} HDUs, {} catalog rows updated", file, hdus.len(), updated);
 
-        Ok(images)
+        let header = FitsHeader {
+            file: file.to_string(),
+            source_url: Some(url),
+            hdus,
+            summary,
+            truncated,
+        };
+        self.cache_repo.set(&cache_key, &header, 86400).await?; // 24 часа
+        Ok(header)
     }
-}
\ No newline at end of file
+
+    /// Заголовки загруженного FITS (файл целиком или его начало); в каталог не сохраняются
+    pub fn inspect_upload(&self, file: &str, bytes: &[u8]) -> Result<FitsHeader, ApiError> {
+        validate_product_name(file)?;
+
+        let invalid = |message: String| {
+            ApiError::ValidationError(vec![ErrorDetail {
+                field: "body".to_string(),
+                message,
+            }])
+        };
+
+        let (hdus, truncated) = fits::parse_file(bytes).map_err(invalid)?;
+        if hdus.is_empty() {
+            return Err(invalid(format!(
+                "Upload at least the complete primary header ({}-byte blocks up to END)",
+                fits::BLOCK_SIZE
+            )));
+        }
+
+        Ok(FitsHeader {
+            file: file.to_string(),
+            source_url: None,
+            summary: summarize(&hdus),
+            hdus,
+            truncated,
+        })
+    }
+
+    /// Каталог по фильтрам (только из БД)
+    pub async fn list_images(&mut self, filter: &JwstImageFilter<'_>, limit: i64) -> Result<Vec<JwstImage>, ApiError> {
+        self.jwst_repo.get_images(filter, limit).await
+    }
+
+    /// Загрузить файлы программы, сохранить в каталог и поставить изображения в очередь зеркалирования
+    async fn load_program(&mut self, program_id: &str) -> Result<usize, ApiError> {
+        let response: Value = self.jwst_client.fetch_images(program_id).await?;
+        let images = parse_images(response, program_id).map_err(ApiError::UpstreamError)?;
+
+        let stored = self.jwst_repo.upsert_images(&images).await?;
+
+        for image in &images {
+            for url in std::iter::once(&image.image_url).chain(image.thumbnail_url.as_ref()) {
+                if is_image_url(url) {
+                    self.media_repo.enqueue(url, "jwst").await?;
+                }
+            }
+        }
+
+        tracing::info!("JWST program {}: {} files stored", program_id, stored);
+        Ok(stored)
+    }
+}
+
+/// Отметка свежести каталога программы
+/// Заголовки HDU продукта по одному Range-запросу на HDU.
+/// Второе значение - true, если прочитаны не все HDU
+async fn read_remote_hdus(client: &JwstClient, url: &str, file: &str) -> Result<(Vec<FitsHdu>, bool), ApiError> {
+    let mut hdus = Vec::new();
+    let mut offset = 0;
+    let mut truncated = false;
+
+    while hdus.len() < FITS_REMOTE_HDUS {
+        let bytes = match client.fetch_range(url, offset, FITS_RANGE_BYTES).await {
+            Ok(bytes) => bytes,
+            // Сервер без Range или сбой на следующем HDU не отменяет уже прочитанные заголовки
+            Err(e) if !hdus.is_empty() => {
+                tracing::warn!("FITS {}: failed to fetch HDU at byte {}: {:?}", file, offset, e);
+                truncated = true;
+                break;
+            }
+            Err(e) => return Err(e),
+        };
+        if bytes.is_empty() {
+            break;
+        }
+        match parse_hdu(&bytes, offset, hdus.len()) {
+            Ok(Some(hdu)) => {
+                offset = next_hdu_offset(&hdu);
+                hdus.push(hdu);
+            }
+            Ok(None) => {
+                truncated = true;
+                break;
+            }
+            // Мусор после последнего HDU не отменяет уже прочитанные заголовки
+            Err(e) if !hdus.is_empty() => {
+                tracing::warn!("FITS {}: stopped at byte {}: {}", file, offset, e);
+                break;
+            }
+            Err(e) => return Err(ApiError::UpstreamError(format!("{}: {}", file, e))),
+        }
+    }
+    truncated |= hdus.len() == FITS_REMOTE_HDUS;
+
+    Ok((hdus, truncated))
+}
+
+fn program_cache_key(program_id: &str) -> String {
+    format!("jwst:images:{}", program_id)
+}
+
+fn validate_product_name(file: &str) -> Result<(), ApiError> {
+    if is_product_name(file) {
+        return Ok(());
+    }
+    Err(ApiError::ValidationError(vec![ErrorDetail {
+        field: "file".to_string(),
+        message: "Expected a product file name like jw02731-o001_t017_nircam_clear-f444w_i2d.fits".to_string(),
+    }]))
+}
+
+#[cfg(test)]
+#[path = "jwst_service_tests.rs"]
+mod jwst_service_tests;
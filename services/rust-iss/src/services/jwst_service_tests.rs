@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    const FILE: &str = "jw02731-o001_t017_nircam_clear-f444w_i2d.fits";
+
+    /// Заголовок из карточек: каждая дополняется до 80 символов, блок - пробелами до 2880
+    fn header(cards: &[&str]) -> Vec<u8> {
+        let mut bytes: Vec<u8> = cards
+            .iter()
+            .chain(std::iter::once(&"END"))
+            .flat_map(|card| format!("{:<80}", card).into_bytes())
+            .collect();
+        bytes.resize(bytes.len().div_ceil(fits::BLOCK_SIZE) * fits::BLOCK_SIZE, b' ');
+        bytes
+    }
+
+    /// Пустой первичный HDU и пустое расширение SCI
+    fn product() -> Vec<u8> {
+        let mut bytes = header(&[
+            "SIMPLE  =                    T",
+            "BITPIX  =                    8",
+            "NAXIS   =                    0",
+            "EXTEND  =                    T",
+            "INSTRUME= 'NIRCAM  '",
+        ]);
+        bytes.extend(header(&[
+            "XTENSION= 'IMAGE   '",
+            "BITPIX  =                  -32",
+            "NAXIS   =                    0",
+            "PCOUNT  =                    0",
+            "GCOUNT  =                    1",
+            "EXTNAME = 'SCI     '",
+        ]));
+        bytes
+    }
+
+    fn client(server: &mockito::Server) -> JwstClient {
+        JwstClient::new(server.url(), String::new(), format!("{}/", server.url())).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_read_remote_hdus_keeps_primary_when_range_is_ignored() {
+        let mut server = mockito::Server::new_async().await;
+        // Сервер игнорирует Range и на любой запрос отдаёт файл целиком
+        let mock = server
+            .mock("GET", format!("/{}", FILE).as_str())
+            .with_status(200)
+            .with_body(product())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let client = client(&server);
+        let (hdus, truncated) = read_remote_hdus(&client, &client.product_url(FILE), FILE)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(hdus.len(), 1);
+        assert_eq!(hdus[0].name, "PRIMARY");
+        assert!(truncated);
+    }
+
+    #[tokio::test]
+    async fn test_read_remote_hdus_follows_ranges() {
+        let mut server = mockito::Server::new_async().await;
+        let bytes = product();
+        let block = fits::BLOCK_SIZE;
+        let path = format!("/{}", FILE);
+        server
+            .mock("GET", path.as_str())
+            .match_header("range", format!("bytes=0-{}", FITS_RANGE_BYTES - 1).as_str())
+            .with_status(206)
+            .with_body(bytes.clone())
+            .create_async()
+            .await;
+        server
+            .mock("GET", path.as_str())
+            .match_header("range", format!("bytes={}-{}", block, block + FITS_RANGE_BYTES - 1).as_str())
+            .with_status(206)
+            .with_body(&bytes[block..])
+            .create_async()
+            .await;
+        server
+            .mock("GET", path.as_str())
+            .match_header("range", format!("bytes={}-{}", 2 * block, 2 * block + FITS_RANGE_BYTES - 1).as_str())
+            .with_status(416)
+            .create_async()
+            .await;
+
+        let client = client(&server);
+        let (hdus, truncated) = read_remote_hdus(&client, &client.product_url(FILE), FILE)
+            .await
+            .unwrap();
+
+        assert_eq!(hdus.len(), 2);
+        assert_eq!(hdus[1].name, "SCI");
+        assert!(!truncated);
+    }
+
+    #[tokio::test]
+    async fn test_read_remote_hdus_fails_without_primary_header() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", format!("/{}", FILE).as_str())
+            .with_status(503)
+            .create_async()
+            .await;
+
+        let client = client(&server);
+        assert!(read_remote_hdus(&client, &client.product_url(FILE), FILE).await.is_err());
+    }
+}
@@ -0,0 +1,359 @@
+use crate::{
+    clients::MediaClient,
+    domain::{
+        error::{ApiError, ErrorDetail},
+        models::{MediaMirrorReport, MediaObject, MediaVariant},
+    },
+    repo::media_repo::MediaRepo,
+};
+use chrono::Utc;
+use image::{
+    codecs::{jpeg::JpegEncoder, webp::WebPEncoder},
+    imageops::FilterType,
+    DynamicImage, ImageFormat,
+};
+use sha2::{Digest, Sha256};
+use std::{
+    io::Cursor,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tokio::sync::Mutex;
+
+/// Сколько раз пытаться скачать один URL
+const MAX_DOWNLOAD_ATTEMPTS: i32 = 3;
+
+/// Максимальная сторона WebP-варианта (WebP кодируется без потерь, полный размер JWST слишком велик)
+const WEBP_MAX_SIZE: u32 = 2048;
+
+/// Сколько LRU-кандидатов рассматривать за одну очистку
+const EVICTION_BATCH: i64 = 500;
+
+const THUMB_QUALITY: u8 = 85;
+
+const VARIANTS: [MediaVariant; 3] = [MediaVariant::Original, MediaVariant::Thumb, MediaVariant::Webp];
+
+pub struct MediaService {
+    media_repo: MediaRepo,
+    media_dir: PathBuf,
+    mirror: MediaMirror,
+}
+
+/// Обработка очереди зеркалирования. Скачивание и рендеринг пачки занимают минуты,
+/// поэтому идут вне блокировки MediaService и не задерживают выдачу /media
+#[derive(Clone)]
+pub struct MediaMirror {
+    media_client: Arc<MediaClient>,
+    media_repo: MediaRepo,
+    media_dir: PathBuf,
+    quota_bytes: i64,
+    max_download_bytes: u64,
+    thumb_size: u32,
+    /// Один проход за раз в процессе (между экземплярами - advisory lock планировщика)
+    running: Arc<Mutex<()>>,
+}
+
+/// Декодированное изображение с готовыми вариантами
+#[derive(Debug)]
+pub struct RenderedMedia {
+    pub content_type: &'static str,
+    pub width: u32,
+    pub height: u32,
+    pub thumb: Vec<u8>,
+    pub webp: Vec<u8>,
+}
+
+impl MediaService {
+    pub fn new(
+        media_client: MediaClient,
+        media_repo: MediaRepo,
+        media_dir: PathBuf,
+        quota_mb: u64,
+        max_download_mb: u64,
+        thumb_size: u32,
+    ) -> Self {
+        Self {
+            mirror: MediaMirror {
+                media_client: Arc::new(media_client),
+                media_repo: media_repo.clone(),
+                media_dir: media_dir.clone(),
+                quota_bytes: (quota_mb * 1024 * 1024) as i64,
+                max_download_bytes: max_download_mb * 1024 * 1024,
+                thumb_size,
+                running: Arc::new(Mutex::new(())),
+            },
+            media_repo,
+            media_dir,
+        }
+    }
+
+    /// Обработчик очереди для запуска после освобождения блокировки сервиса
+    pub fn mirror(&self) -> MediaMirror {
+        self.mirror.clone()
+    }
+
+    /// Содержимое объекта и его content-type
+    pub async fn get_media(&mut self, hash: &str, variant: MediaVariant) -> Result<(Vec<u8>, String), ApiError> {
+        if !is_valid_hash(hash) {
+            return Err(ApiError::ValidationError(vec![ErrorDetail {
+                field: "hash".to_string(),
+                message: "Expected a SHA-256 hex digest".to_string(),
+            }]));
+        }
+
+        let object = self
+            .media_repo
+            .get_object(hash)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("Media {} not found", hash)))?;
+
+        let path = media_path(&self.media_dir, hash, variant);
+        let data = match tokio::fs::read(&path).await {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                // Чтение ничего не удаляет: файл мог быть ещё не дописан или лежать у другого
+                // экземпляра; расхождение исправит задача зеркалирования
+                tracing::warn!("Media file {} is missing for {}", path.display(), hash);
+                return Err(ApiError::NotFound(format!("Media {} not found", hash)));
+            }
+            Err(e) => return Err(ApiError::InternalError(format!("Failed to read media: {}", e))),
+        };
+
+        self.media_repo.touch(hash).await?;
+
+        let content_type = match variant {
+            MediaVariant::Original => object.content_type,
+            MediaVariant::Thumb => "image/jpeg".to_string(),
+            MediaVariant::Webp => "image/webp".to_string(),
+        };
+        Ok((data, content_type))
+    }
+
+    /// Найти зеркало по исходному URL
+    pub async fn lookup(&mut self, url: &str) -> Result<MediaObject, ApiError> {
+        self.media_repo
+            .get_by_url(url)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("No mirror for {}", url)))
+    }
+}
+
+impl MediaMirror {
+    /// Скачать очередь URL, сохранить новые изображения и освободить место сверх квоты
+    pub async fn mirror_pending(&self, batch: i64) -> Result<MediaMirrorReport, ApiError> {
+        let _running = self.running.lock().await;
+
+        let mut report = MediaMirrorReport {
+            repaired: self.repair_missing_files().await?,
+            ..Default::default()
+        };
+
+        for url in self.media_repo.get_pending(batch, MAX_DOWNLOAD_ATTEMPTS).await? {
+            match self.mirror_url(&url).await {
+                Ok(true) => report.mirrored += 1,
+                Ok(false) => report.deduplicated += 1,
+                Err(e) => {
+                    tracing::warn!("Media mirror failed for {}: {}", url, e);
+                    self.media_repo.mark_failed(&url, &e).await?;
+                    report.failed += 1;
+                }
+            }
+        }
+
+        if report.mirrored > 0 {
+            report.evicted = self.evict_over_quota().await?;
+        }
+
+        Ok(report)
+    }
+
+    /// Зеркалировать один URL. Ok(false) - такой файл уже был в хранилище
+    async fn mirror_url(&self, url: &str) -> Result<bool, String> {
+        let body = self.media_client.download(url, self.max_download_bytes).await?;
+        let hash = content_hash(&body);
+
+        if self.media_repo.get_object(&hash).await.map_err(|e| e.to_string())?.is_some() {
+            self.media_repo.link_source(url, &hash).await.map_err(|e| e.to_string())?;
+            return Ok(false);
+        }
+
+        // Декодирование и ресайз - CPU-bound, не блокируем runtime
+        let thumb_size = self.thumb_size;
+        let (body, rendered) = tokio::task::spawn_blocking(move || {
+            let rendered = render_variants(&body, thumb_size);
+            (body, rendered)
+        })
+        .await
+        .map_err(|e| format!("Render task failed: {}", e))?;
+        let rendered = rendered?;
+
+        for (variant, data) in VARIANTS.into_iter().zip([&body, &rendered.thumb, &rendered.webp]) {
+            let path = media_path(&self.media_dir, &hash, variant);
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+            }
+            tokio::fs::write(&path, data)
+                .await
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        }
+
+        let now = Utc::now();
+        let object = MediaObject {
+            hash: hash.clone(),
+            content_type: rendered.content_type.to_string(),
+            width: rendered.width as i32,
+            height: rendered.height as i32,
+            bytes: (body.len() + rendered.thumb.len() + rendered.webp.len()) as i64,
+            created_at: now,
+            last_accessed_at: now,
+        };
+        self.media_repo.save_object(&object).await.map_err(|e| e.to_string())?;
+        self.media_repo.link_source(url, &hash).await.map_err(|e| e.to_string())?;
+
+        tracing::info!("Mirrored {} as {} ({} bytes)", url, hash, object.bytes);
+        Ok(true)
+    }
+
+    /// Удалить давно не использованные объекты, пока хранилище больше квоты
+    async fn evict_over_quota(&self) -> Result<usize, ApiError> {
+        let total = self.media_repo.total_bytes().await?;
+        if total <= self.quota_bytes {
+            return Ok(0);
+        }
+
+        let candidates = self.media_repo.get_least_recently_used(EVICTION_BATCH).await?;
+        let evicted = select_evictions(&candidates, total, self.quota_bytes);
+
+        for hash in &evicted {
+            self.remove_files(hash).await;
+            self.media_repo.evict_object(hash).await?;
+        }
+
+        tracing::info!("Media quota exceeded ({} bytes), evicted {} objects", total, evicted.len());
+        Ok(evicted.len())
+    }
+
+    /// Расхождения БД и диска исправляет только задача зеркалирования: объект,
+    /// у которого пропал хотя бы один файл, удаляется и скачивается заново
+    async fn repair_missing_files(&self) -> Result<usize, ApiError> {
+        let mut repaired = 0;
+
+        for hash in self.media_repo.get_hashes().await? {
+            let mut complete = true;
+            for variant in VARIANTS {
+                // Ошибку доступа не считаем пропажей файла
+                let path = media_path(&self.media_dir, &hash, variant);
+                if !tokio::fs::try_exists(&path).await.unwrap_or(true) {
+                    complete = false;
+                    break;
+                }
+            }
+
+            if !complete {
+                tracing::warn!("Media {} has missing files, re-queueing its sources", hash);
+                self.remove_files(&hash).await;
+                self.media_repo.forget_object(&hash).await?;
+                repaired += 1;
+            }
+        }
+
+        Ok(repaired)
+    }
+
+    async fn remove_files(&self, hash: &str) {
+        for variant in VARIANTS {
+            let path = media_path(&self.media_dir, hash, variant);
+            if let Err(e) = tokio::fs::remove_file(&path).await {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    tracing::warn!("Failed to remove {}: {}", path.display(), e);
+                }
+            }
+        }
+    }
+}
+
+/// SHA-256 содержимого в hex
+pub fn content_hash(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+pub fn is_valid_hash(hash: &str) -> bool {
+    hash.len() == 64 && hash.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+}
+
+/// Похоже ли URL на растровое изображение, которое можно зеркалировать
+pub fn is_image_url(url: &str) -> bool {
+    let path = url.split(['?', '#']).next().unwrap_or_default().to_ascii_lowercase();
+    (path.starts_with("https://") || path.starts_with("http://"))
+        && [".jpg", ".jpeg", ".png", ".gif", ".webp"]
+            .iter()
+            .any(|ext| path.ends_with(ext))
+}
+
+/// Путь файла варианта: {dir}/{первые 2 символа хэша}/{hash}[.thumb.jpg|.webp]
+pub fn media_path(dir: &Path, hash: &str, variant: MediaVariant) -> PathBuf {
+    let file = match variant {
+        MediaVariant::Original => hash.to_string(),
+        MediaVariant::Thumb => format!("{}.thumb.jpg", hash),
+        MediaVariant::Webp => format!("{}.webp", hash),
+    };
+    dir.join(&hash[..2]).join(file)
+}
+
+/// Декодировать изображение и построить JPEG-миниатюру и WebP-вариант
+pub fn render_variants(data: &[u8], thumb_size: u32) -> Result<RenderedMedia, String> {
+    let format = image::guess_format(data).map_err(|e| format!("Unknown image format: {}", e))?;
+    let content_type = match format {
+        ImageFormat::Jpeg => "image/jpeg",
+        ImageFormat::Png => "image/png",
+        ImageFormat::Gif => "image/gif",
+        ImageFormat::WebP => "image/webp",
+        other => return Err(format!("Unsupported image format: {:?}", other)),
+    };
+    let image = image::load_from_memory_with_format(data, format)
+        .map_err(|e| format!("Failed to decode image: {}", e))?;
+
+    let mut thumb = Vec::new();
+    let preview = DynamicImage::ImageRgb8(image.thumbnail(thumb_size, thumb_size).to_rgb8());
+    preview
+        .write_with_encoder(JpegEncoder::new_with_quality(&mut thumb, THUMB_QUALITY))
+        .map_err(|e| format!("Failed to encode thumbnail: {}", e))?;
+
+    let webp_source = if image.width() > WEBP_MAX_SIZE || image.height() > WEBP_MAX_SIZE {
+        image.resize(WEBP_MAX_SIZE, WEBP_MAX_SIZE, FilterType::Lanczos3)
+    } else {
+        image.clone()
+    };
+    let mut webp = Cursor::new(Vec::new());
+    DynamicImage::ImageRgba8(webp_source.to_rgba8())
+        .write_with_encoder(WebPEncoder::new_lossless(&mut webp))
+        .map_err(|e| format!("Failed to encode WebP: {}", e))?;
+
+    Ok(RenderedMedia {
+        content_type,
+        width: image.width(),
+        height: image.height(),
+        thumb,
+        webp: webp.into_inner(),
+    })
+}
+
+/// Выбрать объекты (от самого старого) для удаления, чтобы уложиться в квоту
+pub fn select_evictions(candidates: &[(String, i64)], total: i64, quota: i64) -> Vec<String> {
+    let mut remaining = total;
+    candidates
+        .iter()
+        .take_while(|(_, bytes)| {
+            let over = remaining > quota;
+            remaining -= bytes;
+            over
+        })
+        .map(|(hash, _)| hash.clone())
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "media_service_tests.rs"]
+mod media_service_tests;
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use image::{ImageBuffer, Rgb};
+
+    fn sample_png(width: u32, height: u32) -> Vec<u8> {
+        let image = ImageBuffer::from_fn(width, height, |x, y| Rgb([(x % 256) as u8, (y % 256) as u8, 128]));
+        let mut data = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(image)
+            .write_to(&mut data, ImageFormat::Png)
+            .unwrap();
+        data.into_inner()
+    }
+
+    #[test]
+    fn test_content_hash_is_stable_sha256() {
+        assert_eq!(
+            content_hash(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert!(is_valid_hash(&content_hash(b"")));
+    }
+
+    #[test]
+    fn test_is_valid_hash_rejects_paths() {
+        assert!(!is_valid_hash("../../etc/passwd"));
+        assert!(!is_valid_hash(&"A".repeat(64)));
+        assert!(!is_valid_hash(&"a".repeat(63)));
+        assert!(is_valid_hash(&"a".repeat(64)));
+    }
+
+    #[test]
+    fn test_is_image_url() {
+        assert!(is_image_url("https://apod.nasa.gov/apod/image/2401/M31.jpg"));
+        assert!(is_image_url("https://www.nasa.gov/image.JPG?resize=768,768"));
+        assert!(!is_image_url("https://www.youtube.com/embed/abc?rel=0"));
+        assert!(!is_image_url("https://mast.stsci.edu/jw02731_i2d.fits"));
+        assert!(!is_image_url("file:///etc/hosts.png"));
+    }
+
+    #[test]
+    fn test_media_path_shards_by_hash_prefix() {
+        let hash = content_hash(b"abc");
+        let dir = Path::new("/data/media");
+
+        assert_eq!(media_path(dir, &hash, MediaVariant::Original), dir.join("ba").join(&hash));
+        assert_eq!(
+            media_path(dir, &hash, MediaVariant::Thumb),
+            dir.join("ba").join(format!("{}.thumb.jpg", hash))
+        );
+        assert_eq!(
+            media_path(dir, &hash, MediaVariant::Webp),
+            dir.join("ba").join(format!("{}.webp", hash))
+        );
+    }
+
+    #[test]
+    fn test_render_variants_builds_thumbnail_and_webp() {
+        let rendered = render_variants(&sample_png(640, 320), 64).unwrap();
+
+        assert_eq!(rendered.content_type, "image/png");
+        assert_eq!((rendered.width, rendered.height), (640, 320));
+
+        let thumb = image::load_from_memory_with_format(&rendered.thumb, ImageFormat::Jpeg).unwrap();
+        assert_eq!((thumb.width(), thumb.height()), (64, 32));
+
+        let webp = image::load_from_memory_with_format(&rendered.webp, ImageFormat::WebP).unwrap();
+        assert_eq!((webp.width(), webp.height()), (640, 320));
+    }
+
+    #[test]
+    fn test_render_variants_rejects_non_images() {
+        assert!(render_variants(b"<html>not an image</html>", 64).is_err());
+    }
+
+    #[test]
+    fn test_select_evictions_frees_oldest_first() {
+        let candidates = vec![
+            ("old".to_string(), 40),
+            ("mid".to_string(), 30),
+            ("new".to_string(), 50),
+        ];
+
+        assert!(select_evictions(&candidates, 100, 100).is_empty());
+        assert_eq!(select_evictions(&candidates, 120, 100), vec!["old"]);
+        assert_eq!(select_evictions(&candidates, 150, 100), vec!["old", "mid"]);
+        assert_eq!(select_evictions(&candidates, 500, 100), vec!["old", "mid", "new"]);
+    }
+
+    #[tokio::test]
+    async fn test_media_client_does_not_follow_redirects() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/image.jpg")
+            .with_status(302)
+            .with_header("location", "http://169.254.169.254/latest/meta-data/")
+            .create_async()
+            .await;
+
+        let client = MediaClient::new().unwrap();
+        let result = client.download(&format!("{}/image.jpg", server.url()), 1024).await;
+
+        assert_eq!(result.unwrap_err(), "HTTP 302 Found");
+    }
+}
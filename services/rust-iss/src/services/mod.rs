@@ -1,11 +1,19 @@
+pub mod astronomy_service;
+pub mod eonet_service;
+pub mod epic_service;
 pub mod iss_service;
 pub mod osdr_service;
 pub mod nasa_service;
 pub mod jwst_service;
+pub mod media_service;
 pub mod spacex_service;
 
+pub use astronomy_service::AstronomyService;
+pub use eonet_service::EonetService;
+pub use epic_service::EpicService;
 pub use iss_service::IssService;
 pub use osdr_service::OsdrService;
 pub use nasa_service::NasaService;
 pub use jwst_service::JwstService;
+pub use media_service::MediaService;
 pub use spacex_service::SpaceXService;
\ No newline at end of file
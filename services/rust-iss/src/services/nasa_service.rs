@@ -1,88 +1,752 @@
 use crate::{
-    clients::NasaClient,
-    domain::error::ApiError,
-    repo::cache_repo::CacheRepo,
+    clients::{
+        nasa_budget::{quota_exhausted, RequestPriority},
+        NasaClient,
+    },
+    domain::{
+        error::{ApiError, ErrorDetail},
+        models::{
+            ApodApiEntry, ApodBackfillReport, ApodEntry, DonkiChain, DonkiEvent, DonkiEventType,
+            HeliocentricPosition, NeoApproach, NeoDetails, NeoSort, SpaceWeatherDay, SpaceWeatherIndex,
+        },
+        donki::{build_chains, parse_events},
+        neo::{parse_feed, parse_lookup},
+        provenance,
+        space_weather::daily_index,
+    },
+    repo::{
+        apod_repo::ApodRepo, cache_repo::CacheRepo, donki_repo::DonkiRepo, media_repo::MediaRepo,
+        neo_repo::NeoRepo,
+    },
+    services::media_service::is_image_url,
+    utils::orbit::{heliocentric_position, julian_date},
 };
-use chrono::{Duration, Utc};
+use chrono::{Datelike, Duration, NaiveDate, Utc};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
+use std::collections::HashSet;
+
+/// Устаревшая копия ответа для выдачи при исчерпанном лимите NASA API (секунд)
+const STALE_TTL_SECONDS: usize = 7 * 24 * 3600;
+
+/// Первый выпуск APOD
+const APOD_FIRST_DATE: (i32, u32, u32) = (1995, 6, 16);
+
+/// Даты, которые вообще принимают API NASA (NeoWs считает сближения за 1900-2200)
+const NASA_FIRST_DATE: (i32, u32, u32) = (1900, 1, 1);
+const NASA_LAST_DATE: (i32, u32, u32) = (2200, 12, 31);
+
+/// Первые события в каталоге DONKI
+const DONKI_FIRST_DATE: (i32, u32, u32) = (2010, 1, 1);
+
+/// APOD выходит по времени восточного побережья США. Берём EST круглый год:
+/// летом дата сменится на час позже публикации, но не раньше неё
+const APOD_UTC_OFFSET_HOURS: i64 = -5;
+
+/// Максимальный диапазон для выборки и backfill APOD (дней)
+const APOD_MAX_SPAN_DAYS: i64 = 366;
+
+/// Максимальный диапазон одного запроса start_date/end_date к APOD API (дней)
+const APOD_CHUNK_DAYS: i64 = 60;
+
+/// NeoWs /feed принимает не больше 7 дней за запрос
+const NEO_FEED_CHUNK_DAYS: i64 = 7;
+
+/// Максимальный диапазон /nasa/neo (дней)
+const NEO_MAX_SPAN_DAYS: i64 = 31;
+
+/// Максимальный диапазон запросов событий DONKI (дней)
+const DONKI_MAX_SPAN_DAYS: i64 = 366;
+
+/// Эндпоинт уведомлений DONKI отдаёт не больше ~30 дней за запрос, остаток молча отбрасывается
+const DONKI_NOTIFICATIONS_CHUNK_DAYS: i64 = 30;
+
+/// Диапазон DONKI по умолчанию (дней до сегодня)
+pub const DONKI_DEFAULT_DAYS: i64 = 30;
+
+/// Через сколько дней события DONKI перестают уточняться и день можно отдавать из БД
+const DONKI_SETTLE_DAYS: i32 = 7;
+
+/// Геомагнитная буря длится несколько дней: GST, начавшиеся раньше диапазона, тоже учитываются
+const GST_LOOKBACK_DAYS: i64 = 3;
+
+/// Предел размера графа DONKI при обходе связей
+const DONKI_GRAPH_MAX_EVENTS: usize = 2000;
+
+/// Сколько дней ответ NeoWs lookup в neo_objects считается свежим
+const NEO_OBJECT_TTL_DAYS: i64 = 7;
+
+/// Максимум точек орбиты в одном ответе /nasa/neo/:id
+const NEO_MAX_POSITIONS: i64 = 1000;
 
 pub struct NasaService {
     nasa_client: NasaClient,
+    apod_repo: ApodRepo,
+    media_repo: MediaRepo,
+    neo_repo: NeoRepo,
+    donki_repo: DonkiRepo,
     cache_repo: CacheRepo,
 }
 
 impl NasaService {
-    pub fn new(nasa_client: NasaClient, cache_repo: CacheRepo) -> Self {
+    pub fn new(
+        nasa_client: NasaClient,
+        apod_repo: ApodRepo,
+        media_repo: MediaRepo,
+        neo_repo: NeoRepo,
+        donki_repo: DonkiRepo,
+        cache_repo: CacheRepo,
+    ) -> Self {
         Self {
             nasa_client,
+            apod_repo,
+            media_repo,
+            neo_repo,
+            donki_repo,
             cache_repo,
         }
     }
 
-    /// Получить Astronomy Picture of the Day (кэш 12 часов)
+    /// Получить Astronomy Picture of the Day (кэш 12 часов; при исчерпанном лимите - устаревшая копия)
     pub async fn get_apod(&mut self) -> Result<Value, ApiError> {
         if let Some(cached) = self.cache_repo.get::<Value>("nasa:apod").await? {
             tracing::info!("APOD from cache");
             return Ok(cached);
         }
 
+        if !self.nasa_client.has_budget(RequestPriority::OnDemand) {
+            tracing::warn!("NASA API budget low, serving stale APOD");
+            provenance::record_stale("NASA API quota is low, serving the last APOD");
+            return self
+                .cache_repo
+                .get::<Value>("nasa:apod:stale")
+                .await?
+                .ok_or_else(quota_exhausted);
+        }
+
+        self.refresh_apod().await
+    }
+
+    /// Загрузить сегодняшний APOD, сохранить в архив и обновить кэш
+    pub async fn refresh_apod(&mut self) -> Result<Value, ApiError> {
         let apod = self.nasa_client.fetch_apod().await?;
+
+        match serde_json::from_value::<ApodApiEntry>(apod.clone()) {
+            Ok(entry) => {
+                self.archive_apod(&entry).await?;
+            }
+            Err(e) => tracing::warn!("APOD response not archived, unexpected format: {}", e),
+        }
+
         self.cache_repo.set("nasa:apod", &apod, 43200).await?; // 12 часов
+        self.cache_repo.set("nasa:apod:stale", &apod, STALE_TTL_SECONDS).await?;
 
         Ok(apod)
     }
 
-    /// Получить Near-Earth Objects (кэш 2 часа)
-    pub async fn get_neo(&mut self) -> Result<Value, ApiError> {
-        let today = Utc::now().format("%Y-%m-%d").to_string();
-        let week_later = (Utc::now() + Duration::days(7)).format("%Y-%m-%d").to_string();
+    /// APOD за дату: из архива, при отсутствии - из NASA API с сохранением
+    pub async fn get_apod_by_date(&mut self, date: NaiveDate) -> Result<ApodEntry, ApiError> {
+        validate_apod_range(date, date)?;
 
-        let cache_key = format!("nasa:neo:{}:{}", today, week_later);
+        if let Some(entry) = self.apod_repo.get_by_date(date).await? {
+            return Ok(entry);
+        }
 
-        if let Some(cached) = self.cache_repo.get::<Value>(&cache_key).await? {
-            tracing::info!("NEO from cache");
-            return Ok(cached);
+        if !self.nasa_client.has_budget(RequestPriority::OnDemand) {
+            return Err(quota_exhausted());
         }
 
-        let neo = self.nasa_client.fetch_neo(&today, &week_later).await?;
-        self.cache_repo.set(&cache_key, &neo, 7200).await?; // 2 часа
+        let value = self
+            .nasa_client
+            .fetch_apod_date(&date.format("%Y-%m-%d").to_string())
+            .await?;
+        let entry: ApodApiEntry = serde_json::from_value(value)
+            .map_err(|e| ApiError::UpstreamError(format!("Unexpected APOD response: {}", e)))?;
 
-        Ok(neo)
+        self.archive_apod(&entry).await
     }
 
-    /// Получить DONKI Flare events (кэш 1 час)
-    pub async fn get_donki_flr(&mut self) -> Result<Value, ApiError> {
-        let today = Utc::now().format("%Y-%m-%d").to_string();
-        let month_ago = (Utc::now() - Duration::days(30)).format("%Y-%m-%d").to_string();
+    /// Сохранить APOD в архив и поставить изображения в очередь зеркалирования
+    async fn archive_apod(&self, entry: &ApodApiEntry) -> Result<ApodEntry, ApiError> {
+        let stored = self.apod_repo.upsert(entry).await?;
 
-        let cache_key = format!("nasa:donki:flr:{}:{}", month_ago, today);
+        if stored.media_type == "image" {
+            for url in std::iter::once(&stored.url).chain(stored.hdurl.as_ref()) {
+                if is_image_url(url) {
+                    self.media_repo.enqueue(url, "apod").await?;
+                }
+            }
+        }
 
-        if let Some(cached) = self.cache_repo.get::<Value>(&cache_key).await? {
-            tracing::info!("DONKI FLR from cache");
-            return Ok(cached);
+        Ok(stored)
+    }
+
+    /// APOD за диапазон дат из архива
+    pub async fn get_apod_range(&mut self, start: NaiveDate, end: NaiveDate) -> Result<Vec<ApodEntry>, ApiError> {
+        validate_apod_range(start, end)?;
+        self.apod_repo.get_range(start, end).await
+    }
+
+    /// Дозаполнить архив APOD за пропущенные даты запросами start_date/end_date
+    pub async fn backfill_apod(&mut self, start: NaiveDate, end: NaiveDate) -> Result<ApodBackfillReport, ApiError> {
+        validate_apod_range(start, end)?;
+
+        let missing = self.apod_repo.get_missing_dates(start, end).await?;
+        let mut stored = 0;
+        let mut error = None;
+
+        for (chunk_start, chunk_end) in contiguous_ranges(&missing, APOD_CHUNK_DAYS) {
+            // Остаток попадёт в still_missing
+            if !self.nasa_client.has_budget(RequestPriority::OnDemand) {
+                tracing::warn!("APOD backfill stopped at {}: NASA API budget low", chunk_start);
+                break;
+            }
+
+            let fetched = self
+                .nasa_client
+                .fetch_apod_range(
+                    &chunk_start.format("%Y-%m-%d").to_string(),
+                    &chunk_end.format("%Y-%m-%d").to_string(),
+                )
+                .await
+                .and_then(|value| {
+                    serde_json::from_value::<Vec<ApodApiEntry>>(value)
+                        .map_err(|e| ApiError::UpstreamError(format!("Unexpected APOD response: {}", e)))
+                });
+
+            // Уже сохранённые окна остаются в архиве, остаток попадёт в still_missing
+            let entries = match fetched {
+                Ok(entries) => entries,
+                Err(e) => {
+                    tracing::warn!("APOD backfill stopped at {}..{}: {}", chunk_start, chunk_end, e);
+                    error = Some(format!("{}..{}: {}", chunk_start, chunk_end, e));
+                    break;
+                }
+            };
+
+            for entry in &entries {
+                self.archive_apod(entry).await?;
+                stored += 1;
+            }
+            tracing::info!("APOD backfill {}..{}: {} entries", chunk_start, chunk_end, entries.len());
+        }
+
+        let still_missing = self.apod_repo.get_missing_dates(start, end).await?;
+
+        Ok(ApodBackfillReport {
+            start,
+            end,
+            missing_before: missing.len(),
+            stored,
+            still_missing,
+            error,
+        })
+    }
+
+    /// Сближения NEO за диапазон дат из БД; недостающие окна подгружаются из NeoWs,
+    /// пока позволяет лимит NASA API
+    pub async fn get_neo(
+        &mut self,
+        start: NaiveDate,
+        end: NaiveDate,
+        hazardous: Option<bool>,
+        max_miss_km: Option<f64>,
+        sort: NeoSort,
+    ) -> Result<Vec<NeoApproach>, ApiError> {
+        validate_span(start, end, nasa_date_range(), NEO_MAX_SPAN_DAYS)?;
+
+        let today = Utc::now().date_naive();
+        for (window_start, window_end) in week_windows(start, end) {
+            // Прошедшие недели не меняются - отметка в БД; текущая и будущие - в кэше на 2 часа
+            let loaded = if window_end < today {
+                self.neo_repo.is_window_synced(window_start).await?
+            } else {
+                self.cache_repo
+                    .get::<usize>(&neo_cache_key(window_start, window_end))
+                    .await?
+                    .is_some()
+            };
+            if loaded {
+                continue;
+            }
+            if !self.nasa_client.has_budget(RequestPriority::OnDemand) {
+                tracing::warn!("NASA API budget low, serving stored NEO {}..{}", window_start, window_end);
+                provenance::record_stale(format!("NASA API quota is low, NEO {}..{} may be incomplete", window_start, window_end));
+                continue;
+            }
+            self.load_neo_window(window_start, window_end).await?;
+        }
+
+        self.neo_repo
+            .get_approaches(start, end, hazardous, max_miss_km, sort)
+            .await
+    }
+
+    /// Объект NEO по ID: элементы орбиты, сближения, размеры и положения на орбите с шагом step_days
+    pub async fn get_neo_details(
+        &mut self,
+        neo_id: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+        step_days: u32,
+    ) -> Result<NeoDetails, ApiError> {
+        validate_bounds(start, end, nasa_date_range())?;
+        let step_days = i64::from(step_days);
+        if start > end || step_days < 1 || (end - start).num_days() / step_days >= NEO_MAX_POSITIONS {
+            return Err(ApiError::ValidationError(vec![ErrorDetail {
+                field: "step_days".to_string(),
+                message: format!("Range must be ordered and produce at most {} positions", NEO_MAX_POSITIONS),
+            }]));
+        }
+
+        let stored = self.neo_repo.get_object(neo_id).await?;
+        let fresh = stored
+            .as_ref()
+            .is_some_and(|(_, fetched_at)| Utc::now() - *fetched_at < Duration::days(NEO_OBJECT_TTL_DAYS));
+
+        let (payload, fetched_at) = match stored {
+            Some(stored) if fresh => stored,
+            Some(stored) if !self.nasa_client.has_budget(RequestPriority::OnDemand) => {
+                tracing::warn!("NASA API budget low, serving stored NEO {}", neo_id);
+                provenance::record_stale(format!("NASA API quota is low, serving stored NEO {}", neo_id));
+                stored
+            }
+            None if !self.nasa_client.has_budget(RequestPriority::OnDemand) => return Err(quota_exhausted()),
+            stored => match self.nasa_client.fetch_neo_lookup(neo_id).await {
+                Ok(payload) => {
+                    let name = payload.get("name").and_then(Value::as_str).unwrap_or(neo_id).to_string();
+                    let fetched_at = self.neo_repo.upsert_object(neo_id, &name, &payload).await?;
+                    (payload, fetched_at)
+                }
+                // Устаревшая копия лучше, чем ошибка
+                Err(e) => match stored {
+                    Some(stored) => {
+                        tracing::warn!("NEO lookup {} failed, serving stored copy: {}", neo_id, e);
+                        provenance::record_stale(format!("NEO lookup failed, serving stored copy: {}", e));
+                        stored
+                    }
+                    None => return Err(e),
+                },
+            },
+        };
+
+        let mut details = parse_lookup(payload, fetched_at).map_err(ApiError::UpstreamError)?;
+
+        let mut date = start;
+        while date <= end {
+            let [x, y, z] = heliocentric_position(&details.orbital_elements, julian_date(date))
+                .map_err(ApiError::UpstreamError)?;
+            details.positions.push(HeliocentricPosition {
+                date,
+                x_au: x,
+                y_au: y,
+                z_au: z,
+                distance_au: (x * x + y * y + z * z).sqrt(),
+            });
+            date += Duration::days(step_days);
+        }
+
+        Ok(details)
+    }
+
+    /// Обновить недели NEO, покрывающие ближайшие 7 дней, независимо от кэша (для планировщика)
+    pub async fn refresh_neo(&mut self) -> Result<usize, ApiError> {
+        let today = Utc::now().date_naive();
+        let mut stored = 0;
+        for (window_start, window_end) in week_windows(today, today + Duration::days(NEO_FEED_CHUNK_DAYS - 1)) {
+            stored += self.load_neo_window(window_start, window_end).await?;
+        }
+        Ok(stored)
+    }
+
+    /// Загрузить окно NeoWs (не больше 7 дней) и сохранить сближения. Окно, закончившееся
+    /// до загрузки, отмечается в БД навсегда, иначе - отметка в кэше на 2 часа
+    async fn load_neo_window(&mut self, start: NaiveDate, end: NaiveDate) -> Result<usize, ApiError> {
+        let feed = self
+            .nasa_client
+            .fetch_neo(&start.format("%Y-%m-%d").to_string(), &end.format("%Y-%m-%d").to_string())
+            .await?;
+        let objects = parse_feed(feed).map_err(ApiError::UpstreamError)?;
+
+        let mut stored = 0;
+        for object in &objects {
+            stored += self.neo_repo.upsert_approaches(object).await?;
+        }
+
+        if end < Utc::now().date_naive() {
+            self.neo_repo.mark_window_synced(start).await?;
+        } else {
+            self.cache_repo.set(&neo_cache_key(start, end), &stored, 7200).await?; // 2 часа
+        }
+        tracing::info!("NEO feed {}..{}: {} objects, {} approaches", start, end, objects.len(), stored);
+
+        Ok(stored)
+    }
+
+    /// Получить DONKI Flare events за период
+    pub async fn get_donki_flr(&mut self, start: NaiveDate, end: NaiveDate) -> Result<Value, ApiError> {
+        self.fetch_donki_range(DonkiEventType::Flr, start, end, RequestPriority::OnDemand)
+            .await
+    }
+
+    /// Получить DONKI CME events за период
+    pub async fn get_donki_cme(&mut self, start: NaiveDate, end: NaiveDate) -> Result<Value, ApiError> {
+        self.fetch_donki_range(DonkiEventType::Cme, start, end, RequestPriority::OnDemand)
+            .await
+    }
+
+    /// Типизированные события DONKI за период (GST, SEP, IPS, HSS, RBE, уведомления)
+    pub async fn get_donki_events<T: DeserializeOwned>(
+        &mut self,
+        event_type: DonkiEventType,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<T>, ApiError> {
+        let response = self
+            .fetch_donki_range(event_type, start, end, RequestPriority::OnDemand)
+            .await?;
+        if response.is_null() {
+            return Ok(Vec::new());
+        }
+
+        serde_json::from_value(response).map_err(|e| {
+            ApiError::UpstreamError(format!("Unexpected DONKI {} response: {}", event_type.as_str(), e))
+        })
+    }
+
+    /// Обновить все типы событий DONKI (для планировщика); возвращает число сохранённых событий
+    pub async fn refresh_donki(&mut self) -> Result<usize, ApiError> {
+        let mut stored = 0;
+        let mut last_error = None;
+
+        let today = Utc::now().date_naive();
+        let month_ago = today - Duration::days(DONKI_DEFAULT_DAYS);
+
+        for event_type in DonkiEventType::ALL {
+            match self
+                .fetch_donki_range(event_type, month_ago, today, RequestPriority::Scheduled)
+                .await
+            {
+                Ok(response) => stored += response.as_array().map_or(0, Vec::len),
+                Err(e) => {
+                    tracing::warn!("DONKI {} refresh failed: {}", event_type.as_str(), e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match last_error {
+            Some(e) if stored == 0 => Err(e),
+            _ => Ok(stored),
+        }
+    }
+
+    /// Цепочки связанных событий DONKI, начавшихся в диапазоне дат (связи достраиваются за его пределами)
+    pub async fn get_donki_chains(&mut self, start: NaiveDate, end: NaiveDate) -> Result<Vec<DonkiChain>, ApiError> {
+        validate_span(start, end, donki_date_range(), DONKI_MAX_SPAN_DAYS)?;
+
+        let events = self
+            .donki_repo
+            .get_linkable(day_start(start), day_start(end + Duration::days(1)))
+            .await?;
+        let events = self.expand_donki_links(events).await?;
+
+        Ok(build_chains(&events)
+            .into_iter()
+            .filter(|chain| chain.timeline.len() > 1)
+            .collect())
+    }
+
+    /// Цепочка, в которую входит событие DONKI
+    pub async fn get_donki_related(&mut self, activity_id: &str) -> Result<DonkiChain, ApiError> {
+        let seed = self.donki_repo.get_by_ids(&[activity_id.to_string()]).await?;
+        if seed.is_empty() {
+            return Err(ApiError::NotFound(format!("DONKI event {} not found", activity_id)));
         }
 
-        let flr = self.nasa_client.fetch_donki_flr(&month_ago, &today).await?;
-        self.cache_repo.set(&cache_key, &flr, 3600).await?; // 1 час
+        let events = self.expand_donki_links(seed).await?;
+
+        build_chains(&events)
+            .into_iter()
+            .find(|chain| chain.timeline.iter().any(|e| e.activity_id == activity_id))
+            .ok_or_else(|| ApiError::NotFound(format!("DONKI event {} not found", activity_id)))
+    }
+
+    /// Дневной индекс космической погоды за диапазон и текущий уровень (сегодня, UTC)
+    pub async fn get_space_weather_index(&mut self, start: NaiveDate, end: NaiveDate) -> Result<SpaceWeatherIndex, ApiError> {
+        validate_span(start, end, donki_date_range(), DONKI_MAX_SPAN_DAYS)?;
+
+        let days = self.space_weather_days(start, end).await?;
+
+        let today = Utc::now().date_naive();
+        let current = match days.iter().find(|day| day.date == today) {
+            Some(day) => day.clone(),
+            None => self.space_weather_days(today, today).await?.remove(0),
+        };
 
-        Ok(flr)
+        Ok(SpaceWeatherIndex { current, days })
     }
 
-    /// Получить DONKI CME events (кэш 1 час)
-    pub async fn get_donki_cme(&mut self) -> Result<Value, ApiError> {
-        let today = Utc::now().format("%Y-%m-%d").to_string();
-        let month_ago = (Utc::now() - Duration::days(30)).format("%Y-%m-%d").to_string();
+    async fn space_weather_days(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<SpaceWeatherDay>, ApiError> {
+        let from = day_start(start);
+        let to = day_start(end + Duration::days(1));
 
-        let cache_key = format!("nasa:donki:cme:{}:{}", month_ago, today);
+        let flares = self.donki_repo.get_events(DonkiEventType::Flr, from, to).await?;
+        let cmes = self.donki_repo.get_events(DonkiEventType::Cme, from, to).await?;
+        let storms = self
+            .donki_repo
+            .get_events(DonkiEventType::Gst, from - Duration::days(GST_LOOKBACK_DAYS), to)
+            .await?;
+
+        Ok(daily_index(start, end, &flares, &cmes, &storms))
+    }
+
+    /// Догрузить из БД события, связанные с уже загруженными (в обе стороны), до замыкания
+    async fn expand_donki_links(&self, mut events: Vec<DonkiEvent>) -> Result<Vec<DonkiEvent>, ApiError> {
+        let mut known: HashSet<String> = events.iter().map(|e| e.activity_id.clone()).collect();
+        let mut frontier = 0;
+
+        while frontier < events.len() && events.len() < DONKI_GRAPH_MAX_EVENTS {
+            let frontier_ids: Vec<String> = events[frontier..].iter().map(|e| e.activity_id.clone()).collect();
+            let forward: Vec<String> = events[frontier..]
+                .iter()
+                .flat_map(|e| e.linked_ids.iter())
+                .filter(|id| !known.contains(*id))
+                .cloned()
+                .collect();
+            frontier = events.len();
+
+            let mut found = self.donki_repo.get_by_ids(&forward).await?;
+            found.extend(self.donki_repo.get_linking_to(&frontier_ids).await?);
+
+            for event in found {
+                if known.insert(event.activity_id.clone()) {
+                    events.push(event);
+                }
+            }
+        }
+
+        if events.len() >= DONKI_GRAPH_MAX_EVENTS {
+            tracing::warn!("DONKI graph truncated at {} events", events.len());
+        }
+
+        Ok(events)
+    }
+
+    /// События DONKI за период; уведомления запрашиваются кусками, которые эндпоинт отдаёт целиком
+    async fn fetch_donki_range(
+        &mut self,
+        event_type: DonkiEventType,
+        start: NaiveDate,
+        end: NaiveDate,
+        priority: RequestPriority,
+    ) -> Result<Value, ApiError> {
+        validate_span(start, end, donki_date_range(), DONKI_MAX_SPAN_DAYS)?;
+
+        if event_type != DonkiEventType::Notification {
+            return self.fetch_donki_chunk(event_type, start, end, priority).await;
+        }
+
+        let mut events = Vec::new();
+        for (chunk_start, chunk_end) in date_chunks(start, end, DONKI_NOTIFICATIONS_CHUNK_DAYS) {
+            if let Value::Array(chunk) = self.fetch_donki_chunk(event_type, chunk_start, chunk_end, priority).await? {
+                events.extend(chunk);
+            }
+        }
+        Ok(Value::Array(events))
+    }
+
+    /// События DONKI за период одного запроса. Устоявшаяся история - из donki_events, иначе кэш 1 час
+    /// и NASA API с сохранением событий и отметкой загруженных дней.
+    /// Если лимит NASA API не позволяет запрос - отдаются уже сохранённые события.
+    async fn fetch_donki_chunk(
+        &mut self,
+        event_type: DonkiEventType,
+        start: NaiveDate,
+        end: NaiveDate,
+        priority: RequestPriority,
+    ) -> Result<Value, ApiError> {
+        if self.donki_repo.is_settled(event_type, start, end, DONKI_SETTLE_DAYS).await? {
+            tracing::info!("DONKI {} {}..{} from database", event_type.as_str(), start, end);
+            return self.stored_donki(event_type, start, end).await;
+        }
+
+        let cache_key = format!("nasa:donki:{}:{}:{}", event_type.as_str().to_lowercase(), start, end);
 
         if let Some(cached) = self.cache_repo.get::<Value>(&cache_key).await? {
-            tracing::info!("DONKI CME from cache");
+            tracing::info!("DONKI {} from cache", event_type.as_str());
             return Ok(cached);
         }
 
-        let cme = self.nasa_client.fetch_donki_cme(&month_ago, &today).await?;
-        self.cache_repo.set(&cache_key, &cme, 3600).await?; // 1 час
+        if !self.nasa_client.has_budget(priority) {
+            if priority == RequestPriority::Scheduled {
+                return Err(quota_exhausted());
+            }
+            tracing::warn!("NASA API budget low, serving stored DONKI {} {}..{}", event_type.as_str(), start, end);
+            provenance::record_stale(format!("NASA API quota is low, DONKI {} may be incomplete", event_type.as_str()));
+            return self.stored_donki(event_type, start, end).await;
+        }
+
+        let response = self
+            .nasa_client
+            .fetch_donki(event_type, &start.format("%Y-%m-%d").to_string(), &end.format("%Y-%m-%d").to_string())
+            .await?;
+
+        let (events, skipped) = parse_events(event_type, response.clone()).map_err(ApiError::UpstreamError)?;
+        if skipped > 0 {
+            tracing::warn!("DONKI {}: skipped {} events without ID or time", event_type.as_str(), skipped);
+        }
+        self.donki_repo.upsert_events(&events).await?;
+        self.donki_repo.mark_synced(event_type, start, end).await?;
+
+        self.cache_repo.set(&cache_key, &response, 3600).await?; // 1 час
 
-        Ok(cme)
+        Ok(response)
     }
-}
\ No newline at end of file
+
+    /// Сохранённые события DONKI за период в формате ответа NASA
+    async fn stored_donki(&self, event_type: DonkiEventType, start: NaiveDate, end: NaiveDate) -> Result<Value, ApiError> {
+        let events = self
+            .donki_repo
+            .get_events(event_type, day_start(start), day_start(end + Duration::days(1)))
+            .await?;
+        Ok(Value::Array(events.into_iter().map(|e| e.payload).collect()))
+    }
+}
+
+
+/// Границы дат для всех запросов к NASA: вне них арифметика дат chrono переполняется
+pub fn nasa_date_range() -> (NaiveDate, NaiveDate) {
+    (ymd(NASA_FIRST_DATE), ymd(NASA_LAST_DATE))
+}
+
+/// DONKI: от начала каталога до завтра (события по UTC могут опережать локальную дату)
+fn donki_date_range() -> (NaiveDate, NaiveDate) {
+    (ymd(DONKI_FIRST_DATE), Utc::now().date_naive() + Duration::days(1))
+}
+
+fn ymd((y, m, d): (i32, u32, u32)) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).expect("valid date constant")
+}
+
+/// Проверить, что обе даты лежат в границах (first, last)
+fn validate_bounds(start: NaiveDate, end: NaiveDate, (first, last): (NaiveDate, NaiveDate)) -> Result<(), ApiError> {
+    for (field, date) in [("start", start), ("end", end)] {
+        if date < first || date > last {
+            return Err(ApiError::ValidationError(vec![ErrorDetail {
+                field: field.to_string(),
+                message: format!("Dates must be between {} and {}", first, last),
+            }]));
+        }
+    }
+    Ok(())
+}
+
+/// Проверить, что диапазон лежит в границах, упорядочен и не длиннее max_days (включительно)
+fn validate_span(
+    start: NaiveDate,
+    end: NaiveDate,
+    bounds: (NaiveDate, NaiveDate),
+    max_days: i64,
+) -> Result<(), ApiError> {
+    validate_bounds(start, end, bounds)?;
+    if start > end || (end - start).num_days() >= max_days {
+        return Err(ApiError::ValidationError(vec![ErrorDetail {
+            field: "end".to_string(),
+            message: format!("Range must be ordered and not exceed {} days", max_days),
+        }]));
+    }
+    Ok(())
+}
+
+/// Текущая дата выпуска APOD: до полуночи по восточному времени США NASA не отдаёт "сегодня" по UTC
+pub fn apod_today() -> NaiveDate {
+    (Utc::now() + Duration::hours(APOD_UTC_OFFSET_HOURS)).date_naive()
+}
+
+/// Проверить диапазон дат APOD: не раньше первого выпуска, не позже текущего, не длиннее лимита
+fn validate_apod_range(start: NaiveDate, end: NaiveDate) -> Result<(), ApiError> {
+    let first = ymd(APOD_FIRST_DATE);
+    let today = apod_today();
+
+    let message = if start > end {
+        Some("start must not be after end".to_string())
+    } else if start < first || end > today {
+        Some(format!("APOD dates must be between {} and {}", first, today))
+    } else if (end - start).num_days() >= APOD_MAX_SPAN_DAYS {
+        Some(format!("Range must not exceed {} days", APOD_MAX_SPAN_DAYS))
+    } else {
+        None
+    };
+
+    match message {
+        Some(message) => Err(ApiError::ValidationError(vec![ErrorDetail {
+            field: "date".to_string(),
+            message,
+        }])),
+        None => Ok(()),
+    }
+}
+
+/// Недели (понедельник-воскресенье), покрывающие диапазон. Окна не зависят от начала
+/// запроса, поэтому разные диапазоны переиспользуют одни и те же загрузки NeoWs
+fn week_windows(start: NaiveDate, end: NaiveDate) -> Vec<(NaiveDate, NaiveDate)> {
+    let mut windows = Vec::new();
+    let mut window_start = start - Duration::days(i64::from(start.weekday().num_days_from_monday()));
+
+    while window_start <= end {
+        let window_end = window_start + Duration::days(NEO_FEED_CHUNK_DAYS - 1);
+        windows.push((window_start, window_end));
+        window_start = window_end + Duration::days(1);
+    }
+
+    windows
+}
+
+fn day_start(date: NaiveDate) -> chrono::DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0).expect("valid midnight").and_utc()
+}
+
+fn neo_cache_key(start: NaiveDate, end: NaiveDate) -> String {
+    format!("nasa:neo:{}:{}", start, end)
+}
+
+/// Разбить диапазон [start, end] на последовательные куски не длиннее days (включительно)
+fn date_chunks(start: NaiveDate, end: NaiveDate, days: i64) -> Vec<(NaiveDate, NaiveDate)> {
+    let mut chunks = Vec::new();
+    let mut chunk_start = start;
+
+    while chunk_start <= end {
+        let chunk_end = (chunk_start + Duration::days(days - 1)).min(end);
+        chunks.push((chunk_start, chunk_end));
+        chunk_start = chunk_end + Duration::days(1);
+    }
+
+    chunks
+}
+
+/// Сгруппировать отсортированные даты в непрерывные диапазоны не длиннее max_days
+fn contiguous_ranges(dates: &[NaiveDate], max_days: i64) -> Vec<(NaiveDate, NaiveDate)> {
+    let mut ranges: Vec<(NaiveDate, NaiveDate)> = Vec::new();
+
+    for &date in dates {
+        match ranges.last_mut() {
+            Some((start, end))
+                if date - *end == Duration::days(1) && (date - *start).num_days() < max_days =>
+            {
+                *end = date;
+            }
+            _ => ranges.push((date, date)),
+        }
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+#[path = "nasa_service_tests.rs"]
+mod nasa_service_tests;
\ No newline at end of file
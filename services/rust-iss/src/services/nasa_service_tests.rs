@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_contiguous_ranges_groups_consecutive_dates() {
+        let dates = vec![
+            date("2024-01-01"),
+            date("2024-01-02"),
+            date("2024-01-03"),
+            date("2024-01-07"),
+            date("2024-01-09"),
+            date("2024-01-10"),
+        ];
+
+        assert_eq!(
+            contiguous_ranges(&dates, 60),
+            vec![
+                (date("2024-01-01"), date("2024-01-03")),
+                (date("2024-01-07"), date("2024-01-07")),
+                (date("2024-01-09"), date("2024-01-10")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_contiguous_ranges_splits_long_runs() {
+        let dates: Vec<NaiveDate> = (0..5).map(|i| date("2024-03-01") + Duration::days(i)).collect();
+
+        assert_eq!(
+            contiguous_ranges(&dates, 2),
+            vec![
+                (date("2024-03-01"), date("2024-03-02")),
+                (date("2024-03-03"), date("2024-03-04")),
+                (date("2024-03-05"), date("2024-03-05")),
+            ]
+        );
+        assert!(contiguous_ranges(&[], 2).is_empty());
+    }
+
+    #[test]
+    fn test_date_chunks_cover_range() {
+        assert_eq!(
+            date_chunks(date("2024-01-01"), date("2024-03-01"), 30),
+            vec![
+                (date("2024-01-01"), date("2024-01-30")),
+                (date("2024-01-31"), date("2024-02-29")),
+                (date("2024-03-01"), date("2024-03-01")),
+            ]
+        );
+        assert_eq!(
+            date_chunks(date("2024-01-01"), date("2024-01-30"), 30),
+            vec![(date("2024-01-01"), date("2024-01-30"))]
+        );
+        assert!(date_chunks(date("2024-01-02"), date("2024-01-01"), 30).is_empty());
+    }
+
+    #[test]
+    fn test_week_windows_are_aligned_to_mondays() {
+        // 2024-01-01 - понедельник
+        assert_eq!(
+            week_windows(date("2024-01-01"), date("2024-01-16")),
+            vec![
+                (date("2024-01-01"), date("2024-01-07")),
+                (date("2024-01-08"), date("2024-01-14")),
+                (date("2024-01-15"), date("2024-01-21")),
+            ]
+        );
+        // Окна не зависят от начала запроса
+        assert_eq!(
+            week_windows(date("2024-01-03"), date("2024-01-09")),
+            vec![
+                (date("2024-01-01"), date("2024-01-07")),
+                (date("2024-01-08"), date("2024-01-14")),
+            ]
+        );
+        assert_eq!(
+            week_windows(date("2024-01-07"), date("2024-01-07")),
+            vec![(date("2024-01-01"), date("2024-01-07"))]
+        );
+        assert!(week_windows(date("2024-01-09"), date("2024-01-01")).is_empty());
+    }
+
+    #[test]
+    fn test_validate_span() {
+        assert!(validate_span(date("2024-01-01"), date("2024-01-01"), nasa_date_range(), 1).is_ok());
+        assert!(validate_span(date("2024-01-01"), date("2024-01-30"), nasa_date_range(), 30).is_ok());
+        assert!(validate_span(date("2024-01-01"), date("2024-01-31"), nasa_date_range(), 30).is_err());
+        assert!(validate_span(date("2024-01-02"), date("2024-01-01"), nasa_date_range(), 30).is_err());
+
+        // Границы проверяются до арифметики дат: chrono разбирает годы до +262142
+        let far = NaiveDate::parse_from_str("+262142-12-31", "%Y-%m-%d").unwrap();
+        assert!(validate_span(far, far, nasa_date_range(), 30).is_err());
+        assert!(validate_span(date("2009-12-31"), date("2010-01-05"), donki_date_range(), 30).is_err());
+        assert!(validate_span(date("1899-12-31"), date("1900-01-05"), nasa_date_range(), 30).is_err());
+    }
+
+    #[test]
+    fn test_validate_apod_range() {
+        assert!(validate_apod_range(date("2024-01-01"), date("2024-01-31")).is_ok());
+        assert!(validate_apod_range(date("1995-06-16"), date("1995-06-16")).is_ok());
+
+        // До первого выпуска, в будущем, перевёрнутый и слишком длинный диапазоны
+        assert!(validate_apod_range(date("1995-06-15"), date("1995-06-20")).is_err());
+        let tomorrow = Utc::now().date_naive() + Duration::days(1);
+        assert!(validate_apod_range(tomorrow, tomorrow).is_err());
+        assert!(validate_apod_range(date("2024-02-01"), date("2024-01-01")).is_err());
+        assert!(validate_apod_range(date("2020-01-01"), date("2022-01-01")).is_err());
+    }
+
+    #[test]
+    fn test_apod_today_lags_utc() {
+        // В 00:00-05:00 UTC выпуск APOD ещё вчерашний
+        let lag = Utc::now().date_naive() - apod_today();
+        assert!(lag == Duration::zero() || lag == Duration::days(1));
+        assert!(validate_apod_range(apod_today(), apod_today()).is_ok());
+    }
+
+    #[test]
+    fn test_apod_api_entry_without_url() {
+        let json = r#"{
+            "date": "2024-01-05",
+            "title": "Interactive sky",
+            "explanation": "No image today",
+            "media_type": "other"
+        }"#;
+
+        let entry: ApodApiEntry = serde_json::from_str(json).unwrap();
+        assert_eq!(entry.date, date("2024-01-05"));
+        assert_eq!(entry.url, "");
+        assert!(entry.hdurl.is_none());
+    }
+}
@@ -1,60 +1,298 @@
 use crate::{
-    clients::OsdrClient,
-    domain::{error::ApiError, models::OsdrDataset},
-    repo::{cache_repo::CacheRepo, osdr_repo::OsdrRepo},
+    clients::{fixtures, OsdrClient},
+    domain::{
+        citation::{dataset_url, format_citations, CitationFormat},
+        error::{ApiError, ErrorDetail},
+        models::{
+            CreateWatchlistRequest, NotifyChannel, OsdrApiDataset, OsdrDataset, OsdrSyncRun,
+            OsdrSyncSource, OsdrSyncStats, OsdrWatchlist, RelatedOsdrDataset, WatchNotification,
+        },
+        provenance,
+        watchlist::match_dataset,
+    },
+    notifiers::{NotificationMessage, Notifier},
+    repo::{cache_repo::CacheRepo, osdr_repo::{OsdrRepo, UpsertOutcome}, WatchlistRepo},
+    utils::text_similarity::SimilarityIndex,
 };
 use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{Mutex, RwLock};
 
 pub struct OsdrService {
     osdr_client: OsdrClient,
     osdr_repo: OsdrRepo,
+    watchlist_repo: WatchlistRepo,
     cache_repo: CacheRepo,
+    dispatcher: NotificationDispatcher,
+    related: RelatedIndexer,
+    fallbacks: bool,
 }
 
 impl OsdrService {
-    pub fn new(osdr_client: OsdrClient, osdr_repo: OsdrRepo, cache_repo: CacheRepo) -> Self {
+    pub fn new(
+        osdr_client: OsdrClient,
+        osdr_repo: OsdrRepo,
+        watchlist_repo: WatchlistRepo,
+        cache_repo: CacheRepo,
+        notifiers: Vec<Box<dyn Notifier>>,
+        fallbacks: bool,
+    ) -> Self {
         Self {
             osdr_client,
+            dispatcher: NotificationDispatcher {
+                watchlist_repo: watchlist_repo.clone(),
+                notifiers: Arc::new(notifiers),
+                delivering: Arc::new(Mutex::new(())),
+            },
+            related: RelatedIndexer {
+                osdr_repo: osdr_repo.clone(),
+                current: Arc::new(RwLock::new(None)),
+                rebuilding: Arc::new(Mutex::new(())),
+            },
             osdr_repo,
+            watchlist_repo,
             cache_repo,
+            fallbacks,
         }
     }
 
-    /// Синхронизация датасетов из NASA OSDR
-    pub async fn sync_datasets(&mut self) -> Result<usize, ApiError> {
-        tracing::info!("Syncing OSDR datasets from NASA API");
+    /// Синхронизация датасетов из NASA OSDR с записью в журнал osdr_sync_runs
+    pub async fn sync_datasets(&mut self, source: OsdrSyncSource) -> Result<OsdrSyncRun, ApiError> {
+        tracing::info!("Syncing OSDR datasets from NASA API (source: {})", source.as_str());
 
-        let api_response = self.osdr_client.fetch_datasets().await?;
-        let mut saved_count = 0;
+        let run_id = self.osdr_repo.start_sync_run(source).await?;
+        let catalog_was_empty = self.osdr_repo.count().await? == 0;
+        let mut stats = OsdrSyncStats::default();
+
+        let api_response = match self.osdr_client.fetch_datasets().await {
+            Ok(response) => response,
+            Err(e) => {
+                self.osdr_repo
+                    .finish_sync_run(run_id, &stats, Some(&e.to_string()))
+                    .await?;
+                return Err(e);
+            }
+        };
+
+        let present_ids: Vec<String> = api_response
+            .results
+            .iter()
+            .map(|d| d.dataset_id.clone())
+            .collect();
+        let from_upstream = api_response.from_upstream;
+        let mut changed: Vec<(OsdrDataset, &'static str)> = Vec::new();
 
         for api_dataset in api_response.results {
-            let release_date = api_dataset
-                .release_date
-                .and_then(|s| chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok());
+            let dataset = to_dataset(api_dataset);
 
-            let dataset = OsdrDataset {
-                id: None,
-                dataset_id: api_dataset.dataset_id,
-                title: api_dataset.title,
-                description: api_dataset.description,
-                release_date,
-                updated_at: Utc::now(),
-            };
+            match self.osdr_repo.upsert_tracked(&dataset).await {
+                Ok(UpsertOutcome::Inserted) => {
+                    stats.inserted += 1;
+                    changed.push((dataset, "inserted"));
+                }
+                Ok(UpsertOutcome::Updated) => {
+                    stats.updated += 1;
+                    changed.push((dataset, "updated"));
+                }
+                Ok(UpsertOutcome::Unchanged) => stats.unchanged += 1,
+                Err(e) => {
+                    tracing::warn!("Failed to save OSDR dataset {}: {}", dataset.dataset_id, e);
+                    stats.failed += 1;
+                }
+            }
+        }
 
-            self.osdr_repo.save(&dataset).await?;
-            saved_count += 1;
+        // Отзываем только по полному каталогу OSDR: пустой ответ или демо-данные отозвали бы всё
+        if from_upstream && !present_ids.is_empty() {
+            stats.withdrawn = self.osdr_repo.mark_withdrawn_except(&present_ids).await? as i32;
         }
 
+        let run = self.osdr_repo.finish_sync_run(run_id, &stats, None).await?;
+
         // Инвалидируем кэш списка
-        self.cache_repo.delete("osdr:all").await?;
+        self.invalidate_list_cache().await?;
+
+        // Модель похожих датасетов перестраивается после каждой синхронизации - в фоне,
+        // чтобы не держать блокировку сервиса на время построения
+        let related = self.related.clone();
+        tokio::spawn(async move {
+            if let Err(e) = related.rebuild().await {
+                tracing::warn!("Failed to rebuild OSDR related-datasets index: {}", e);
+            }
+        });
+
+        // Первая загрузка в пустой каталог - не новые датасеты, а весь архив OSDR
+        if catalog_was_empty {
+            tracing::info!(
+                "Initial OSDR catalog load, skipping watchlist notifications for {} datasets",
+                changed.len()
+            );
+        } else if let Err(e) = self.record_notifications(&changed).await {
+            // Ошибки уведомлений не должны ронять синхронизацию
+            tracing::warn!("Failed to process OSDR watchlists: {}", e);
+        }
+
+        tracing::info!(
+            "OSDR sync complete: {} inserted, {} updated, {} unchanged, {} failed, {} withdrawn",
+            stats.inserted, stats.updated, stats.unchanged, stats.failed, stats.withdrawn
+        );
+        Ok(run)
+    }
+
+    /// Получить журнал последних синхронизаций
+    pub async fn get_sync_runs(&self, limit: i32) -> Result<Vec<OsdrSyncRun>, ApiError> {
+        self.osdr_repo.get_sync_runs(limit).await
+    }
+
+    /// Удалить tombstone-записи старше срока хранения
+    pub async fn purge_withdrawn(&mut self, retention_days: i64) -> Result<u64, ApiError> {
+        let purged = self.osdr_repo.purge_withdrawn_older_than(retention_days).await?;
+        if purged > 0 {
+            self.invalidate_list_cache().await?;
+        }
+        Ok(purged)
+    }
+
+    /// Записать уведомления для совпавших watchlist; доставка - через notification_dispatcher
+    async fn record_notifications(&self, changed: &[(OsdrDataset, &'static str)]) -> Result<(), ApiError> {
+        if changed.is_empty() {
+            return Ok(());
+        }
 
-        tracing::info!("OSDR sync complete: {} datasets", saved_count);
-        Ok(saved_count)
+        let watchlists = self.watchlist_repo.get_all().await?;
+        for (dataset, change) in changed {
+            for watchlist in &watchlists {
+                if let Some(terms) = match_dataset(watchlist, dataset) {
+                    self.watchlist_repo
+                        .record_notification(watchlist.id, &dataset.dataset_id, &dataset.title, change, &terms)
+                        .await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Доставщик уведомлений, которым можно пользоваться после освобождения блокировки сервиса
+    pub fn notification_dispatcher(&self) -> NotificationDispatcher {
+        self.dispatcher.clone()
+    }
+
+    /// Создать watchlist
+    pub async fn create_watchlist(
+        &self,
+        mut request: CreateWatchlistRequest,
+    ) -> Result<OsdrWatchlist, ApiError> {
+        request.keywords = clean_terms(&request.keywords);
+        request.organisms = clean_terms(&request.organisms);
+        request.target = request.target.trim().to_string();
+
+        let mut errors = Vec::new();
+        if request.keywords.is_empty() && request.organisms.is_empty() {
+            errors.push(ErrorDetail {
+                field: "keywords".to_string(),
+                message: "At least one keyword or organism is required".to_string(),
+            });
+        }
+        match self.dispatcher.notifier_for(request.channel) {
+            Some(notifier) => {
+                if let Err(e) = notifier.validate_target(&request.target) {
+                    errors.push(ErrorDetail {
+                        field: "target".to_string(),
+                        message: format!("Invalid target for channel {}: {}", request.channel.as_str(), e),
+                    });
+                }
+            }
+            None => errors.push(ErrorDetail {
+                field: "channel".to_string(),
+                message: format!("Channel {} is not configured", request.channel.as_str()),
+            }),
+        }
+        if !errors.is_empty() {
+            return Err(ApiError::ValidationError(errors));
+        }
+
+        self.watchlist_repo.create(&request).await
+    }
+
+    /// Список watchlist
+    pub async fn get_watchlists(&self) -> Result<Vec<OsdrWatchlist>, ApiError> {
+        self.watchlist_repo.get_all().await
+    }
+
+    /// Удалить watchlist
+    pub async fn delete_watchlist(&self, id: i64) -> Result<(), ApiError> {
+        if !self.watchlist_repo.delete(id).await? {
+            return Err(ApiError::NotFound(format!("Watchlist {} not found", id)));
+        }
+        Ok(())
+    }
+
+    /// История уведомлений watchlist
+    pub async fn get_watchlist_notifications(
+        &self,
+        id: i64,
+        limit: i32,
+    ) -> Result<Vec<WatchNotification>, ApiError> {
+        if self.watchlist_repo.get_by_id(id).await?.is_none() {
+            return Err(ApiError::NotFound(format!("Watchlist {} not found", id)));
+        }
+        self.watchlist_repo.get_notifications(id, limit).await
+    }
+
+    /// Модель похожих датасетов, которой можно пользоваться после освобождения блокировки сервиса
+    pub fn related_indexer(&self) -> RelatedIndexer {
+        self.related.clone()
+    }
+
+    /// Экспорт цитирований для списка accession (порядок сохраняется)
+    pub async fn get_citations(
+        &self,
+        dataset_ids: &[String],
+        format: CitationFormat,
+    ) -> Result<String, ApiError> {
+        let mut requested: Vec<String> = Vec::new();
+        for id in dataset_ids.iter().map(|id| id.trim()).filter(|id| !id.is_empty()) {
+            if !requested.iter().any(|r| r == id) {
+                requested.push(id.to_string());
+            }
+        }
+
+        if requested.is_empty() || requested.len() > MAX_CITATIONS {
+            return Err(ApiError::ValidationError(vec![ErrorDetail {
+                field: "accessions".to_string(),
+                message: format!("Expected between 1 and {} accessions", MAX_CITATIONS),
+            }]));
+        }
+
+        let found = self.osdr_repo.get_by_ids(&requested).await?;
+        let missing: Vec<&str> = requested
+            .iter()
+            .filter(|id| !found.iter().any(|d| &d.dataset_id == *id))
+            .map(|id| id.as_str())
+            .collect();
+        if !missing.is_empty() {
+            return Err(ApiError::NotFound(format!(
+                "OSDR datasets not found: {}",
+                missing.join(", ")
+            )));
+        }
+
+        let ordered: Vec<OsdrDataset> = requested
+            .iter()
+            .filter_map(|id| found.iter().find(|d| &d.dataset_id == id).cloned())
+            .collect();
+
+        Ok(format_citations(&ordered, format))
     }
 
     /// Получить все датасеты (с кэшированием)
-    pub async fn get_all_datasets(&mut self, limit: i32) -> Result<Vec<OsdrDataset>, ApiError> {
-        let cache_key = format!("osdr:all:{}", limit);
+    pub async fn get_all_datasets(
+        &mut self,
+        limit: i32,
+        include_withdrawn: bool,
+    ) -> Result<Vec<OsdrDataset>, ApiError> {
+        let cache_key = list_cache_key(limit, include_withdrawn);
 
         // Проверяем кэш (TTL 30 минут)
         if let Some(cached) = self.cache_repo.get::<Vec<OsdrDataset>>(&cache_key).await? {
@@ -63,7 +301,19 @@ impl OsdrService {
         }
 
         // Читаем из БД
-        let datasets = self.osdr_repo.get_all(limit).await?;
+        let datasets = self.osdr_repo.get_all(limit, include_withdrawn).await?;
+
+        // Каталог ещё ни разу не синхронизирован - демо-данные только в ответ, без кэша
+        if datasets.is_empty() && self.fallbacks {
+            tracing::warn!("OSDR catalog is empty, serving demo data");
+            provenance::record_fallback("OSDR catalog is not synced yet, serving demo data");
+            return Ok(fixtures::osdr_datasets()
+                .results
+                .into_iter()
+                .take(limit.max(0) as usize)
+                .map(to_dataset)
+                .collect());
+        }
 
         // Сохраняем в кэш
         self.cache_repo.set(&cache_key, &datasets, 1800).await?;
@@ -88,14 +338,240 @@ impl OsdrService {
                 description: api_dataset.description.clone(),
                 release_date,
                 updated_at: Utc::now(),
+                doi: api_dataset.doi.clone(),
+                withdrawn_at: None,
             };
 
             self.osdr_repo.save(&dataset).await?;
         }
 
-        let datasets = self.osdr_repo.get_all(100).await?;
+        let datasets = self.osdr_repo.get_all(100, false).await?;
         self.cache_repo.set("osdr:datasets", &datasets, 3600).await?;
 
         Ok(datasets)
     }
+
+    async fn invalidate_list_cache(&self) -> Result<(), ApiError> {
+        self.cache_repo.delete(&list_cache_key(LIST_LIMIT, false)).await?;
+        self.cache_repo.delete(&list_cache_key(LIST_LIMIT, true)).await?;
+        Ok(())
+    }
+}
+
+/// TF-IDF модель похожих датасетов и версия каталога, по которой она построена
+struct RelatedIndex {
+    index: SimilarityIndex,
+    titles: HashMap<String, String>,
+    /// Синхронизация, по которой построена модель (синхронизировать мог другой экземпляр)
+    version: Option<i64>,
+}
+
+/// Похожие датасеты. Построение модели - попарное сравнение всего каталога, поэтому оно идёт
+/// вне блокировки OsdrService и вне async-рантайма; готовая модель подменяется целиком
+#[derive(Clone)]
+pub struct RelatedIndexer {
+    osdr_repo: OsdrRepo,
+    current: Arc<RwLock<Option<Arc<RelatedIndex>>>>,
+    /// Не даёт строить модель дважды одновременно
+    rebuilding: Arc<Mutex<()>>,
+}
+
+impl RelatedIndexer {
+    /// Перестроить модель по текущему каталогу
+    pub async fn rebuild(&self) -> Result<(), ApiError> {
+        let _guard = self.rebuilding.lock().await;
+        self.build().await.map(|_| ())
+    }
+
+    /// Похожие датасеты (модель перестраивается, если каталог синхронизирован после её построения)
+    pub async fn related(&self, dataset_id: &str, limit: usize) -> Result<Vec<RelatedOsdrDataset>, ApiError> {
+        let version = self.osdr_repo.get_latest_finished_run_id().await?;
+        let index = match self.fresh(version).await {
+            Some(index) => index,
+            None => {
+                let _guard = self.rebuilding.lock().await;
+                // Пока ждали, модель мог перестроить другой запрос
+                match self.fresh(version).await {
+                    Some(index) => index,
+                    None => self.build().await?,
+                }
+            }
+        };
+
+        let related = index
+            .index
+            .related(dataset_id, limit.clamp(1, MAX_RELATED))
+            .ok_or_else(|| ApiError::NotFound(format!("OSDR dataset {} not found", dataset_id)))?;
+
+        Ok(related
+            .iter()
+            .map(|(id, score)| RelatedOsdrDataset {
+                dataset_id: id.clone(),
+                title: index.titles.get(id).cloned().unwrap_or_default(),
+                score: *score,
+            })
+            .collect())
+    }
+
+    async fn fresh(&self, version: Option<i64>) -> Option<Arc<RelatedIndex>> {
+        self.current
+            .read()
+            .await
+            .clone()
+            .filter(|index| index.version == version)
+    }
+
+    /// Снимок каталога из БД, построение в spawn_blocking и подмена модели
+    async fn build(&self) -> Result<Arc<RelatedIndex>, ApiError> {
+        let version = self.osdr_repo.get_latest_finished_run_id().await?;
+        let texts = self.osdr_repo.get_active_texts().await?;
+
+        let index = tokio::task::spawn_blocking(move || {
+            // Заголовок учитывается дважды: он короче и информативнее описания
+            let documents: Vec<(String, String)> = texts
+                .iter()
+                .map(|(id, title, description)| {
+                    let text = format!("{} {} {}", title, title, description.as_deref().unwrap_or(""));
+                    (id.clone(), text)
+                })
+                .collect();
+
+            RelatedIndex {
+                index: SimilarityIndex::build(&documents, MAX_RELATED),
+                titles: texts.into_iter().map(|(id, title, _)| (id, title)).collect(),
+                version,
+            }
+        })
+        .await
+        .map_err(|e| ApiError::InternalError(format!("Related-datasets index build failed: {}", e)))?;
+
+        let index = Arc::new(index);
+        *self.current.write().await = Some(index.clone());
+
+        tracing::info!("OSDR related-datasets index rebuilt: {} datasets", index.titles.len());
+        Ok(index)
+    }
+}
+
+/// Доставка уведомлений watchlist. Вебхуки и SMTP медленные (таймауты, повторы),
+/// поэтому доставка идёт вне блокировки OsdrService
+#[derive(Clone)]
+pub struct NotificationDispatcher {
+    watchlist_repo: WatchlistRepo,
+    notifiers: Arc<Vec<Box<dyn Notifier>>>,
+    /// Не даёт двум доставкам в процессе отправить одно уведомление дважды
+    delivering: Arc<Mutex<()>>,
+}
+
+impl NotificationDispatcher {
+    /// Доставить недоставленные уведомления (включая повторы после ошибок)
+    pub async fn deliver_pending(&self) -> Result<usize, ApiError> {
+        let Ok(_guard) = self.delivering.try_lock() else {
+            return Ok(0);
+        };
+
+        let pending = self.watchlist_repo.get_pending(MAX_DELIVERY_ATTEMPTS).await?;
+        if pending.is_empty() {
+            return Ok(0);
+        }
+
+        let watchlists: HashMap<i64, OsdrWatchlist> = self
+            .watchlist_repo
+            .get_all()
+            .await?
+            .into_iter()
+            .map(|w| (w.id, w))
+            .collect();
+
+        let mut delivered = 0;
+        for notification in pending {
+            let Some(watchlist) = watchlists.get(&notification.watchlist_id) else {
+                continue;
+            };
+
+            let result = match self.notifier_for(watchlist.channel) {
+                Some(notifier) => {
+                    let message = NotificationMessage {
+                        watchlist_id: watchlist.id,
+                        watchlist_name: watchlist.name.clone(),
+                        url: dataset_url(&notification.dataset_id),
+                        dataset_id: notification.dataset_id,
+                        title: notification.title,
+                        change: notification.change,
+                        matched_terms: notification.matched_terms,
+                        detected_at: notification.created_at,
+                    };
+                    notifier.deliver(&watchlist.target, &message).await
+                }
+                None => Err(format!("No notifier configured for channel {}", watchlist.channel.as_str())),
+            };
+
+            if let Err(e) = &result {
+                tracing::warn!("OSDR watchlist notification {} failed: {}", notification.id, e);
+            } else {
+                delivered += 1;
+            }
+            self.watchlist_repo
+                .mark_attempt(notification.id, result.err().as_deref())
+                .await?;
+        }
+
+        if delivered > 0 {
+            tracing::info!("Delivered {} OSDR watchlist notifications", delivered);
+        }
+        Ok(delivered)
+    }
+
+    fn notifier_for(&self, channel: NotifyChannel) -> Option<&dyn Notifier> {
+        self.notifiers
+            .iter()
+            .find(|n| n.channel() == channel)
+            .map(|n| n.as_ref())
+    }
+}
+
+/// Размер страницы /osdr/list
+pub const LIST_LIMIT: i32 = 50;
+
+/// Максимум accession в одном запросе на экспорт цитирований
+const MAX_CITATIONS: usize = 100;
+
+/// Сколько похожих датасетов хранится для каждого элемента
+const MAX_RELATED: usize = 20;
+
+/// После стольких неудачных попыток уведомление больше не доставляется
+const MAX_DELIVERY_ATTEMPTS: i32 = 5;
+
+/// Датасет API в запись каталога
+fn to_dataset(api_dataset: OsdrApiDataset) -> OsdrDataset {
+    OsdrDataset {
+        id: None,
+        dataset_id: api_dataset.dataset_id,
+        title: api_dataset.title,
+        description: api_dataset.description,
+        release_date: api_dataset
+            .release_date
+            .and_then(|s| chrono::NaiveDate::parse_from_str(&s, "%Y-%m-%d").ok()),
+        updated_at: Utc::now(),
+        doi: api_dataset.doi,
+        withdrawn_at: None,
+    }
+}
+
+fn clean_terms(terms: &[String]) -> Vec<String> {
+    let mut cleaned: Vec<String> = Vec::new();
+    for term in terms.iter().map(|t| t.trim()).filter(|t| !t.is_empty()) {
+        if !cleaned.iter().any(|c| c.eq_ignore_ascii_case(term)) {
+            cleaned.push(term.to_string());
+        }
+    }
+    cleaned
+}
+
+fn list_cache_key(limit: i32, include_withdrawn: bool) -> String {
+    if include_withdrawn {
+        format!("osdr:all:{}:withdrawn", limit)
+    } else {
+        format!("osdr:all:{}", limit)
+    }
 }
\ No newline at end of file
@@ -1,19 +1,37 @@
 use crate::{
     clients::SpaceXClient,
-    domain::error::ApiError,
-    repo::cache_repo::CacheRepo,
+    domain::{
+        error::ApiError,
+        ical::{launch_event, CalendarEvent},
+        models::{LaunchChangeKind, LaunchScheduleChange, SpaceXLaunch, SpaceXLaunchDetails, SpaceXSyncReport},
+        spacex::{parse_docs, schedule_changes, to_launch, to_launchpad, to_payload, to_rocket},
+    },
+    repo::{
+        cache_repo::CacheRepo,
+        spacex_repo::{SpaceXLaunchFilter, SpaceXRepo},
+    },
 };
+use chrono::{DateTime, Duration, Utc};
 use serde_json::Value;
+use std::collections::HashMap;
+
+/// Сколько дней прошедшие запуски остаются в календаре
+const CALENDAR_PAST_DAYS: i64 = 30;
+
+/// Максимум запусков в календаре
+const CALENDAR_LIMIT: i64 = 500;
 
 pub struct SpaceXService {
     spacex_client: SpaceXClient,
+    spacex_repo: SpaceXRepo,
     cache_repo: CacheRepo,
 }
 
 impl SpaceXService {
-    pub fn new(spacex_client: SpaceXClient, cache_repo: CacheRepo) -> Self {
+    pub fn new(spacex_client: SpaceXClient, spacex_repo: SpaceXRepo, cache_repo: CacheRepo) -> Self {
         Self {
             spacex_client,
+            spacex_repo,
             cache_repo,
         }
     }
@@ -30,4 +48,131 @@ impl SpaceXService {
 
         Ok(launch)
     }
-}
\ No newline at end of file
+
+    /// Загрузить ракеты, площадки, запуски (прошедшие и предстоящие) и полезные нагрузки в БД
+    pub async fn sync(&mut self) -> Result<SpaceXSyncReport, ApiError> {
+        let mut report = SpaceXSyncReport::default();
+
+        let (rockets, skipped) = parse_docs(self.spacex_client.query_all("rockets").await?, to_rocket);
+        report.rockets = self.spacex_repo.upsert_rockets(&rockets).await?;
+        report.skipped += skipped;
+
+        let (pads, skipped) = parse_docs(self.spacex_client.query_all("launchpads").await?, to_launchpad);
+        report.launchpads = self.spacex_repo.upsert_launchpads(&pads).await?;
+        report.skipped += skipped;
+
+        let (launches, skipped) = parse_docs(self.spacex_client.query_all("launches").await?, to_launch);
+
+        // Снимок, перезапись и история - в одной транзакции, иначе параллельный прогон
+        // мог бы сравнить запуски с уже перезаписанным состоянием
+        let detected_at = Utc::now();
+        let (upserted, recorded) = self
+            .spacex_repo
+            .upsert_launches_tracking_changes(&launches, |tracked| {
+                let tracked: HashMap<String, SpaceXLaunch> =
+                    tracked.into_iter().map(|launch| (launch.id.clone(), launch)).collect();
+                launches
+                    .iter()
+                    .filter_map(|fresh| tracked.get(&fresh.id).map(|stored| schedule_changes(stored, fresh, detected_at)))
+                    .flatten()
+                    .collect()
+            })
+            .await?;
+        report.launches = upserted;
+        report.skipped += skipped;
+        report.schedule_changes = recorded;
+
+        let (payloads, skipped) = parse_docs(self.spacex_client.query_all("payloads").await?, to_payload);
+        report.payloads = self.spacex_repo.upsert_payloads(&payloads).await?;
+        report.skipped += skipped;
+
+        if report.skipped > 0 {
+            tracing::warn!("SpaceX sync: skipped {} malformed documents", report.skipped);
+        }
+        Ok(report)
+    }
+
+    /// Архив запусков по фильтрам (только из БД)
+    pub async fn get_launches(
+        &mut self,
+        filter: &SpaceXLaunchFilter<'_>,
+        limit: i64,
+    ) -> Result<Vec<SpaceXLaunch>, ApiError> {
+        self.spacex_repo.get_launches(filter, limit).await
+    }
+
+    /// История переносов и смены статуса запуска
+    pub async fn get_launch_slips(&mut self, id: &str) -> Result<Vec<LaunchScheduleChange>, ApiError> {
+        if self.spacex_repo.get_launch(id).await?.is_none() {
+            return Err(ApiError::NotFound(format!("Launch {} not found", id)));
+        }
+        self.spacex_repo.get_launch_history(id).await
+    }
+
+    /// Лента изменений расписания по всем запускам
+    pub async fn get_schedule_changes(
+        &mut self,
+        since: DateTime<Utc>,
+        kind: Option<LaunchChangeKind>,
+        limit: i64,
+    ) -> Result<Vec<LaunchScheduleChange>, ApiError> {
+        self.spacex_repo
+            .get_schedule_changes(since, kind.as_ref().map(LaunchChangeKind::as_str), limit)
+            .await
+    }
+
+    /// События календаря: предстоящие и недавние запуски; SEQUENCE - число изменений расписания
+    pub async fn get_calendar_events(&mut self, alarm_minutes: i64) -> Result<Vec<CalendarEvent>, ApiError> {
+        let filter = SpaceXLaunchFilter {
+            from: Some(Utc::now() - Duration::days(CALENDAR_PAST_DAYS)),
+            ..Default::default()
+        };
+        let launches = self.spacex_repo.get_launches(&filter, CALENDAR_LIMIT).await?;
+
+        let ids: Vec<String> = launches.iter().map(|l| l.id.clone()).collect();
+        let revisions: HashMap<String, (i64, DateTime<Utc>)> = self
+            .spacex_repo
+            .get_schedule_revisions(&ids)
+            .await?
+            .into_iter()
+            .map(|(id, count, last)| (id, (count, last)))
+            .collect();
+
+        Ok(launches
+            .iter()
+            .filter_map(|launch| {
+                let (sequence, last_modified) = match revisions.get(&launch.id) {
+                    Some(&(count, last)) => (count, Some(last)),
+                    None => (0, None),
+                };
+                launch_event(launch, sequence, last_modified, alarm_minutes)
+            })
+            .collect())
+    }
+
+    /// Запуск с ракетой, площадкой и полезными нагрузками
+    pub async fn get_launch(&mut self, id: &str) -> Result<SpaceXLaunchDetails, ApiError> {
+        let launch = self
+            .spacex_repo
+            .get_launch(id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("Launch {} not found", id)))?;
+
+        let rocket = match &launch.rocket_id {
+            Some(rocket_id) => self.spacex_repo.get_rocket(rocket_id).await?,
+            None => None,
+        };
+        let launchpad = match &launch.launchpad_id {
+            Some(launchpad_id) => self.spacex_repo.get_launchpad(launchpad_id).await?,
+            None => None,
+        };
+        let payloads = self.spacex_repo.get_payloads(id).await?;
+
+        Ok(SpaceXLaunchDetails {
+            launch,
+            rocket,
+            launchpad,
+            payloads,
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use crate::utils::orbit::{gmst, julian_date_at, J2000_JD};
+use chrono::{DateTime, Utc};
+
+/// Видимое положение светила (углы в радианах)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CelestialPosition {
+    /// Эклиптическая долгота
+    pub longitude: f64,
+    pub right_ascension: f64,
+    pub declination: f64,
+    /// Горизонтальный параллакс (для Солнца пренебрежимо мал)
+    pub parallax: f64,
+}
+
+/// Положение Солнца по формулам Astronomical Almanac низкой точности (~0.01° в 1950-2050)
+pub fn sun_position(at: DateTime<Utc>) -> CelestialPosition {
+    let n = julian_date_at(at) - J2000_JD;
+    let mean_longitude = 280.460 + 0.9856474 * n;
+    let mean_anomaly = (357.528 + 0.9856003 * n).to_radians();
+    let longitude =
+        (mean_longitude + 1.915 * mean_anomaly.sin() + 0.020 * (2.0 * mean_anomaly).sin()).to_radians();
+
+    to_equatorial(longitude, 0.0, n, 0.0)
+}
+
+/// Положение Луны (геоцентрическое) по формулам Astronomical Almanac низкой точности (~0.3°)
+pub fn moon_position(at: DateTime<Utc>) -> CelestialPosition {
+    let n = julian_date_at(at) - J2000_JD;
+    let t = n / 36525.0;
+    let sin = |a: f64, b: f64| (a + b * t).to_radians().sin();
+    let cos = |a: f64, b: f64| (a + b * t).to_radians().cos();
+
+    let longitude = 218.32 + 481267.881 * t + 6.29 * sin(135.0, 477198.87) - 1.27 * sin(259.3, -413335.36)
+        + 0.66 * sin(235.7, 890534.22)
+        + 0.21 * sin(269.9, 954397.74)
+        - 0.19 * sin(357.5, 35999.05)
+        - 0.11 * sin(186.5, 966404.03);
+    let latitude = 5.13 * sin(93.3, 483202.02) + 0.28 * sin(228.2, 960400.89)
+        - 0.28 * sin(318.3, 6003.15)
+        - 0.17 * sin(217.6, -407332.21);
+    let parallax = 0.9508
+        + 0.0518 * cos(135.0, 477198.87)
+        + 0.0095 * cos(259.3, -413335.36)
+        + 0.0078 * cos(235.7, 890534.22)
+        + 0.0028 * cos(269.9, 954397.74);
+
+    to_equatorial(longitude.to_radians(), latitude.to_radians(), n, parallax.to_radians())
+}
+
+fn to_equatorial(longitude: f64, latitude: f64, days_since_j2000: f64, parallax: f64) -> CelestialPosition {
+    let obliquity = (23.439 - 0.0000004 * days_since_j2000).to_radians();
+    let (sin_lon, cos_lon) = longitude.sin_cos();
+    let (sin_lat, cos_lat) = latitude.sin_cos();
+    let (sin_eps, cos_eps) = obliquity.sin_cos();
+
+    let right_ascension = (sin_lon * cos_lat * cos_eps - sin_lat * sin_eps).atan2(cos_lon * cos_lat);
+    let declination = (sin_lat * cos_eps + cos_lat * sin_eps * sin_lon).asin();
+
+    CelestialPosition {
+        longitude: longitude.rem_euclid(std::f64::consts::TAU),
+        right_ascension: right_ascension.rem_euclid(std::f64::consts::TAU),
+        declination,
+        parallax,
+    }
+}
+
+/// Часовой угол светила для долготы lon (рад, от -π до π; 0 - верхняя кульминация)
+pub fn hour_angle(position: &CelestialPosition, lon: f64, at: DateTime<Utc>) -> f64 {
+    let angle = gmst(at) + lon.to_radians() - position.right_ascension;
+    (angle + std::f64::consts::PI).rem_euclid(std::f64::consts::TAU) - std::f64::consts::PI
+}
+
+/// Геоцентрическая высота светила над горизонтом (градусы)
+pub fn altitude(position: &CelestialPosition, lat: f64, lon: f64, at: DateTime<Utc>) -> f64 {
+    let (sin_lat, cos_lat) = lat.to_radians().sin_cos();
+    let (sin_dec, cos_dec) = position.declination.sin_cos();
+    (sin_lat * sin_dec + cos_lat * cos_dec * hour_angle(position, lon, at).cos())
+        .asin()
+        .to_degrees()
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_sun_at_solstice_and_equinox() {
+        let solstice = sun_position(Utc.with_ymd_and_hms(2024, 6, 20, 20, 51, 0).unwrap());
+        assert!((solstice.declination.to_degrees() - 23.44).abs() < 0.02);
+        assert!((solstice.longitude.to_degrees() - 90.0).abs() < 0.02);
+
+        let equinox = sun_position(Utc.with_ymd_and_hms(2024, 3, 20, 3, 6, 0).unwrap());
+        assert!(equinox.declination.to_degrees().abs() < 0.02);
+    }
+
+    #[test]
+    fn test_moon_opposite_sun_at_full_moon() {
+        // Полнолуние 22.06.2024 01:08 UTC
+        let at = Utc.with_ymd_and_hms(2024, 6, 22, 1, 8, 0).unwrap();
+        let elongation = (moon_position(at).longitude - sun_position(at).longitude).to_degrees().rem_euclid(360.0);
+        assert!((elongation - 180.0).abs() < 1.0, "elongation {}", elongation);
+
+        let parallax = moon_position(at).parallax.to_degrees();
+        assert!((0.89..1.0).contains(&parallax));
+    }
+
+    #[test]
+    fn test_sun_altitude_at_noon() {
+        // Солнечный полдень на Гринвиче в день равноденствия: высота 90° - широта
+        let at = Utc.with_ymd_and_hms(2024, 3, 20, 12, 7, 0).unwrap();
+        let sun = sun_position(at);
+        assert!(hour_angle(&sun, 0.0, at).to_degrees().abs() < 0.5);
+        assert!((altitude(&sun, 51.48, 0.0, at) - 38.52).abs() < 0.2);
+    }
+}
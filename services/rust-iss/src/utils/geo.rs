@@ -0,0 +1,59 @@
+/// Средний радиус Земли (км)
+pub const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Широта/долгота (градусы) в единичный вектор
+pub fn to_unit_vector(lat: f64, lon: f64) -> [f64; 3] {
+    let (lat, lon) = (lat.to_radians(), lon.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+/// Единичный (или любой ненулевой) вектор в широту/долготу (градусы)
+pub fn from_vector(v: [f64; 3]) -> (f64, f64) {
+    let [x, y, z] = v;
+    let lat = z.atan2((x * x + y * y).sqrt()).to_degrees();
+    let lon = y.atan2(x).to_degrees();
+    (lat, lon)
+}
+
+/// Расстояние по большому кругу (км)
+pub fn haversine_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+}
+
+/// Точка между a и b (доля t от 0 до 1) по кратчайшей дуге; корректно через антимеридиан
+pub fn interpolate(a: (f64, f64), b: (f64, f64), t: f64) -> (f64, f64) {
+    let va = to_unit_vector(a.0, a.1);
+    let vb = to_unit_vector(b.0, b.1);
+    let v = [
+        va[0] + (vb[0] - va[0]) * t,
+        va[1] + (vb[1] - va[1]) * t,
+        va[2] + (vb[2] - va[2]) * t,
+    ];
+    if v.iter().all(|c| c.abs() < 1e-12) {
+        return a;
+    }
+    from_vector(v)
+}
+
+/// Геометрический центр набора точек на сфере
+pub fn centroid(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let sum = points.iter().fold([0.0; 3], |acc, &(lat, lon)| {
+        let v = to_unit_vector(lat, lon);
+        [acc[0] + v[0], acc[1] + v[1], acc[2] + v[2]]
+    });
+    if sum.iter().all(|c| c.abs() < 1e-12) {
+        return None;
+    }
+    Some(from_vector(sum))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_haversine_known_distance() {
+        // Москва - Санкт-Петербург ≈ 634 км
+        let d = haversine_km(55.7558, 37.6173, 59.9343, 30.3351);
+        assert!((d - 634.0).abs() < 5.0, "distance {}", d);
+        assert_eq!(haversine_km(10.0, 20.0, 10.0, 20.0), 0.0);
+    }
+
+    #[test]
+    fn test_interpolate_crosses_antimeridian() {
+        let (lat, lon) = interpolate((0.0, 179.0), (0.0, -179.0), 0.5);
+        assert!(lat.abs() < 1e-9);
+        assert!((lon.abs() - 180.0).abs() < 1e-9, "lon {}", lon);
+    }
+
+    #[test]
+    fn test_interpolate_endpoints() {
+        let (lat, lon) = interpolate((10.0, 20.0), (30.0, 40.0), 0.0);
+        assert!((lat - 10.0).abs() < 1e-9 && (lon - 20.0).abs() < 1e-9);
+        let (lat, lon) = interpolate((10.0, 20.0), (30.0, 40.0), 1.0);
+        assert!((lat - 30.0).abs() < 1e-9 && (lon - 40.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_centroid() {
+        let (lat, lon) = centroid(&[(0.0, -1.0), (0.0, 1.0), (1.0, 0.0), (-1.0, 0.0)]).unwrap();
+        assert!(lat.abs() < 1e-9 && lon.abs() < 1e-9);
+        assert!(centroid(&[]).is_none());
+    }
+}
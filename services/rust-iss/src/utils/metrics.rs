@@ -128,6 +128,28 @@ lazy_static! {
     )
     .unwrap();
 
+    // NASA API quota metrics
+    pub static ref NASA_API_RATE_LIMIT: IntGaugeVec = register_int_gauge_vec!(
+        "nasa_api_rate_limit",
+        "NASA API hourly request limit (X-RateLimit-Limit)",
+        &["key"]
+    )
+    .unwrap();
+
+    pub static ref NASA_API_RATE_REMAINING: IntGaugeVec = register_int_gauge_vec!(
+        "nasa_api_rate_remaining",
+        "NASA API requests remaining in the current window (X-RateLimit-Remaining)",
+        &["key"]
+    )
+    .unwrap();
+
+    pub static ref NASA_API_BUDGET_DENIED_TOTAL: IntCounterVec = register_int_counter_vec!(
+        "nasa_api_budget_denied_total",
+        "NASA API requests skipped because the quota budget was exhausted",
+        &["priority"]
+    )
+    .unwrap();
+
     // Advisory locks metrics
     pub static ref ADVISORY_LOCKS_ACQUIRED: IntCounterVec = register_int_counter_vec!(
         "advisory_locks_acquired",
@@ -243,6 +265,27 @@ pub fn record_external_api_request(api: &str, success: bool, duration_secs: f64)
         .observe(duration_secs);
 }
 
+/// Record NASA API quota headers
+pub fn record_nasa_quota(key: &str, limit: Option<i64>, remaining: Option<i64>) {
+    if let Some(limit) = limit {
+        NASA_API_RATE_LIMIT
+            .with_label_values(&[key])
+            .set(limit);
+    }
+
+    if let Some(remaining) = remaining {
+        NASA_API_RATE_REMAINING
+            .with_label_values(&[key])
+            .set(remaining);
+    }
+}
+
+pub fn record_nasa_budget_denied(priority: &str) {
+    NASA_API_BUDGET_DENIED_TOTAL
+        .with_label_values(&[priority])
+        .inc();
+}
+
 /// Record advisory lock metrics
 pub fn record_advisory_lock_acquired(lock_id: i64) {
     ADVISORY_LOCKS_ACQUIRED
@@ -1 +1,5 @@
+pub mod ephemeris;
+pub mod geo;
 pub mod metrics;
+pub mod orbit;
+pub mod text_similarity;
@@ -0,0 +1,228 @@
+use crate::domain::models::{OrbitalElements, Tle};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::f64::consts::TAU;
+
+/// Юлианская дата UNIX-эпохи (1970-01-01 00:00 UTC)
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+
+/// Юлианская дата эпохи J2000.0
+pub const J2000_JD: f64 = 2451545.0;
+
+/// Точность решения уравнения Кеплера (рад)
+const KEPLER_TOLERANCE: f64 = 1e-12;
+
+/// Гравитационный параметр Земли (км³/с²)
+const EARTH_MU: f64 = 398600.4418;
+
+/// Экваториальный радиус Земли WGS84 (км)
+const EARTH_EQUATORIAL_RADIUS_KM: f64 = 6378.137;
+
+/// Сжатие Земли WGS84
+const EARTH_FLATTENING: f64 = 1.0 / 298.257223563;
+
+/// Вторая зональная гармоника геопотенциала
+const EARTH_J2: f64 = 1.08262668e-3;
+
+/// Юлианская дата на полночь UTC указанного дня
+pub fn julian_date(date: NaiveDate) -> f64 {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid unix epoch");
+    UNIX_EPOCH_JD + (date - epoch).num_days() as f64
+}
+
+/// Юлианская дата момента времени
+pub fn julian_date_at(at: DateTime<Utc>) -> f64 {
+    UNIX_EPOCH_JD + at.timestamp_millis() as f64 / 86_400_000.0
+}
+
+/// Решить уравнение Кеплера E - e·sin(E) = M методом Ньютона (только эллиптические орбиты)
+pub fn eccentric_anomaly(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    let m = mean_anomaly.rem_euclid(std::f64::consts::TAU);
+    // Для больших эксцентриситетов старт с π сходится надёжнее
+    let mut e_anomaly = if eccentricity > 0.8 { std::f64::consts::PI } else { m };
+
+    for _ in 0..50 {
+        let delta = (e_anomaly - eccentricity * e_anomaly.sin() - m) / (1.0 - eccentricity * e_anomaly.cos());
+        e_anomaly -= delta;
+        if delta.abs() < KEPLER_TOLERANCE {
+            break;
+        }
+    }
+
+    e_anomaly
+}
+
+/// Гелиоцентрическое положение (x, y, z, а.е., эклиптика J2000) на юлианскую дату
+pub fn heliocentric_position(elements: &OrbitalElements, jd: f64) -> Result<[f64; 3], String> {
+    let e = elements.eccentricity;
+    if !(0.0..1.0).contains(&e) {
+        return Err(format!("Only elliptic orbits are supported (e = {})", e));
+    }
+
+    let a = elements.semi_major_axis_au;
+    let mean_anomaly =
+        (elements.mean_anomaly_deg + elements.mean_motion_deg_per_day * (jd - elements.epoch_jd)).to_radians();
+    let e_anomaly = eccentric_anomaly(mean_anomaly, e);
+
+    // Положение в плоскости орбиты, ось x направлена на перигелий
+    let x_orbit = a * (e_anomaly.cos() - e);
+    let y_orbit = a * (1.0 - e * e).sqrt() * e_anomaly.sin();
+
+    Ok(orbit_to_reference(
+        x_orbit,
+        y_orbit,
+        elements.ascending_node_deg.to_radians(),
+        elements.perihelion_argument_deg.to_radians(),
+        elements.inclination_deg.to_radians(),
+    ))
+}
+
+/// Поворот из плоскости орбиты (ось x на перицентр) в опорную систему (углы в радианах)
+fn orbit_to_reference(x_orbit: f64, y_orbit: f64, node: f64, argument: f64, inclination: f64) -> [f64; 3] {
+    let (sin_node, cos_node) = node.sin_cos();
+    let (sin_peri, cos_peri) = argument.sin_cos();
+    let (sin_inc, cos_inc) = inclination.sin_cos();
+
+    let x = (cos_node * cos_peri - sin_node * sin_peri * cos_inc) * x_orbit
+        + (-cos_node * sin_peri - sin_node * cos_peri * cos_inc) * y_orbit;
+    let y = (sin_node * cos_peri + cos_node * sin_peri * cos_inc) * x_orbit
+        + (-sin_node * sin_peri + cos_node * cos_peri * cos_inc) * y_orbit;
+    let z = (sin_peri * sin_inc) * x_orbit + (cos_peri * sin_inc) * y_orbit;
+
+    [x, y, z]
+}
+
+/// Разобрать TLE; строки проверяются по номеру и контрольной сумме
+pub fn parse_tle(name: &str, line1: &str, line2: &str) -> Result<Tle, String> {
+    let (line1, line2) = (line1.trim_end(), line2.trim_end());
+    if !line1.starts_with("1 ") || !line2.starts_with("2 ") || line1.len() < 69 || line2.len() < 69 {
+        return Err("Malformed TLE lines".to_string());
+    }
+    verify_checksum(line1)?;
+    verify_checksum(line2)?;
+
+    let year = tle_field(line1, 18..20)? as i32;
+    let year = if year < 57 { 2000 + year } else { 1900 + year };
+    let day_of_year = tle_field(line1, 20..32)?;
+    let epoch = NaiveDate::from_ymd_opt(year, 1, 1)
+        .ok_or("Invalid TLE epoch year")?
+        .and_hms_opt(0, 0, 0)
+        .ok_or("Invalid TLE epoch")?
+        .and_utc()
+        + Duration::milliseconds(((day_of_year - 1.0) * 86_400_000.0).round() as i64);
+
+    Ok(Tle {
+        name: name.trim().to_string(),
+        line1: line1.to_string(),
+        line2: line2.to_string(),
+        epoch,
+        inclination_deg: tle_field(line2, 8..16)?,
+        ascending_node_deg: tle_field(line2, 17..25)?,
+        eccentricity: format!("0.{}", line2[26..33].trim())
+            .parse()
+            .map_err(|_| "Invalid TLE eccentricity".to_string())?,
+        perigee_argument_deg: tle_field(line2, 34..42)?,
+        mean_anomaly_deg: tle_field(line2, 43..51)?,
+        mean_motion_rev_per_day: tle_field(line2, 52..63)?,
+        mean_motion_dot: tle_field(line1, 33..43)?,
+        revolution: tle_field(line2, 63..68)? as i64,
+    })
+}
+
+fn tle_field(line: &str, range: std::ops::Range<usize>) -> Result<f64, String> {
+    let raw = line.get(range.clone()).ok_or("Truncated TLE line")?.trim();
+    raw.parse()
+        .map_err(|_| format!("Invalid TLE field at columns {}-{}: '{}'", range.start + 1, range.end, raw))
+}
+
+/// Контрольная сумма: цифры складываются, минус считается за 1, по модулю 10
+fn verify_checksum(line: &str) -> Result<(), String> {
+    let expected = line[68..69].parse::<u32>().map_err(|_| "Invalid TLE checksum".to_string())?;
+    let sum: u32 = line[..68]
+        .chars()
+        .map(|c| match c {
+            '-' => 1,
+            c => c.to_digit(10).unwrap_or(0),
+        })
+        .sum();
+    if sum % 10 != expected {
+        return Err(format!("TLE checksum mismatch in line {}", &line[..1]));
+    }
+    Ok(())
+}
+
+/// Положение спутника (км, экваториальная система на дату) по TLE.
+/// Упрощённая модель: кеплерово движение с вековыми возмущениями от J2 и торможением из TLE;
+/// для МКС на горизонте нескольких суток ошибка по времени порядка минуты
+pub fn tle_position(tle: &Tle, at: DateTime<Utc>) -> [f64; 3] {
+    let minutes = (at - tle.epoch).num_milliseconds() as f64 / 60_000.0;
+    let days = minutes / 1440.0;
+
+    let e = tle.eccentricity;
+    let inclination = tle.inclination_deg.to_radians();
+    // Среднее движение (рад/мин) и большая полуось (км)
+    let n = tle.mean_motion_rev_per_day * TAU / 1440.0;
+    let a = (EARTH_MU * 3600.0 / (n * n)).cbrt();
+    let p = a * (1.0 - e * e);
+    let j2_rate = 1.5 * EARTH_J2 * (EARTH_EQUATORIAL_RADIUS_KM / p).powi(2) * n;
+
+    let node = tle.ascending_node_deg.to_radians() - j2_rate * inclination.cos() * minutes;
+    let argument =
+        tle.perigee_argument_deg.to_radians() + j2_rate * (2.0 - 2.5 * inclination.sin().powi(2)) * minutes;
+    let mean_anomaly = tle.mean_anomaly_deg.to_radians() + n * minutes + TAU * tle.mean_motion_dot * days * days;
+
+    let e_anomaly = eccentric_anomaly(mean_anomaly, e);
+    let x_orbit = a * (e_anomaly.cos() - e);
+    let y_orbit = a * (1.0 - e * e).sqrt() * e_anomaly.sin();
+
+    orbit_to_reference(x_orbit, y_orbit, node, argument, inclination)
+}
+
+/// Номер витка на момент at (виток начинается в восходящем узле)
+pub fn tle_revolution(tle: &Tle, at: DateTime<Utc>) -> i64 {
+    let days = (at - tle.epoch).num_milliseconds() as f64 / 86_400_000.0;
+    let phase = (tle.perigee_argument_deg + tle.mean_anomaly_deg) / 360.0;
+    tle.revolution + (phase.rem_euclid(1.0) + tle.mean_motion_rev_per_day * days).floor() as i64
+}
+
+/// Среднее звёздное время по Гринвичу (рад)
+pub fn gmst(at: DateTime<Utc>) -> f64 {
+    let d = julian_date_at(at) - J2000_JD;
+    let t = d / 36525.0;
+    let degrees = 280.46061837 + 360.98564736629 * d + 0.000387933 * t * t - t * t * t / 38_710_000.0;
+    degrees.rem_euclid(360.0).to_radians()
+}
+
+/// Угол места и азимут (градусы, азимут от севера по часовой) объекта с экваториальными
+/// координатами position (км) для наблюдателя на поверхности эллипсоида WGS84
+pub fn look_angles(lat: f64, lon: f64, position: [f64; 3], at: DateTime<Utc>) -> (f64, f64) {
+    // В земную систему координат
+    let (sin_g, cos_g) = gmst(at).sin_cos();
+    let target = [
+        cos_g * position[0] + sin_g * position[1],
+        -sin_g * position[0] + cos_g * position[1],
+        position[2],
+    ];
+
+    let (sin_lat, cos_lat) = lat.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = lon.to_radians().sin_cos();
+    let e2 = EARTH_FLATTENING * (2.0 - EARTH_FLATTENING);
+    let radius = EARTH_EQUATORIAL_RADIUS_KM / (1.0 - e2 * sin_lat * sin_lat).sqrt();
+    let observer = [
+        radius * cos_lat * cos_lon,
+        radius * cos_lat * sin_lon,
+        radius * (1.0 - e2) * sin_lat,
+    ];
+
+    let range = [target[0] - observer[0], target[1] - observer[1], target[2] - observer[2]];
+    let south = sin_lat * cos_lon * range[0] + sin_lat * sin_lon * range[1] - cos_lat * range[2];
+    let east = -sin_lon * range[0] + cos_lon * range[1];
+    let zenith = cos_lat * cos_lon * range[0] + cos_lat * sin_lon * range[1] + sin_lat * range[2];
+
+    let distance = (south * south + east * east + zenith * zenith).sqrt();
+    let elevation = (zenith / distance).asin().to_degrees();
+    let azimuth = east.atan2(-south).to_degrees().rem_euclid(360.0);
+    (elevation, azimuth)
+}
+
+#[cfg(test)]
+mod tests;
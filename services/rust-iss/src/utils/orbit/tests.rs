@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    fn elements(eccentricity: f64, inclination_deg: f64) -> OrbitalElements {
+        OrbitalElements {
+            orbit_id: "1".to_string(),
+            epoch_jd: 2451545.0,
+            eccentricity,
+            semi_major_axis_au: 2.0,
+            inclination_deg,
+            ascending_node_deg: 0.0,
+            perihelion_argument_deg: 0.0,
+            mean_anomaly_deg: 0.0,
+            mean_motion_deg_per_day: 1.0,
+            orbital_period_days: 360.0,
+            perihelion_distance_au: 2.0 * (1.0 - eccentricity),
+            aphelion_distance_au: 2.0 * (1.0 + eccentricity),
+            orbit_class: None,
+        }
+    }
+
+    fn distance(p: [f64; 3]) -> f64 {
+        (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt()
+    }
+
+    #[test]
+    fn test_julian_date() {
+        assert_eq!(julian_date(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()), 2451544.5);
+        assert_eq!(julian_date(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()), 2440587.5);
+    }
+
+    #[test]
+    fn test_eccentric_anomaly_solves_kepler_equation() {
+        for &e in &[0.0, 0.2, 0.7, 0.95] {
+            for &m in &[0.1, 1.0, 3.0, 5.5] {
+                let big_e = eccentric_anomaly(m, e);
+                assert!((big_e - e * big_e.sin() - m).abs() < 1e-9, "e={} m={}", e, m);
+            }
+        }
+    }
+
+    #[test]
+    fn test_circular_orbit_quarter_period() {
+        let orbit = elements(0.0, 0.0);
+
+        let start = heliocentric_position(&orbit, orbit.epoch_jd).unwrap();
+        assert!((start[0] - 2.0).abs() < 1e-9 && start[1].abs() < 1e-9);
+
+        let quarter = heliocentric_position(&orbit, orbit.epoch_jd + 90.0).unwrap();
+        assert!(quarter[0].abs() < 1e-9 && (quarter[1] - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_eccentric_orbit_stays_between_apsides() {
+        let orbit = elements(0.5, 30.0);
+
+        let perihelion = heliocentric_position(&orbit, orbit.epoch_jd).unwrap();
+        assert!((distance(perihelion) - orbit.perihelion_distance_au).abs() < 1e-9);
+
+        let aphelion = heliocentric_position(&orbit, orbit.epoch_jd + 180.0).unwrap();
+        assert!((distance(aphelion) - orbit.aphelion_distance_au).abs() < 1e-9);
+
+        // Наклонённая орбита выходит из плоскости эклиптики
+        let quarter = heliocentric_position(&orbit, orbit.epoch_jd + 90.0).unwrap();
+        assert!(quarter[2] > 0.0);
+    }
+
+    #[test]
+    fn test_rejects_hyperbolic_orbit() {
+        assert!(heliocentric_position(&elements(1.2, 0.0), 2451545.0).is_err());
+    }
+
+    const ISS_LINE1: &str = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+    const ISS_LINE2: &str = "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+
+    #[test]
+    fn test_parse_tle() {
+        let tle = parse_tle("ISS (ZARYA)", ISS_LINE1, ISS_LINE2).unwrap();
+        assert_eq!(tle.epoch.to_rfc3339(), "2008-09-20T12:25:40.104+00:00");
+        assert_eq!(tle.inclination_deg, 51.6416);
+        assert_eq!(tle.eccentricity, 0.0006703);
+        assert_eq!(tle.mean_motion_rev_per_day, 15.72125391);
+        assert_eq!(tle.mean_motion_dot, -0.00002182);
+        assert_eq!(tle.revolution, 56353);
+
+        let corrupted = ISS_LINE2.replace("51.6416", "51.6417");
+        assert!(parse_tle("ISS", ISS_LINE1, &corrupted).is_err());
+        assert!(parse_tle("ISS", ISS_LINE2, ISS_LINE1).is_err());
+    }
+
+    #[test]
+    fn test_tle_position_stays_on_low_orbit() {
+        let tle = parse_tle("ISS", ISS_LINE1, ISS_LINE2).unwrap();
+        let max_z = 6800.0 * tle.inclination_deg.to_radians().sin();
+
+        for minutes in (0..3 * 1440).step_by(7) {
+            let position = tle_position(&tle, tle.epoch + Duration::minutes(minutes));
+            let altitude = distance(position) - 6378.137;
+            assert!((320.0..380.0).contains(&altitude), "altitude {} at +{}m", altitude, minutes);
+            assert!(position[2].abs() <= max_z);
+        }
+
+        // Через виток спутник возвращается почти в ту же точку (узел дрейфует на ~0.4°)
+        let period = Duration::milliseconds((86_400_000.0 / tle.mean_motion_rev_per_day) as i64);
+        let start = tle_position(&tle, tle.epoch);
+        let after = tle_position(&tle, tle.epoch + period);
+        let drift = distance([after[0] - start[0], after[1] - start[1], after[2] - start[2]]);
+        assert!(drift < 60.0, "drift {} km", drift);
+        assert_eq!(tle_revolution(&tle, tle.epoch + period * 3), tle.revolution + 3);
+    }
+
+    #[test]
+    fn test_gmst_at_j2000() {
+        let j2000 = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap().and_utc();
+        assert!((gmst(j2000).to_degrees() - 280.46061837).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_look_angles() {
+        let at = NaiveDate::from_ymd_opt(2024, 3, 20).unwrap().and_hms_opt(0, 0, 0).unwrap().and_utc();
+        let (sin_g, cos_g) = gmst(at).sin_cos();
+        // Объект в 400 км над точкой (0°, 0°), переведённый в экваториальную систему
+        let r = 6378.137 + 400.0;
+        let overhead = [r * cos_g, r * sin_g, 0.0];
+        let (elevation, _) = look_angles(0.0, 0.0, overhead, at);
+        assert!((elevation - 90.0).abs() < 1e-6);
+
+        // Для наблюдателя южнее объект на севере и над горизонтом
+        let (elevation, azimuth) = look_angles(-10.0, 0.0, overhead, at);
+        assert!(elevation > 0.0 && elevation < 90.0);
+        assert!(!(1.0..=359.0).contains(&azimuth));
+
+        // С противоположной стороны Земли - под горизонтом
+        assert!(look_angles(0.0, 180.0, overhead, at).0 < -80.0);
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+/// Стоп-слова, которые не несут смысла для сравнения описаний датасетов
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "into", "is", "it",
+    "its", "of", "on", "or", "that", "the", "their", "this", "to", "was", "were", "with",
+];
+
+/// Разреженный L2-нормированный TF-IDF вектор: (term_id, weight), отсортирован по term_id
+type SparseVector = Vec<(usize, f64)>;
+
+/// In-process TF-IDF модель с заранее посчитанными ближайшими соседями
+pub struct SimilarityIndex {
+    neighbours: HashMap<String, Vec<(String, f64)>>,
+}
+
+impl SimilarityIndex {
+    /// Построить индекс по документам (id, текст) и сохранить top_k соседей для каждого
+    pub fn build(documents: &[(String, String)], top_k: usize) -> Self {
+        let tokenized: Vec<Vec<String>> = documents.iter().map(|(_, text)| tokenize(text)).collect();
+
+        let mut vocabulary: HashMap<&str, usize> = HashMap::new();
+        let mut document_frequency: Vec<usize> = Vec::new();
+        for tokens in &tokenized {
+            let mut seen: Vec<usize> = Vec::new();
+            for token in tokens {
+                let next_id = vocabulary.len();
+                let term_id = *vocabulary.entry(token.as_str()).or_insert(next_id);
+                if term_id == document_frequency.len() {
+                    document_frequency.push(0);
+                }
+                if !seen.contains(&term_id) {
+                    seen.push(term_id);
+                    document_frequency[term_id] += 1;
+                }
+            }
+        }
+
+        let total = documents.len() as f64;
+        let idf: Vec<f64> = document_frequency
+            .iter()
+            .map(|&df| ((1.0 + total) / (1.0 + df as f64)).ln() + 1.0)
+            .collect();
+
+        let vectors: Vec<SparseVector> = tokenized
+            .iter()
+            .map(|tokens| {
+                let mut counts: HashMap<usize, usize> = HashMap::new();
+                for token in tokens {
+                    *counts.entry(vocabulary[token.as_str()]).or_insert(0) += 1;
+                }
+
+                let mut vector: SparseVector = counts
+                    .into_iter()
+                    .map(|(term_id, count)| (term_id, (1.0 + (count as f64).ln()) * idf[term_id]))
+                    .collect();
+                vector.sort_by_key(|(term_id, _)| *term_id);
+
+                let norm = vector.iter().map(|(_, w)| w * w).sum::<f64>().sqrt();
+                if norm > 0.0 {
+                    for (_, weight) in vector.iter_mut() {
+                        *weight /= norm;
+                    }
+                }
+                vector
+            })
+            .collect();
+
+        let mut neighbours = HashMap::with_capacity(documents.len());
+        for (i, (id, _)) in documents.iter().enumerate() {
+            let mut scored: Vec<(String, f64)> = documents
+                .iter()
+                .enumerate()
+                .filter(|(j, (other_id, _))| *j != i && other_id != id)
+                .map(|(j, (other_id, _))| (other_id.clone(), cosine(&vectors[i], &vectors[j])))
+                .filter(|(_, score)| *score > 0.0)
+                .collect();
+
+            scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            scored.truncate(top_k);
+            neighbours.insert(id.clone(), scored);
+        }
+
+        Self { neighbours }
+    }
+
+    /// Соседи документа по убыванию сходства; None, если документа нет в индексе
+    pub fn related(&self, id: &str, limit: usize) -> Option<&[(String, f64)]> {
+        self.neighbours
+            .get(id)
+            .map(|scored| &scored[..scored.len().min(limit)])
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(|token| token.to_lowercase())
+        .filter(|token| token.chars().count() > 1 && !STOP_WORDS.contains(&token.as_str()))
+        .collect()
+}
+
+fn cosine(a: &SparseVector, b: &SparseVector) -> f64 {
+    let (mut i, mut j, mut dot) = (0, 0, 0.0);
+    while i < a.len() && j < b.len() {
+        match a[i].0.cmp(&b[j].0) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                dot += a[i].1 * b[j].1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    dot
+}
+
+#[cfg(test)]
+mod tests;
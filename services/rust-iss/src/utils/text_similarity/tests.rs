@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    fn documents() -> Vec<(String, String)> {
+        vec![
+            ("GLDS-379".to_string(), "Rodent Research-1: spaceflight bone loss in mice".to_string()),
+            ("GLDS-242".to_string(), "Rodent Research-9: mice bone density after spaceflight".to_string()),
+            ("GLDS-38".to_string(), "APEX-03: Arabidopsis root gravitropism in microgravity".to_string()),
+            ("GLDS-7".to_string(), "Arabidopsis thaliana root growth on the ISS".to_string()),
+            ("GLDS-1".to_string(), "Unrelated telemetry archive".to_string()),
+        ]
+    }
+
+    #[test]
+    fn test_related_ranks_similar_documents_first() {
+        let index = SimilarityIndex::build(&documents(), 10);
+
+        let related = index.related("GLDS-379", 10).unwrap();
+        assert_eq!(related[0].0, "GLDS-242");
+
+        let related = index.related("GLDS-38", 10).unwrap();
+        assert_eq!(related[0].0, "GLDS-7");
+    }
+
+    #[test]
+    fn test_related_excludes_self_and_zero_scores() {
+        let index = SimilarityIndex::build(&documents(), 10);
+
+        let related = index.related("GLDS-1", 10).unwrap();
+        assert!(related.is_empty());
+
+        let related = index.related("GLDS-242", 10).unwrap();
+        assert!(related.iter().all(|(id, score)| id != "GLDS-242" && *score > 0.0));
+        assert!(related.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+
+    #[test]
+    fn test_related_respects_limits() {
+        let index = SimilarityIndex::build(&documents(), 1);
+        assert_eq!(index.related("GLDS-379", 10).unwrap().len(), 1);
+
+        let index = SimilarityIndex::build(&documents(), 10);
+        assert_eq!(index.related("GLDS-379", 1).unwrap().len(), 1);
+        assert!(index.related("GLDS-999", 10).is_none());
+    }
+
+    #[test]
+    fn test_tokenize_drops_stop_words_and_short_tokens() {
+        assert_eq!(
+            tokenize("The effect of a Microgravity on C. elegans"),
+            vec!["effect", "microgravity", "elegans"]
+        );
+    }
+}